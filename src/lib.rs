@@ -44,10 +44,21 @@ an optimized set of tools for computer graphics and physics. Those features incl
 * Vectors with static sizes: `Vec0`, `Vec1`, `Vec2`, `Vec3`, `Vec4`, `Vec5`, `Vec6`.
 * Points with static sizes: `Pnt0`, `Pnt1`, `Pnt2`, `Pnt3`, `Pnt4`, `Pnt5`, `Pnt6`.
 * Square matrices with static sizes: `Mat1`, `Mat2`, `Mat3`, `Mat4`, `Mat5`, `Mat6 `.
+* Rectangular matrices with static sizes: `Mat2x3`, `Mat3x4`, `Mat4x3`, etc.
 * Rotation matrices: `Rot2`, `Rot3`, `Rot4`.
 * Quaternions: `Quat`, `UnitQuat`.
-* Isometries: `Iso2`, `Iso3`, `Iso4`.
+* Dual quaternions: `DualQuat`, `UnitDualQuat`.
+* Unit complex numbers: `UnitComplex`.
+* Isometries: `Iso2`, `Iso3`, `Iso4`, `ComplexIso2`, `QuatIso3`.
+* Similarities: `Sim2`, `Sim3`.
+* Affine transformations: `Aff2`, `Aff3`.
+* Spatial vectors for rigid body dynamics: `Twist`, `Wrench`.
+* Mass properties of rigid bodies: `Inertia3`.
 * 3D projections for computer graphics: `Persp3`, `PerspMat3`, `Ortho3`, `OrthoMat3`.
+* Axis-aligned bounding boxes: `Aabb`.
+* Simple geometric primitives with intersection queries: `Ray`, `Line`, `Segment`, `Plane`,
+  `Triangle`, `Tetrahedron`.
+* Robust geometric predicates: `orient2d`, `orient3d`, `incircle`, `insphere`.
 * Dynamically sized vector: `DVec`.
 * Dynamically sized (square or rectangular) matrix: `DMat`.
 * A few methods for data analysis: `Cov`, `Mean`.
@@ -92,6 +103,7 @@ extern crate serialize;
 extern crate test;
 
 use std::cmp;
+use std::vec::MoveItems;
 pub use traits::{
     Absolute,
     AbsoluteRotate,
@@ -101,9 +113,11 @@ pub use traits::{
     BaseFloat,
     BaseNum,
     Bounded,
+    CanonicalBasisIter,
     Cast,
     Col,
     ColSlice, RowSlice,
+    CompFloat, CompMap, CompReduce,
     Cov,
     Cross,
     CrossMatrix,
@@ -145,6 +159,7 @@ pub use traits::{
     Transpose,
     UniformSphereSample,
     VecAsPnt,
+    Vee,
     Zero
 };
 
@@ -152,20 +167,39 @@ pub use structs::{
     Identity,
     DMat,
     DVec, DVec1, DVec2,  DVec3,  DVec4,  DVec5,  DVec6,
-    Iso2, Iso3, Iso4,
+    Iso2, Iso3, Iso4, ComplexIso2, QuatIso3,
+    Inertia3,
+    Sim2, Sim3,
+    Aff2, Aff3,
     Mat1, Mat2, Mat3, Mat4,
     Mat5, Mat6,
+    Mat1x2, Mat1x3, Mat1x4, Mat1x5, Mat1x6,
+    Mat2x1, Mat2x3, Mat2x4, Mat2x5, Mat2x6,
+    Mat3x1, Mat3x2, Mat3x4, Mat3x5, Mat3x6,
+    Mat4x1, Mat4x2, Mat4x3, Mat4x5, Mat4x6,
+    Mat5x1, Mat5x2, Mat5x3, Mat5x4, Mat5x6,
+    Mat6x1, Mat6x2, Mat6x3, Mat6x4, Mat6x5,
     Rot2, Rot3, Rot4,
     Vec0, Vec1, Vec2, Vec3, Vec4, Vec5, Vec6,
     Pnt0, Pnt1, Pnt2, Pnt3, Pnt4, Pnt5, Pnt6,
     Persp3, PerspMat3,
     Ortho3, OrthoMat3,
-    Quat, UnitQuat
+    Quat, UnitQuat,
+    DualQuat, UnitDualQuat,
+    UnitComplex,
+    Twist, Wrench,
+    Aabb,
+    Ray, Line, Segment, Plane, Triangle, Tetrahedron
 };
 
 pub use linalg::{
     qr,
-    householder_matrix
+    eigen_sym,
+    householder_matrix,
+    orient2d,
+    orient3d,
+    incircle,
+    insphere
 };
 
 mod structs;
@@ -320,6 +354,33 @@ pub fn center<N: BaseFloat, P: FloatPnt<N, V>, V>(a: &P, b: &P) -> P {
     (*a + *b.as_vec()) / _2
 }
 
+/// Returns the affine combination of `pts` with the weights `weights`, i.e., their weighted
+/// centroid.
+///
+/// The weights are normalized so that they sum to one. Fails if `pts` is empty, if `pts` and
+/// `weights` do not have the same length, or if the weights sum to zero.
+pub fn affine_combination<N: BaseFloat, P: FloatPnt<N, V>, V: FloatVec<N>>(pts: &[P], weights: &[N])
+                                                                           -> P {
+    assert!(!pts.is_empty() && pts.len() == weights.len(),
+            "There must be as many weights as points, and at least one point.");
+
+    let mut total: N = zero();
+
+    for w in weights.iter() {
+        total = total + *w;
+    }
+
+    assert!(total != zero(), "The weights of an affine combination must not sum to zero.");
+
+    let mut res: V = zero();
+
+    for (p, w) in pts.iter().zip(weights.iter()) {
+        res = res + (*p - pts[0]) * (*w / total);
+    }
+
+    pts[0] + res
+}
+
 /*
  * FloatPnt
  */
@@ -715,6 +776,16 @@ pub fn cross_matrix<V: CrossMatrix<M>, M>(v: &V) -> M {
     CrossMatrix::cross_matrix(v)
 }
 
+/*
+ * Vee<V>
+ */
+
+/// Given the cross matrix of a vector, retrieves this vector.
+#[inline(always)]
+pub fn vee<M: Vee<V>, V>(m: &M) -> V {
+    Vee::vee(m)
+}
+
 /*
  * ToHomogeneous<U>
  */
@@ -749,6 +820,22 @@ pub fn sample_sphere<V: UniformSphereSample>(f: |V| -> ()) {
     UniformSphereSample::sample(f)
 }
 
+/// An iterator through the samples of the unit sphere living on the dimension as the samples
+/// types.
+///
+/// The number of sampling point is implementation-specific. It is always uniform.
+#[inline(always)]
+pub fn sphere_samples<V: UniformSphereSample>() -> MoveItems<V> {
+    UniformSphereSample::sphere_samples()
+}
+
+/// An iterator through `count` quasi-uniformly distributed samples of the unit sphere living on
+/// the dimension as the samples types.
+#[inline(always)]
+pub fn sphere_samples_with_count<V: UniformSphereSample>(count: uint) -> MoveItems<V> {
+    UniformSphereSample::sphere_samples_with_count(count)
+}
+
 //
 //
 // Operations
@@ -781,6 +868,84 @@ pub fn abs<M: Absolute<Res>, Res>(m: &M) -> Res {
     Absolute::abs(m)
 }
 
+/*
+ * CompMap<N>
+ */
+
+/// Applies `f` to each component of `v`.
+#[inline(always)]
+pub fn map<V: CompMap<N>, N>(v: &V, f: |N| -> N) -> V {
+    v.map(f)
+}
+
+/// Applies `f` to each pair of corresponding components of `a` and `b`.
+#[inline(always)]
+pub fn zip_map<V: CompMap<N>, N>(a: &V, b: &V, f: |N, N| -> N) -> V {
+    a.zip_map(b, f)
+}
+
+/*
+ * CompFloat<N>
+ */
+
+/// Rounds each component of `v` toward negative infinity.
+#[inline(always)]
+pub fn floor<V: CompFloat<N>, N>(v: &V) -> V {
+    v.floor()
+}
+
+/// Rounds each component of `v` toward positive infinity.
+#[inline(always)]
+pub fn ceil<V: CompFloat<N>, N>(v: &V) -> V {
+    v.ceil()
+}
+
+/// Rounds each component of `v` to the nearest integer.
+#[inline(always)]
+pub fn round<V: CompFloat<N>, N>(v: &V) -> V {
+    v.round()
+}
+
+/// Computes the component-wise sign of `v`.
+#[inline(always)]
+pub fn signum<V: CompFloat<N>, N>(v: &V) -> V {
+    v.signum()
+}
+
+/// Linear interpolation between `a` (for `t = 0`) and `b` (for `t = 1`).
+#[inline(always)]
+pub fn lerp<V: CompFloat<N>, N>(a: &V, b: &V, t: &N) -> V {
+    a.lerp(b, t)
+}
+
+/*
+ * CompReduce<N>
+ */
+
+/// Computes the sum of the components of `v`.
+#[inline(always)]
+pub fn sum<V: CompReduce<N>, N>(v: &V) -> N {
+    v.sum()
+}
+
+/// Computes the product of the components of `v`.
+#[inline(always)]
+pub fn product<V: CompReduce<N>, N>(v: &V) -> N {
+    v.product()
+}
+
+/// Computes the index and value of the smallest component of `v`.
+#[inline(always)]
+pub fn min_component<V: CompReduce<N>, N>(v: &V) -> (uint, N) {
+    v.min_component()
+}
+
+/// Computes the index and value of the greatest component of `v`.
+#[inline(always)]
+pub fn max_component<V: CompReduce<N>, N>(v: &V) -> (uint, N) {
+    v.max_component()
+}
+
 /*
  * Inv
  */
@@ -871,6 +1036,18 @@ pub fn orthonormal_subspace_basis<V: Basis>(v: &V, f: |V| -> bool) {
     Basis::orthonormal_subspace_basis(v, f)
 }
 
+/// An iterator through the canonical basis for a given dimension.
+#[inline(always)]
+pub fn canonical_basis_iter<V: Basis>() -> CanonicalBasisIter<V> {
+    Basis::canonical_basis_iter()
+}
+
+/// An iterator through the basis of the orthonormal subspace of a given vector.
+#[inline(always)]
+pub fn orthonormal_subspace_basis_iter<V: Basis>(v: &V) -> MoveItems<V> {
+    Basis::orthonormal_subspace_basis_iter(v)
+}
+
 /// Gets the (0-based) i-th element of the canonical basis of V.
 #[inline]
 pub fn canonical_basis_element<V: Basis>(i: uint) -> Option<V> {
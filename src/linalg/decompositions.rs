@@ -114,3 +114,78 @@ pub fn eigen_qr<N, V, VS, M>(m: &M, eps: &N, niter: uint) -> (M, V)
 
     (eigenvectors, eigenvalues.diag())
 }
+
+/// Eigendecomposition of a symmetric matrix using the cyclic Jacobi method.
+///
+/// Returns the matrix whose columns are the eigenvectors, and the eigenvalues. The iterations
+/// stop when every off-diagonal component is smaller than `eps`, or after `niter` sweeps. Unlike
+/// `eigen_qr`, this converges quadratically but only the lower triangle of `m` is used.
+pub fn eigen_sym<N, V, M>(m: &M, eps: &N, niter: uint) -> (M, V)
+    where N: BaseFloat + Clone,
+          M: Indexable<(uint, uint), N> + SquareMat<N, V> + Clone {
+    let dim              = ::dim::<M>();
+    let mut eigenvectors = ::one::<M>();
+    let mut eigenvalues  = m.clone();
+
+    // Symmetrize the input.
+    for j in range(0u, dim) {
+        for i in range(j + 1, dim) {
+            eigenvalues[(j, i)] = eigenvalues[(i, j)].clone();
+        }
+    }
+
+    for _ in range(0u, niter) {
+        let mut stop = true;
+
+        for p in range(0u, dim) {
+            for q in range(p + 1, dim) {
+                let apq = eigenvalues[(p, q)].clone();
+
+                if apq.abs() < *eps || ::is_zero(&apq) {
+                    continue;
+                }
+
+                stop = false;
+
+                // Rotation which cancels the component `(p, q)`.
+                let _1: N = ::one();
+                let _2: N = _1 + _1;
+                let theta = (eigenvalues[(q, q)] - eigenvalues[(p, p)]) / (apq * _2);
+                let t     = _1 / (theta.abs() + (theta * theta + _1).sqrt());
+                let t     = if theta < ::zero() { -t } else { t };
+                let c     = _1 / (t * t + _1).sqrt();
+                let s     = t * c;
+
+                for k in range(0u, dim) {
+                    let akp = eigenvalues[(k, p)].clone();
+                    let akq = eigenvalues[(k, q)].clone();
+
+                    eigenvalues[(k, p)] = c * akp - s * akq;
+                    eigenvalues[(k, q)] = s * akp + c * akq;
+                }
+
+                for k in range(0u, dim) {
+                    let apk = eigenvalues[(p, k)].clone();
+                    let aqk = eigenvalues[(q, k)].clone();
+
+                    eigenvalues[(p, k)] = c * apk - s * aqk;
+                    eigenvalues[(q, k)] = s * apk + c * aqk;
+                }
+
+                for k in range(0u, dim) {
+                    let vkp = eigenvectors[(k, p)].clone();
+                    let vkq = eigenvectors[(k, q)].clone();
+
+                    eigenvectors[(k, p)] = c * vkp - s * vkq;
+                    eigenvectors[(k, q)] = s * vkp + c * vkq;
+                }
+            }
+        }
+
+        if stop {
+            break;
+        }
+    }
+
+    (eigenvectors, eigenvalues.diag())
+}
@@ -1,4 +1,6 @@
 
-pub use self::decompositions::{qr, eigen_qr, householder_matrix};
+pub use self::decompositions::{qr, eigen_qr, eigen_sym, householder_matrix};
+pub use self::predicates::{orient2d, orient3d, incircle, insphere};
 
 mod decompositions;
+mod predicates;
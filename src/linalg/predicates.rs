@@ -0,0 +1,456 @@
+//! Robust geometric predicates.
+//!
+//! Those predicates follow the approach of Jonathan Richard Shewchuk: the result is first
+//! evaluated with plain floating-point arithmetic, and is recomputed exactly using floating-point
+//! expansions only if the magnitude of the approximate result is smaller than a known bound of its
+//! rounding error. The sign of the returned value is thus always exact.
+
+use std::num::Float;
+use structs::{Pnt2, Pnt3};
+
+// Error bounds of the fast (non-exact) evaluation of each predicate.
+// `EPSILON` is half the machine epsilon, i.e., the largest power of two such that
+// `1.0 + EPSILON == 1.0` with round-to-even.
+static EPSILON:         f64 = 1.1102230246251565e-16;
+static CCW_ERRBOUND_A:  f64 = (3.0 + 16.0 * EPSILON) * EPSILON;
+static O3D_ERRBOUND_A:  f64 = (7.0 + 56.0 * EPSILON) * EPSILON;
+static ICC_ERRBOUND_A:  f64 = (10.0 + 96.0 * EPSILON) * EPSILON;
+static ISP_ERRBOUND_A:  f64 = (16.0 + 224.0 * EPSILON) * EPSILON;
+
+// 2^27 + 1, used to split a float into two non-overlapping halves.
+static SPLITTER: f64 = 134217729.0;
+
+/// Orientation test of three points on the plane.
+///
+/// Returns a positive value if `a`, `b` and `c` are in counterclockwise order, a negative value if
+/// they are in clockwise order, and zero if they are collinear. The value is an approximation of
+/// twice the signed area of the triangle `abc`, but its sign is exact.
+pub fn orient2d(a: &Pnt2<f64>, b: &Pnt2<f64>, c: &Pnt2<f64>) -> f64 {
+    let detleft  = (a.x - c.x) * (b.y - c.y);
+    let detright = (a.y - c.y) * (b.x - c.x);
+    let det      = detleft - detright;
+    let errbound = CCW_ERRBOUND_A * (detleft.abs() + detright.abs());
+
+    if det > errbound || -det > errbound {
+        return det
+    }
+
+    let acx = diff(a.x, c.x);
+    let acy = diff(a.y, c.y);
+    let bcx = diff(b.x, c.x);
+    let bcy = diff(b.y, c.y);
+
+    estimate(&sub(&mul(&acx, &bcy), &mul(&acy, &bcx)))
+}
+
+/// Orientation test of four points in space.
+///
+/// Returns a positive value if `d` lies below the plane passing through `a`, `b` and `c`, "below"
+/// being defined so that `a`, `b` and `c` appear in counterclockwise order when viewed from above
+/// the plane. Returns a negative value if `d` lies above the plane, and zero if the four points are
+/// coplanar. The value is an approximation of six times the signed volume of the tetrahedron
+/// `abcd`, but its sign is exact.
+pub fn orient3d(a: &Pnt3<f64>, b: &Pnt3<f64>, c: &Pnt3<f64>, d: &Pnt3<f64>) -> f64 {
+    let adx = a.x - d.x;
+    let bdx = b.x - d.x;
+    let cdx = c.x - d.x;
+    let ady = a.y - d.y;
+    let bdy = b.y - d.y;
+    let cdy = c.y - d.y;
+    let adz = a.z - d.z;
+    let bdz = b.z - d.z;
+    let cdz = c.z - d.z;
+
+    let bdxcdy = bdx * cdy;
+    let cdxbdy = cdx * bdy;
+    let cdxady = cdx * ady;
+    let adxcdy = adx * cdy;
+    let adxbdy = adx * bdy;
+    let bdxady = bdx * ady;
+
+    let det = adz * (bdxcdy - cdxbdy) + bdz * (cdxady - adxcdy) + cdz * (adxbdy - bdxady);
+
+    let permanent = (bdxcdy.abs() + cdxbdy.abs()) * adz.abs() +
+                    (cdxady.abs() + adxcdy.abs()) * bdz.abs() +
+                    (adxbdy.abs() + bdxady.abs()) * cdz.abs();
+    let errbound  = O3D_ERRBOUND_A * permanent;
+
+    if det > errbound || -det > errbound {
+        return det
+    }
+
+    let adx = diff(a.x, d.x);
+    let bdx = diff(b.x, d.x);
+    let cdx = diff(c.x, d.x);
+    let ady = diff(a.y, d.y);
+    let bdy = diff(b.y, d.y);
+    let cdy = diff(c.y, d.y);
+    let adz = diff(a.z, d.z);
+    let bdz = diff(b.z, d.z);
+    let cdz = diff(c.z, d.z);
+
+    let bc = sub(&mul(&bdx, &cdy), &mul(&cdx, &bdy));
+    let ca = sub(&mul(&cdx, &ady), &mul(&adx, &cdy));
+    let ab = sub(&mul(&adx, &bdy), &mul(&bdx, &ady));
+
+    let det = add(&add(&mul(&adz, &bc), &mul(&bdz, &ca)), &mul(&cdz, &ab));
+
+    estimate(&det)
+}
+
+/// Tests whether the point `d` lies inside of the circle passing through `a`, `b` and `c`.
+///
+/// Returns a positive value if `d` lies inside of the circle, a negative value if it lies
+/// outside, and zero if the four points are cocircular. The points `a`, `b` and `c` must be in
+/// counterclockwise order, otherwise the sign of the result is reversed. The sign of the result is
+/// exact.
+pub fn incircle(a: &Pnt2<f64>, b: &Pnt2<f64>, c: &Pnt2<f64>, d: &Pnt2<f64>) -> f64 {
+    let adx = a.x - d.x;
+    let bdx = b.x - d.x;
+    let cdx = c.x - d.x;
+    let ady = a.y - d.y;
+    let bdy = b.y - d.y;
+    let cdy = c.y - d.y;
+
+    let bdxcdy = bdx * cdy;
+    let cdxbdy = cdx * bdy;
+    let cdxady = cdx * ady;
+    let adxcdy = adx * cdy;
+    let adxbdy = adx * bdy;
+    let bdxady = bdx * ady;
+
+    let alift = adx * adx + ady * ady;
+    let blift = bdx * bdx + bdy * bdy;
+    let clift = cdx * cdx + cdy * cdy;
+
+    let det = alift * (bdxcdy - cdxbdy) + blift * (cdxady - adxcdy) + clift * (adxbdy - bdxady);
+
+    let permanent = (bdxcdy.abs() + cdxbdy.abs()) * alift +
+                    (cdxady.abs() + adxcdy.abs()) * blift +
+                    (adxbdy.abs() + bdxady.abs()) * clift;
+    let errbound  = ICC_ERRBOUND_A * permanent;
+
+    if det > errbound || -det > errbound {
+        return det
+    }
+
+    let adx = diff(a.x, d.x);
+    let bdx = diff(b.x, d.x);
+    let cdx = diff(c.x, d.x);
+    let ady = diff(a.y, d.y);
+    let bdy = diff(b.y, d.y);
+    let cdy = diff(c.y, d.y);
+
+    let alift = add(&mul(&adx, &adx), &mul(&ady, &ady));
+    let blift = add(&mul(&bdx, &bdx), &mul(&bdy, &bdy));
+    let clift = add(&mul(&cdx, &cdx), &mul(&cdy, &cdy));
+
+    let bc = sub(&mul(&bdx, &cdy), &mul(&cdx, &bdy));
+    let ca = sub(&mul(&cdx, &ady), &mul(&adx, &cdy));
+    let ab = sub(&mul(&adx, &bdy), &mul(&bdx, &ady));
+
+    let det = add(&add(&mul(&alift, &bc), &mul(&blift, &ca)), &mul(&clift, &ab));
+
+    estimate(&det)
+}
+
+/// Tests whether the point `e` lies inside of the sphere passing through `a`, `b`, `c` and `d`.
+///
+/// Returns a positive value if `e` lies inside of the sphere, a negative value if it lies
+/// outside, and zero if the five points are cospherical. The points `a`, `b`, `c` and `d` must be
+/// ordered so that `orient3d(a, b, c, d)` is positive, otherwise the sign of the result is
+/// reversed. The sign of the result is exact.
+pub fn insphere(a: &Pnt3<f64>, b: &Pnt3<f64>, c: &Pnt3<f64>, d: &Pnt3<f64>, e: &Pnt3<f64>)
+                -> f64 {
+    let aex = a.x - e.x;
+    let bex = b.x - e.x;
+    let cex = c.x - e.x;
+    let dex = d.x - e.x;
+    let aey = a.y - e.y;
+    let bey = b.y - e.y;
+    let cey = c.y - e.y;
+    let dey = d.y - e.y;
+    let aez = a.z - e.z;
+    let bez = b.z - e.z;
+    let cez = c.z - e.z;
+    let dez = d.z - e.z;
+
+    let aexbey = aex * bey;
+    let bexaey = bex * aey;
+    let bexcey = bex * cey;
+    let cexbey = cex * bey;
+    let cexdey = cex * dey;
+    let dexcey = dex * cey;
+    let dexaey = dex * aey;
+    let aexdey = aex * dey;
+    let aexcey = aex * cey;
+    let cexaey = cex * aey;
+    let bexdey = bex * dey;
+    let dexbey = dex * bey;
+
+    let ab = aexbey - bexaey;
+    let bc = bexcey - cexbey;
+    let cd = cexdey - dexcey;
+    let da = dexaey - aexdey;
+    let ac = aexcey - cexaey;
+    let bd = bexdey - dexbey;
+
+    let abc = aez * bc - bez * ac + cez * ab;
+    let bcd = bez * cd - cez * bd + dez * bc;
+    let cda = cez * da + dez * ac + aez * cd;
+    let dab = dez * ab + aez * bd + bez * da;
+
+    let alift = aex * aex + aey * aey + aez * aez;
+    let blift = bex * bex + bey * bey + bez * bez;
+    let clift = cex * cex + cey * cey + cez * cez;
+    let dlift = dex * dex + dey * dey + dez * dez;
+
+    let det = (dlift * abc - clift * dab) + (blift * cda - alift * bcd);
+
+    let aez = aez.abs();
+    let bez = bez.abs();
+    let cez = cez.abs();
+    let dez = dez.abs();
+
+    let aexbey = aexbey.abs();
+    let bexaey = bexaey.abs();
+    let bexcey = bexcey.abs();
+    let cexbey = cexbey.abs();
+    let cexdey = cexdey.abs();
+    let dexcey = dexcey.abs();
+    let dexaey = dexaey.abs();
+    let aexdey = aexdey.abs();
+    let aexcey = aexcey.abs();
+    let cexaey = cexaey.abs();
+    let bexdey = bexdey.abs();
+    let dexbey = dexbey.abs();
+
+    let permanent = ((cexdey + dexcey) * bez + (dexbey + bexdey) * cez +
+                     (bexcey + cexbey) * dez) * alift +
+                    ((dexaey + aexdey) * cez + (aexcey + cexaey) * dez +
+                     (cexdey + dexcey) * aez) * blift +
+                    ((aexbey + bexaey) * dez + (bexdey + dexbey) * aez +
+                     (dexaey + aexdey) * bez) * clift +
+                    ((bexcey + cexbey) * aez + (cexaey + aexcey) * bez +
+                     (aexbey + bexaey) * cez) * dlift;
+    let errbound  = ISP_ERRBOUND_A * permanent;
+
+    if det > errbound || -det > errbound {
+        return det
+    }
+
+    let aex = diff(a.x, e.x);
+    let bex = diff(b.x, e.x);
+    let cex = diff(c.x, e.x);
+    let dex = diff(d.x, e.x);
+    let aey = diff(a.y, e.y);
+    let bey = diff(b.y, e.y);
+    let cey = diff(c.y, e.y);
+    let dey = diff(d.y, e.y);
+    let aez = diff(a.z, e.z);
+    let bez = diff(b.z, e.z);
+    let cez = diff(c.z, e.z);
+    let dez = diff(d.z, e.z);
+
+    let ab = sub(&mul(&aex, &bey), &mul(&bex, &aey));
+    let bc = sub(&mul(&bex, &cey), &mul(&cex, &bey));
+    let cd = sub(&mul(&cex, &dey), &mul(&dex, &cey));
+    let da = sub(&mul(&dex, &aey), &mul(&aex, &dey));
+    let ac = sub(&mul(&aex, &cey), &mul(&cex, &aey));
+    let bd = sub(&mul(&bex, &dey), &mul(&dex, &bey));
+
+    let abc = add(&sub(&mul(&aez, &bc), &mul(&bez, &ac)), &mul(&cez, &ab));
+    let bcd = add(&sub(&mul(&bez, &cd), &mul(&cez, &bd)), &mul(&dez, &bc));
+    let cda = add(&add(&mul(&cez, &da), &mul(&dez, &ac)), &mul(&aez, &cd));
+    let dab = add(&add(&mul(&dez, &ab), &mul(&aez, &bd)), &mul(&bez, &da));
+
+    let alift = add(&add(&mul(&aex, &aex), &mul(&aey, &aey)), &mul(&aez, &aez));
+    let blift = add(&add(&mul(&bex, &bex), &mul(&bey, &bey)), &mul(&bez, &bez));
+    let clift = add(&add(&mul(&cex, &cex), &mul(&cey, &cey)), &mul(&cez, &cez));
+    let dlift = add(&add(&mul(&dex, &dex), &mul(&dey, &dey)), &mul(&dez, &dez));
+
+    let left  = sub(&mul(&dlift, &abc), &mul(&clift, &dab));
+    let right = sub(&mul(&blift, &cda), &mul(&alift, &bcd));
+
+    estimate(&add(&left, &right))
+}
+
+/*
+ *
+ * Floating-point expansion arithmetic.
+ *
+ * An expansion is a sum of non-overlapping floats, sorted by increasing magnitude. Zero
+ * components are always eliminated, so the last component of an expansion is an approximation of
+ * its value, with the same sign.
+ *
+ */
+
+// Computes `a + b` exactly as the sum `x + y` where `x` is the rounded result.
+#[inline]
+fn two_sum(a: f64, b: f64) -> (f64, f64) {
+    let x      = a + b;
+    let bvirt  = x - a;
+    let avirt  = x - bvirt;
+    let bround = b - bvirt;
+    let around = a - avirt;
+
+    (x, around + bround)
+}
+
+// Same as `two_sum`, assuming `|a| >= |b|`.
+#[inline]
+fn fast_two_sum(a: f64, b: f64) -> (f64, f64) {
+    let x     = a + b;
+    let bvirt = x - a;
+
+    (x, b - bvirt)
+}
+
+// Splits `a` into two non-overlapping halves with at most 26 significant bits each.
+#[inline]
+fn split(a: f64) -> (f64, f64) {
+    let c    = SPLITTER * a;
+    let abig = c - a;
+    let hi   = c - abig;
+
+    (hi, a - hi)
+}
+
+// Computes `a * b` exactly as the sum `x + y` where `x` is the rounded result.
+#[inline]
+fn two_product(a: f64, b: f64) -> (f64, f64) {
+    let x          = a * b;
+    let (ahi, alo) = split(a);
+    let (bhi, blo) = split(b);
+    let err1       = x - ahi * bhi;
+    let err2       = err1 - alo * bhi;
+    let err3       = err2 - ahi * blo;
+
+    (x, alo * blo - err3)
+}
+
+// The exact difference `a - b` as an expansion.
+#[inline]
+fn diff(a: f64, b: f64) -> Vec<f64> {
+    let x      = a - b;
+    let bvirt  = a - x;
+    let avirt  = x + bvirt;
+    let bround = bvirt - b;
+    let around = a - avirt;
+
+    expansion(around + bround, x)
+}
+
+// Builds an expansion from a rounded result `x` and its rounding error `y`.
+#[inline]
+fn expansion(y: f64, x: f64) -> Vec<f64> {
+    let mut res = Vec::with_capacity(2);
+
+    if y != 0.0 {
+        res.push(y)
+    }
+
+    if x != 0.0 {
+        res.push(x)
+    }
+
+    res
+}
+
+// Adds the float `b` to the expansion `e`.
+fn grow(e: &[f64], b: f64) -> Vec<f64> {
+    let mut res = Vec::with_capacity(e.len() + 1);
+    let mut q   = b;
+
+    for ei in e.iter() {
+        let (sum, err) = two_sum(q, *ei);
+
+        if err != 0.0 {
+            res.push(err)
+        }
+
+        q = sum;
+    }
+
+    if q != 0.0 {
+        res.push(q)
+    }
+
+    res
+}
+
+// The sum of two expansions.
+fn add(e: &Vec<f64>, f: &Vec<f64>) -> Vec<f64> {
+    let mut res = e.clone();
+
+    for fi in f.iter() {
+        res = grow(res.as_slice(), *fi);
+    }
+
+    res
+}
+
+// The difference of two expansions.
+fn sub(e: &Vec<f64>, f: &Vec<f64>) -> Vec<f64> {
+    let neg_f: Vec<f64> = f.iter().map(|fi| -*fi).collect();
+
+    add(e, &neg_f)
+}
+
+// The product of the expansion `e` with the float `b`.
+fn scale(e: &[f64], b: f64) -> Vec<f64> {
+    let mut res = Vec::with_capacity(2 * e.len());
+
+    if e.is_empty() || b == 0.0 {
+        return res
+    }
+
+    let (mut q, err) = two_product(e[0], b);
+
+    if err != 0.0 {
+        res.push(err)
+    }
+
+    for ei in e.slice_from(1).iter() {
+        let (p1, p0)    = two_product(*ei, b);
+        let (sum, err1) = two_sum(q, p0);
+
+        if err1 != 0.0 {
+            res.push(err1)
+        }
+
+        let (new_q, err2) = fast_two_sum(p1, sum);
+
+        if err2 != 0.0 {
+            res.push(err2)
+        }
+
+        q = new_q;
+    }
+
+    if q != 0.0 {
+        res.push(q)
+    }
+
+    res
+}
+
+// The product of two expansions.
+fn mul(e: &Vec<f64>, f: &Vec<f64>) -> Vec<f64> {
+    let mut res = Vec::new();
+
+    for fi in f.iter() {
+        res = add(&res, &scale(e.as_slice(), *fi));
+    }
+
+    res
+}
+
+// An approximation of the value of an expansion, with the same sign.
+#[inline]
+fn estimate(e: &Vec<f64>) -> f64 {
+    match e.last() {
+        Some(x) => *x,
+        None    => 0.0
+    }
+}
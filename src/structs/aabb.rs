@@ -0,0 +1,157 @@
+//! Axis-aligned bounding boxes.
+
+use traits::operations::POrd;
+use traits::structure::{BaseFloat, FloatVec, FloatPnt, Dim};
+use traits::geometry::{Transform, AbsoluteRotate};
+
+/// An axis-aligned bounding box.
+///
+/// It is represented by its two extremal points, i.e., the points having respectively the
+/// smallest and the greatest coordinates along each axis.
+#[deriving(Eq, PartialEq, Encodable, Decodable, Clone, Show)]
+pub struct Aabb<P> {
+    /// The point of the box with the smallest coordinates.
+    pub mins: P,
+    /// The point of the box with the greatest coordinates.
+    pub maxs: P
+}
+
+impl<P: POrd + Clone> Aabb<P> {
+    /// Creates a new box from its extremal points.
+    ///
+    /// Fails if `mins` is not smaller than or equal to `maxs` along each axis.
+    #[inline]
+    pub fn new(mins: P, maxs: P) -> Aabb<P> {
+        assert!(POrd::partial_le(&mins, &maxs),
+                "The minimal point of an aabb must be smaller than its maximal point.");
+
+        Aabb {
+            mins: mins,
+            maxs: maxs
+        }
+    }
+
+    /// Creates the smallest box containing all the points yielded by `pts`.
+    ///
+    /// Returns `None` if `pts` is empty.
+    pub fn from_points<I: Iterator<P>>(mut pts: I) -> Option<Aabb<P>> {
+        let first = match pts.next() {
+            Some(p) => p,
+            None    => return None
+        };
+
+        let mut res = Aabb::new(first.clone(), first);
+
+        for p in pts {
+            res.mins = POrd::inf(&res.mins, &p);
+            res.maxs = POrd::sup(&res.maxs, &p);
+        }
+
+        Some(res)
+    }
+
+    /// The smallest box containing both `self` and `other`.
+    #[inline]
+    pub fn merged(&self, other: &Aabb<P>) -> Aabb<P> {
+        Aabb {
+            mins: POrd::inf(&self.mins, &other.mins),
+            maxs: POrd::sup(&self.maxs, &other.maxs)
+        }
+    }
+
+    /// Enlarges `self` so that it also contains `other`.
+    #[inline]
+    pub fn merge(&mut self, other: &Aabb<P>) {
+        self.mins = POrd::inf(&self.mins, &other.mins);
+        self.maxs = POrd::sup(&self.maxs, &other.maxs);
+    }
+
+    /// Tests whether `self` and `other` overlap.
+    ///
+    /// Boxes which only touch each other are considered overlapping.
+    #[inline]
+    pub fn intersects(&self, other: &Aabb<P>) -> bool {
+        POrd::partial_le(&self.mins, &other.maxs) && POrd::partial_le(&other.mins, &self.maxs)
+    }
+
+    /// The box contained by both `self` and `other`.
+    ///
+    /// Returns `None` if `self` and `other` do not overlap.
+    #[inline]
+    pub fn intersection(&self, other: &Aabb<P>) -> Option<Aabb<P>> {
+        let mins = POrd::sup(&self.mins, &other.mins);
+        let maxs = POrd::inf(&self.maxs, &other.maxs);
+
+        if POrd::partial_le(&mins, &maxs) {
+            Some(Aabb { mins: mins, maxs: maxs })
+        }
+        else {
+            None
+        }
+    }
+
+    /// Tests whether `other` is completely contained by `self`.
+    #[inline]
+    pub fn contains(&self, other: &Aabb<P>) -> bool {
+        POrd::partial_le(&self.mins, &other.mins) && POrd::partial_le(&other.maxs, &self.maxs)
+    }
+
+    /// Tests whether the point `p` is contained by `self`.
+    #[inline]
+    pub fn contains_pnt(&self, p: &P) -> bool {
+        POrd::partial_le(&self.mins, p) && POrd::partial_le(p, &self.maxs)
+    }
+}
+
+impl<N: BaseFloat, V: FloatVec<N>, P: FloatPnt<N, V> + POrd + Clone> Aabb<P> {
+    /// The center of this box.
+    #[inline]
+    pub fn center(&self) -> P {
+        self.mins + self.half_extents()
+    }
+
+    /// The extents of this box, i.e., its length along each axis.
+    #[inline]
+    pub fn extents(&self) -> V {
+        self.maxs - self.mins
+    }
+
+    /// The half-extents of this box, i.e., the half of its length along each axis.
+    #[inline]
+    pub fn half_extents(&self) -> V {
+        let two: N = ::one::<N>() + ::one();
+
+        (self.maxs - self.mins) / two
+    }
+
+    /// The volume of this box, i.e., the product of its extents.
+    ///
+    /// This is the area of the box in two dimensions.
+    #[inline]
+    pub fn volume(&self) -> N {
+        let extents = self.extents();
+        let mut res = ::one::<N>();
+
+        for i in range(0u, Dim::dim(None::<V>)) {
+            res = res * extents[i];
+        }
+
+        res
+    }
+
+    /// The smallest axis-aligned box containing the image of `self` by the transformation `m`.
+    ///
+    /// This relies on `AbsoluteRotate` to compute the extents of the transformed box, thus
+    /// avoiding the transformation of each of its vertices. Therefore, `m` must be an affine
+    /// transformation: projective matrices are not supported.
+    #[inline]
+    pub fn transform_by<M: Transform<P> + AbsoluteRotate<V>>(&self, m: &M) -> Aabb<P> {
+        let center = m.transform(&self.center());
+        let half   = m.absolute_rotate(&self.half_extents());
+
+        Aabb {
+            mins: center + (-half),
+            maxs: center + half
+        }
+    }
+}
@@ -0,0 +1,206 @@
+//! Affine transformations.
+
+#![allow(missing_docs)]
+
+use std::rand::{Rand, Rng};
+use structs::mat::{Mat2, Mat3, Mat4};
+use traits::structure::{Dim, Col, Row, BaseFloat, BaseNum, One};
+use traits::operations::{Inv, ApproxEq, Det};
+use traits::geometry::{Norm, Dot, Transform, Transformation, Translation, ToHomogeneous,
+                       FromHomogeneous};
+
+use structs::vec::{Vec2, Vec3};
+use structs::pnt::{Pnt2, Pnt3};
+use structs::rot::{Rot2, Rot3};
+use structs::iso::{Iso2, Iso3};
+use structs::sim::{Sim2, Sim3};
+
+/// Two dimensional affine transformation.
+///
+/// This is the composition of a linear transformation followed by a translation. Unlike
+/// isometries and similarities, affine transformations allow non-uniform scaling and shearing.
+#[deriving(Eq, PartialEq, Encodable, Decodable, Clone, Show)]
+pub struct Aff2<N> {
+    /// The linear part of this affine transformation.
+    pub linear:      Mat2<N>,
+    /// The translation applicable by this affine transformation.
+    pub translation: Vec2<N>
+}
+
+/// Three dimensional affine transformation.
+///
+/// This is the composition of a linear transformation followed by a translation. Unlike
+/// isometries and similarities, affine transformations allow non-uniform scaling and shearing.
+#[deriving(Eq, PartialEq, Encodable, Decodable, Clone, Show)]
+pub struct Aff3<N> {
+    /// The linear part of this affine transformation.
+    pub linear:      Mat3<N>,
+    /// The translation applicable by this affine transformation.
+    pub translation: Vec3<N>
+}
+
+impl<N: Clone + BaseFloat + ApproxEq<N>> Aff2<N> {
+    /// Creates an affine transformation from its decomposition.
+    ///
+    /// The resulting transformation applies, in that order, a shear along the `x` axis (the
+    /// point `(x, y)` is mapped to `(x + shear * y, y)`), a non-uniform scaling, a rotation, and
+    /// a translation.
+    pub fn new_with_decomposition(translation: Vec2<N>, rotation: Rot2<N>, scale: Vec2<N>,
+                                  shear: N) -> Aff2<N> {
+        let scale_shear = Mat2::new(scale.x.clone(), scale.x * shear,
+                                    ::zero(),        scale.y.clone());
+
+        Aff2::new(translation, *rotation.submat() * scale_shear)
+    }
+
+    /// Decomposes this affine transformation into a translation, a rotation, a non-uniform
+    /// scaling, and a shear.
+    ///
+    /// This is the inverse of `Aff2::new_with_decomposition`. The scaling factor along the `x`
+    /// axis is always positive, while the one along the `y` axis is negative if the
+    /// transformation contains a reflection. Returns `None` if the linear part is singular.
+    pub fn decompose(&self) -> Option<(Vec2<N>, Rot2<N>, Vec2<N>, N)> {
+        let c1 = self.linear.col(0);
+        let c2 = self.linear.col(1);
+
+        // The singularity tests are relative to the norm of each column, so that transformations
+        // with a tiny scale can still be decomposed.
+        let eps = ApproxEq::approx_epsilon(None::<N>);
+        let sx  = Norm::norm(&c1);
+
+        if sx == ::zero() {
+            return None
+        }
+
+        let e1  = c1 / sx;
+        let k12 = Dot::dot(&e1, &c2);
+        let u2  = c2 - e1 * k12;
+        let mut sy = Norm::norm(&u2);
+
+        if sy <= eps * Norm::norm(&c2) {
+            return None
+        }
+
+        let mut e2 = u2 / sy;
+
+        if Det::det(&self.linear) < ::zero() {
+            // Move the reflection to the scaling factor.
+            e2 = -e2;
+            sy = -sy;
+        }
+
+        let rotation = unsafe {
+            Rot2::new_with_mat(Mat2::new(e1.x.clone(), e2.x.clone(),
+                                         e1.y.clone(), e2.y.clone()))
+        };
+
+        Some((self.translation.clone(), rotation, Vec2::new(sx, sy), k12 / sx))
+    }
+}
+
+impl<N: Clone + BaseFloat + ApproxEq<N>> Aff3<N> {
+    /// Creates an affine transformation from its decomposition.
+    ///
+    /// The resulting transformation applies, in that order, a shear, a non-uniform scaling, a
+    /// rotation, and a translation. The shear is given by the three coefficients `(xy, xz, yz)`
+    /// that map the point `(x, y, z)` to `(x + xy * y + xz * z, y + yz * z, z)`.
+    pub fn new_with_decomposition(translation: Vec3<N>, rotation: Rot3<N>, scale: Vec3<N>,
+                                  shear: Vec3<N>) -> Aff3<N> {
+        let scale_shear = Mat3::new(scale.x.clone(), scale.x * shear.x, scale.x * shear.y,
+                                    ::zero(),        scale.y.clone(),   scale.y * shear.z,
+                                    ::zero(),        ::zero(),          scale.z.clone());
+
+        Aff3::new(translation, *rotation.submat() * scale_shear)
+    }
+
+    /// Decomposes this affine transformation into a translation, a rotation, a non-uniform
+    /// scaling, and a shear `(xy, xz, yz)`.
+    ///
+    /// This is the inverse of `Aff3::new_with_decomposition`. The scaling factors along the `x`
+    /// and `y` axis are always positive, while the one along the `z` axis is negative if the
+    /// transformation contains a reflection. Returns `None` if the linear part is singular.
+    pub fn decompose(&self) -> Option<(Vec3<N>, Rot3<N>, Vec3<N>, Vec3<N>)> {
+        // Gram-Schmidt orthonormalization of the columns, i.e., a QR decomposition of the linear
+        // part.
+        let c1 = self.linear.col(0);
+        let c2 = self.linear.col(1);
+        let c3 = self.linear.col(2);
+
+        // The singularity tests are relative to the norm of each column, so that transformations
+        // with a tiny scale can still be decomposed.
+        let eps = ApproxEq::approx_epsilon(None::<N>);
+        let sx  = Norm::norm(&c1);
+
+        if sx == ::zero() {
+            return None
+        }
+
+        let e1  = c1 / sx;
+        let k12 = Dot::dot(&e1, &c2);
+        let u2  = c2 - e1 * k12;
+        let sy  = Norm::norm(&u2);
+
+        if sy <= eps * Norm::norm(&c2) {
+            return None
+        }
+
+        let e2  = u2 / sy;
+        let k13 = Dot::dot(&e1, &c3);
+        let k23 = Dot::dot(&e2, &c3);
+        let u3  = c3 - e1 * k13 - e2 * k23;
+        let mut sz = Norm::norm(&u3);
+
+        if sz <= eps * Norm::norm(&c3) {
+            return None
+        }
+
+        let mut e3 = u3 / sz;
+
+        if Det::det(&self.linear) < ::zero() {
+            // Move the reflection to the scaling factor.
+            e3 = -e3;
+            sz = -sz;
+        }
+
+        let rotation = unsafe {
+            Rot3::new_with_mat(Mat3::new(e1.x.clone(), e2.x.clone(), e3.x.clone(),
+                                         e1.y.clone(), e2.y.clone(), e3.y.clone(),
+                                         e1.z.clone(), e2.z.clone(), e3.z.clone()))
+        };
+
+        Some((self.translation.clone(),
+              rotation,
+              Vec3::new(sx, sy, sz),
+              Vec3::new(k12 / sx, k13 / sx, k23 / sy)))
+    }
+}
+
+aff_impl!(Aff2, Mat2, Vec2, Iso2, Sim2)
+dim_impl!(Aff2, 2)
+aff_one_impl!(Aff2)
+aff_rand_impl!(Aff2)
+aff_approx_eq_impl!(Aff2)
+aff_to_homogeneous_impl!(Aff2, Mat3)
+aff_from_homogeneous_impl!(Aff2, Mat2, Mat3, Vec2)
+aff_inv_impl!(Aff2)
+aff_transform_impl!(Aff2, Pnt2, Vec2)
+aff_transformation_impl!(Aff2)
+aff_translation_impl!(Aff2, Vec2)
+aff_mul_aff_impl!(Aff2)
+aff_mul_pnt_impl!(Aff2, Pnt2)
+aff_mul_vec_impl!(Aff2, Vec2)
+
+aff_impl!(Aff3, Mat3, Vec3, Iso3, Sim3)
+dim_impl!(Aff3, 3)
+aff_one_impl!(Aff3)
+aff_rand_impl!(Aff3)
+aff_approx_eq_impl!(Aff3)
+aff_to_homogeneous_impl!(Aff3, Mat4)
+aff_from_homogeneous_impl!(Aff3, Mat3, Mat4, Vec3)
+aff_inv_impl!(Aff3)
+aff_transform_impl!(Aff3, Pnt3, Vec3)
+aff_transformation_impl!(Aff3)
+aff_translation_impl!(Aff3, Vec3)
+aff_mul_aff_impl!(Aff3)
+aff_mul_pnt_impl!(Aff3, Pnt3)
+aff_mul_vec_impl!(Aff3, Vec3)
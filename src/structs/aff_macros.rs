@@ -0,0 +1,309 @@
+#![macro_escape]
+
+macro_rules! aff_impl(
+    ($t: ident, $tm: ident, $tv: ident, $tiso: ident, $tsim: ident) => (
+        impl<N: Clone + BaseNum> $t<N> {
+            /// Creates a new affine transformation from a translation and a linear part.
+            #[inline]
+            pub fn new(translation: $tv<N>, linear: $tm<N>) -> $t<N> {
+                $t {
+                    linear:      linear,
+                    translation: translation
+                }
+            }
+        }
+
+        impl<N: Clone + BaseFloat> $t<N> {
+            /// Creates a new affine transformation from an isometry.
+            #[inline]
+            pub fn new_with_iso(iso: $tiso<N>) -> $t<N> {
+                $t::new(iso.translation, iso.rotation.submat().clone())
+            }
+
+            /// Creates a new affine transformation from a similarity.
+            #[inline]
+            pub fn new_with_sim(sim: $tsim<N>) -> $t<N> {
+                $t::new(sim.translation, *sim.rotation.submat() * sim.scale)
+            }
+        }
+    )
+)
+
+macro_rules! aff_one_impl(
+    ($t: ident) => (
+        impl<N: BaseNum + Clone> One for $t<N> {
+            #[inline]
+            fn one() -> $t<N> {
+                $t::new(::zero(), ::one())
+            }
+        }
+    )
+)
+
+macro_rules! aff_mul_aff_impl(
+    ($t: ident) => (
+        impl<N: BaseNum + Clone> Mul<$t<N>, $t<N>> for $t<N> {
+            #[inline]
+            fn mul(&self, right: &$t<N>) -> $t<N> {
+                $t::new(self.translation + self.linear * right.translation,
+                        self.linear * right.linear)
+            }
+        }
+    )
+)
+
+macro_rules! aff_mul_pnt_impl(
+    ($t: ident, $tp: ident) => (
+        impl<N: BaseNum + Clone> Mul<$tp<N>, $tp<N>> for $t<N> {
+            #[inline]
+            fn mul(&self, right: &$tp<N>) -> $tp<N> {
+                self.linear * *right + self.translation
+            }
+        }
+    )
+)
+
+macro_rules! aff_mul_vec_impl(
+    ($t: ident, $tv: ident) => (
+        impl<N: BaseNum + Clone> Mul<$tv<N>, $tv<N>> for $t<N> {
+            #[inline]
+            fn mul(&self, right: &$tv<N>) -> $tv<N> {
+                // vectors are not affected by the translation
+                self.linear * *right
+            }
+        }
+    )
+)
+
+macro_rules! aff_transform_impl(
+    ($t: ident, $tp: ident, $tv: ident) => (
+        impl<N: BaseNum + ApproxEq<N> + Clone> Transform<$tp<N>> for $t<N> {
+            #[inline]
+            fn transform(&self, p: &$tp<N>) -> $tp<N> {
+                *self * *p
+            }
+
+            #[inline]
+            fn inv_transform(&self, p: &$tp<N>) -> $tp<N> {
+                let inv: $t<N> = Inv::inv_cpy(self).expect(
+                    "Cannot invert a singular affine transformation.");
+
+                inv * *p
+            }
+        }
+
+        impl<N: BaseNum + ApproxEq<N> + Clone> Transform<$tv<N>> for $t<N> {
+            #[inline]
+            fn transform(&self, v: &$tv<N>) -> $tv<N> {
+                *self * *v
+            }
+
+            #[inline]
+            fn inv_transform(&self, v: &$tv<N>) -> $tv<N> {
+                let inv: $t<N> = Inv::inv_cpy(self).expect(
+                    "Cannot invert a singular affine transformation.");
+
+                inv * *v
+            }
+        }
+    )
+)
+
+macro_rules! aff_transformation_impl(
+    ($t: ident) => (
+        impl<N: BaseNum + ApproxEq<N> + Clone> Transformation<$t<N>> for $t<N> {
+            fn transformation(&self) -> $t<N> {
+                self.clone()
+            }
+
+            fn inv_transformation(&self) -> $t<N> {
+                Inv::inv_cpy(self).expect("Cannot invert a singular affine transformation.")
+            }
+
+            fn append_transformation(&mut self, t: &$t<N>) {
+                *self = *t * *self
+            }
+
+            fn append_transformation_cpy(aff: &$t<N>, t: &$t<N>) -> $t<N> {
+                *t * *aff
+            }
+
+            fn prepend_transformation(&mut self, t: &$t<N>) {
+                *self = *self * *t
+            }
+
+            fn prepend_transformation_cpy(aff: &$t<N>, t: &$t<N>) -> $t<N> {
+                *aff * *t
+            }
+
+            fn set_transformation(&mut self, t: $t<N>) {
+                *self = t
+            }
+        }
+    )
+)
+
+macro_rules! aff_translation_impl(
+    ($t: ident, $tv: ident) => (
+        impl<N: BaseNum + Clone> Translation<$tv<N>> for $t<N> {
+            #[inline]
+            fn translation(&self) -> $tv<N> {
+                self.translation.clone()
+            }
+
+            #[inline]
+            fn inv_translation(&self) -> $tv<N> {
+                -self.translation
+            }
+
+            #[inline]
+            fn append_translation(&mut self, t: &$tv<N>) {
+                self.translation = *t + self.translation
+            }
+
+            #[inline]
+            fn append_translation_cpy(aff: &$t<N>, t: &$tv<N>) -> $t<N> {
+                $t::new(*t + aff.translation, aff.linear.clone())
+            }
+
+            #[inline]
+            fn prepend_translation(&mut self, t: &$tv<N>) {
+                self.translation = self.translation + self.linear * *t
+            }
+
+            #[inline]
+            fn prepend_translation_cpy(aff: &$t<N>, t: &$tv<N>) -> $t<N> {
+                $t::new(aff.translation + aff.linear * *t, aff.linear.clone())
+            }
+
+            #[inline]
+            fn set_translation(&mut self, t: $tv<N>) {
+                self.translation = t
+            }
+        }
+    )
+)
+
+macro_rules! aff_inv_impl(
+    ($t: ident) => (
+        impl<N: BaseNum + ApproxEq<N> + Clone> Inv for $t<N> {
+            #[inline]
+            fn inv(&mut self) -> bool {
+                if !self.linear.inv() {
+                    return false
+                }
+
+                self.translation = self.linear * -self.translation;
+
+                true
+            }
+
+            #[inline]
+            fn inv_cpy(m: &$t<N>) -> Option<$t<N>> {
+                let mut res = m.clone();
+
+                if res.inv() {
+                    Some(res)
+                }
+                else {
+                    None
+                }
+            }
+        }
+    )
+)
+
+macro_rules! aff_to_homogeneous_impl(
+    ($t: ident, $th: ident) => (
+        impl<N: BaseNum + Clone> ToHomogeneous<$th<N>> for $t<N> {
+            fn to_homogeneous(&self) -> $th<N> {
+                let mut res = self.linear.to_homogeneous();
+
+                // copy the translation
+                let dim = Dim::dim(None::<$th<N>>);
+
+                res.set_col(dim - 1, self.translation.as_pnt().to_homogeneous().to_vec());
+
+                res
+            }
+        }
+    )
+)
+
+macro_rules! aff_from_homogeneous_impl(
+    ($t: ident, $tm: ident, $th: ident, $tv: ident) => (
+        impl<N: BaseNum + Clone> FromHomogeneous<$th<N>> for $t<N> {
+            /// Builds an affine transformation from an homogeneous matrix.
+            ///
+            /// The last row of the homogeneous matrix is ignored.
+            fn from(m: &$th<N>) -> $t<N> {
+                let linear: $tm<N> = FromHomogeneous::from(m);
+                let dim            = Dim::dim(None::<$th<N>>);
+                let translation    = m.col(dim - 1);
+                let mut t: $tv<N>  = ::zero();
+
+                for i in range(0u, dim - 1) {
+                    t[i] = translation[i].clone();
+                }
+
+                $t::new(t, linear)
+            }
+        }
+
+        impl<N: BaseNum + ApproxEq<N> + Clone> $t<N> {
+            /// Extracts an affine transformation from an homogeneous matrix.
+            ///
+            /// Returns `None` if the last row of `m` is not `(0, ..., 0, 1)`.
+            pub fn new_with_homogeneous(m: &$th<N>) -> Option<$t<N>> {
+                let dim = Dim::dim(None::<$th<N>>);
+                let row = m.row(dim - 1);
+
+                for i in range(0u, dim - 1) {
+                    if !::approx_eq(&row[i], &::zero()) {
+                        return None
+                    }
+                }
+
+                if !::approx_eq(&row[dim - 1], &::one()) {
+                    return None
+                }
+
+                Some(FromHomogeneous::from(m))
+            }
+        }
+    )
+)
+
+macro_rules! aff_approx_eq_impl(
+    ($t: ident) => (
+        impl<N: ApproxEq<N>> ApproxEq<N> for $t<N> {
+            #[inline]
+            fn approx_epsilon(_: Option<$t<N>>) -> N {
+                ApproxEq::approx_epsilon(None::<N>)
+            }
+
+            #[inline]
+            fn approx_eq(a: &$t<N>, b: &$t<N>) -> bool {
+                ApproxEq::approx_eq(&a.linear, &b.linear) &&
+                    ApproxEq::approx_eq(&a.translation, &b.translation)
+            }
+
+            #[inline]
+            fn approx_eq_eps(a: &$t<N>, b: &$t<N>, epsilon: &N) -> bool {
+                ApproxEq::approx_eq_eps(&a.linear, &b.linear, epsilon) &&
+                    ApproxEq::approx_eq_eps(&a.translation, &b.translation, epsilon)
+            }
+        }
+    )
+)
+
+macro_rules! aff_rand_impl(
+    ($t: ident) => (
+        impl<N: Rand + Clone + BaseNum> Rand for $t<N> {
+            #[inline]
+            fn rand<R: Rng>(rng: &mut R) -> $t<N> {
+                $t::new(rng.gen(), rng.gen())
+            }
+        }
+    )
+)
@@ -0,0 +1,309 @@
+//! Unit complex numbers.
+
+#![allow(missing_docs)] // we allow missing to avoid having to document the dispatch trait.
+
+use std::rand::{Rand, Rng};
+use structs::{Vec1, Vec2, Pnt2, Rot2, Mat2, Mat3};
+use traits::operations::{ApproxEq, Inv};
+use traits::structure::{Dim, BaseFloat, BaseNum, One};
+use traits::geometry::{Rotation, Rotate, Transform, AbsoluteRotate, RotationMatrix, ToHomogeneous};
+
+/// A unit complex number that can represent a 2D rotation.
+///
+/// Composing and renormalizing unit complex numbers is cheaper than doing the same with 2×2
+/// rotation matrices.
+#[deriving(Eq, PartialEq, Encodable, Decodable, Clone, Hash, Show)]
+pub struct UnitComplex<N> {
+    re: N,
+    im: N
+}
+
+impl<N: BaseFloat + Clone> UnitComplex<N> {
+    /// Creates a new unit complex number from a rotation angle in radian.
+    #[inline]
+    pub fn new(angle: Vec1<N>) -> UnitComplex<N> {
+        let (sin, cos) = angle.x.sin_cos();
+
+        UnitComplex {
+            re: cos,
+            im: sin
+        }
+    }
+
+    /// Creates a new unit complex number from a complex number.
+    ///
+    /// The input complex number will be normalized.
+    #[inline]
+    pub fn new_with_complex(re: N, im: N) -> UnitComplex<N> {
+        let mut res = UnitComplex {
+            re: re,
+            im: im
+        };
+
+        res.renormalize();
+
+        res
+    }
+
+    /// Creates a new unit complex number from a rotation matrix.
+    #[inline]
+    pub fn new_with_rot(rot: Rot2<N>) -> UnitComplex<N> {
+        UnitComplex::new_with_complex(rot.submat().m11.clone(), rot.submat().m21.clone())
+    }
+
+    /// Builds a rotation matrix from this unit complex number.
+    #[inline]
+    pub fn to_rot(&self) -> Rot2<N> {
+        unsafe {
+            Rot2::new_with_mat(
+                Mat2::new(
+                    self.re.clone(), -self.im,
+                    self.im.clone(), self.re.clone()
+                )
+            )
+        }
+    }
+
+    /// The rotation angle in `]-pi, pi]` represented by this unit complex number.
+    #[inline]
+    pub fn angle(&self) -> N {
+        self.im.atan2(self.re)
+    }
+
+    /// Renormalizes this complex number to remove the numerical drift accumulated after many
+    /// compositions.
+    #[inline]
+    pub fn renormalize(&mut self) {
+        let n = (self.re * self.re + self.im * self.im).sqrt();
+
+        self.re = self.re / n;
+        self.im = self.im / n;
+    }
+}
+
+impl<N> UnitComplex<N> {
+    /// Creates a new unit complex number from a complex number.
+    ///
+    /// This is unsafe because the input complex number will not be normalized.
+    #[inline]
+    pub unsafe fn new_with_unit_complex(re: N, im: N) -> UnitComplex<N> {
+        UnitComplex {
+            re: re,
+            im: im
+        }
+    }
+
+    /// The real part of this unit complex number, i.e., the cosinus of the rotation angle.
+    #[inline]
+    pub fn re<'a>(&'a self) -> &'a N {
+        &self.re
+    }
+
+    /// The imaginary part of this unit complex number, i.e., the sinus of the rotation angle.
+    #[inline]
+    pub fn im<'a>(&'a self) -> &'a N {
+        &self.im
+    }
+}
+
+impl<N: BaseNum + Clone> One for UnitComplex<N> {
+    #[inline]
+    fn one() -> UnitComplex<N> {
+        unsafe {
+            UnitComplex::new_with_unit_complex(::one(), ::zero())
+        }
+    }
+}
+
+impl<N: Clone + Neg<N>> Inv for UnitComplex<N> {
+    #[inline]
+    fn inv_cpy(m: &UnitComplex<N>) -> Option<UnitComplex<N>> {
+        let mut cpy = m.clone();
+
+        cpy.inv();
+        Some(cpy)
+    }
+
+    #[inline]
+    fn inv(&mut self) -> bool {
+        self.im = -self.im;
+
+        true
+    }
+}
+
+impl<N: Rand + BaseFloat + Clone> Rand for UnitComplex<N> {
+    #[inline]
+    fn rand<R: Rng>(rng: &mut R) -> UnitComplex<N> {
+        UnitComplex::new(rng.gen())
+    }
+}
+
+impl<N: ApproxEq<N>> ApproxEq<N> for UnitComplex<N> {
+    #[inline]
+    fn approx_epsilon(_: Option<UnitComplex<N>>) -> N {
+        ApproxEq::approx_epsilon(None::<N>)
+    }
+
+    #[inline]
+    fn approx_eq(a: &UnitComplex<N>, b: &UnitComplex<N>) -> bool {
+        ApproxEq::approx_eq(&a.re, &b.re) && ApproxEq::approx_eq(&a.im, &b.im)
+    }
+
+    #[inline]
+    fn approx_eq_eps(a: &UnitComplex<N>, b: &UnitComplex<N>, eps: &N) -> bool {
+        ApproxEq::approx_eq_eps(&a.re, &b.re, eps) && ApproxEq::approx_eq_eps(&a.im, &b.im, eps)
+    }
+}
+
+impl<N: BaseNum + Clone> Mul<UnitComplex<N>, UnitComplex<N>> for UnitComplex<N> {
+    #[inline]
+    fn mul(&self, right: &UnitComplex<N>) -> UnitComplex<N> {
+        UnitComplex {
+            re: self.re * right.re - self.im * right.im,
+            im: self.re * right.im + self.im * right.re
+        }
+    }
+}
+
+impl<N: BaseNum + Clone> Mul<Vec2<N>, Vec2<N>> for UnitComplex<N> {
+    #[inline]
+    fn mul(&self, right: &Vec2<N>) -> Vec2<N> {
+        Vec2::new(self.re * right.x - self.im * right.y, self.im * right.x + self.re * right.y)
+    }
+}
+
+impl<N: BaseNum + Clone> Mul<Pnt2<N>, Pnt2<N>> for UnitComplex<N> {
+    #[inline]
+    fn mul(&self, right: &Pnt2<N>) -> Pnt2<N> {
+        Pnt2::new(self.re * right.x - self.im * right.y, self.im * right.x + self.re * right.y)
+    }
+}
+
+impl<N: BaseNum + Clone> Mul<UnitComplex<N>, Vec2<N>> for Vec2<N> {
+    #[inline]
+    fn mul(&self, right: &UnitComplex<N>) -> Vec2<N> {
+        Vec2::new(right.re * self.x + right.im * self.y, right.re * self.y - right.im * self.x)
+    }
+}
+
+impl<N: BaseNum + Clone> Mul<UnitComplex<N>, Pnt2<N>> for Pnt2<N> {
+    #[inline]
+    fn mul(&self, right: &UnitComplex<N>) -> Pnt2<N> {
+        Pnt2::new(right.re * self.x + right.im * self.y, right.re * self.y - right.im * self.x)
+    }
+}
+
+impl<N: BaseFloat + Clone> Rotation<Vec1<N>> for UnitComplex<N> {
+    #[inline]
+    fn rotation(&self) -> Vec1<N> {
+        Vec1::new(self.angle())
+    }
+
+    #[inline]
+    fn inv_rotation(&self) -> Vec1<N> {
+        -self.rotation()
+    }
+
+    #[inline]
+    fn append_rotation(&mut self, rot: &Vec1<N>) {
+        *self = Rotation::append_rotation_cpy(self, rot)
+    }
+
+    #[inline]
+    fn append_rotation_cpy(t: &UnitComplex<N>, rot: &Vec1<N>) -> UnitComplex<N> {
+        UnitComplex::new(rot.clone()) * *t
+    }
+
+    #[inline]
+    fn prepend_rotation(&mut self, rot: &Vec1<N>) {
+        *self = Rotation::prepend_rotation_cpy(self, rot)
+    }
+
+    #[inline]
+    fn prepend_rotation_cpy(t: &UnitComplex<N>, rot: &Vec1<N>) -> UnitComplex<N> {
+        *t * UnitComplex::new(rot.clone())
+    }
+
+    #[inline]
+    fn set_rotation(&mut self, rot: Vec1<N>) {
+        *self = UnitComplex::new(rot)
+    }
+}
+
+impl<N: BaseNum + Clone> Rotate<Vec2<N>> for UnitComplex<N> {
+    #[inline]
+    fn rotate(&self, v: &Vec2<N>) -> Vec2<N> {
+        *self * *v
+    }
+
+    #[inline]
+    fn inv_rotate(&self, v: &Vec2<N>) -> Vec2<N> {
+        *v * *self
+    }
+}
+
+impl<N: BaseNum + Clone> Rotate<Pnt2<N>> for UnitComplex<N> {
+    #[inline]
+    fn rotate(&self, p: &Pnt2<N>) -> Pnt2<N> {
+        *self * *p
+    }
+
+    #[inline]
+    fn inv_rotate(&self, p: &Pnt2<N>) -> Pnt2<N> {
+        *p * *self
+    }
+}
+
+impl<N: BaseNum + Clone> Transform<Vec2<N>> for UnitComplex<N> {
+    #[inline]
+    fn transform(&self, v: &Vec2<N>) -> Vec2<N> {
+        *self * *v
+    }
+
+    #[inline]
+    fn inv_transform(&self, v: &Vec2<N>) -> Vec2<N> {
+        *v * *self
+    }
+}
+
+impl<N: BaseNum + Clone> Transform<Pnt2<N>> for UnitComplex<N> {
+    #[inline]
+    fn transform(&self, p: &Pnt2<N>) -> Pnt2<N> {
+        *self * *p
+    }
+
+    #[inline]
+    fn inv_transform(&self, p: &Pnt2<N>) -> Pnt2<N> {
+        *p * *self
+    }
+}
+
+impl<N: BaseFloat> AbsoluteRotate<Vec2<N>> for UnitComplex<N> {
+    #[inline]
+    fn absolute_rotate(&self, v: &Vec2<N>) -> Vec2<N> {
+        let re = ::abs(&self.re);
+        let im = ::abs(&self.im);
+
+        Vec2::new(re * v.x + im * v.y, im * v.x + re * v.y)
+    }
+}
+
+impl<N: BaseFloat + Clone> RotationMatrix<N, Vec2<N>, Vec1<N>, Rot2<N>> for UnitComplex<N> {
+    #[inline]
+    fn to_rot_mat(&self) -> Rot2<N> {
+        self.to_rot()
+    }
+}
+
+impl<N: BaseNum + Clone> ToHomogeneous<Mat3<N>> for UnitComplex<N> {
+    #[inline]
+    fn to_homogeneous(&self) -> Mat3<N> {
+        Mat3::new(
+            self.re.clone(), -self.im,        ::zero(),
+            self.im.clone(), self.re.clone(), ::zero(),
+            ::zero(),        ::zero(),        ::one())
+    }
+}
+
+dim_impl!(UnitComplex, 2)
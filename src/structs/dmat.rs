@@ -3,13 +3,16 @@
 #![allow(missing_docs)] // we hide doc to not have to document the $trhs double dispatch trait.
 
 use std::cmp;
-use std::rand::Rand;
+use std::rand::{Rand, Rng};
 use std::rand;
 use traits::operations::ApproxEq;
 use std::mem;
 use structs::dvec::DVec;
+use structs::random;
+use linalg;
 use traits::operations::{Inv, Transpose, Mean, Cov};
-use traits::structure::{Cast, ColSlice, RowSlice, Diag, Eye, Indexable, Shape, Zero, One, BaseNum};
+use traits::structure::{Cast, ColSlice, RowSlice, Diag, Eye, Indexable, Shape, Zero, One, BaseNum,
+                        BaseFloat};
 use std::fmt::{Show, Formatter, Result};
 
 
@@ -69,6 +72,53 @@ impl<N: Rand> DMat<N> {
     }
 }
 
+impl<N: BaseFloat + Clone> DMat<N> {
+    /// Samples a random orthogonal matrix, uniformly distributed with respect to the Haar measure
+    /// of the orthogonal group.
+    ///
+    /// # Arguments
+    /// * `dim` - The number of rows and columns of the matrix.
+    /// * `rng` - The random number generator used for the sampling.
+    pub fn new_random_orthogonal<R: Rng>(dim: uint, rng: &mut R) -> DMat<N> {
+        let gaussian: DMat<N> = DMat::from_fn(dim, dim, |_, _| random::normal(rng));
+        let (mut q, r)        = linalg::qr(&gaussian);
+
+        // The QR decomposition is made unique by forcing the diagonal of `r` to be positive.
+        // Without this, the distribution of `q` would not be uniform.
+        for j in range(0u, dim) {
+            if r[(j, j)] < ::zero() {
+                for i in range(0u, dim) {
+                    q[(i, j)] = -q[(i, j)];
+                }
+            }
+        }
+
+        q
+    }
+
+    /// Samples a random symmetric positive-definite matrix.
+    ///
+    /// The matrix eigenvectors are uniformly distributed, and its eigenvalues are uniformly
+    /// distributed in `[min_eig, max_eig)`.
+    ///
+    /// # Arguments
+    /// * `dim` - The number of rows and columns of the matrix.
+    /// * `min_eig` - The lower bound of the eigenvalues. Must be positive.
+    /// * `max_eig` - The upper bound of the eigenvalues.
+    /// * `rng` - The random number generator used for the sampling.
+    pub fn new_random_spd<R: Rng>(dim: uint, min_eig: N, max_eig: N, rng: &mut R) -> DMat<N> {
+        assert!(min_eig > ::zero() && min_eig <= max_eig,
+                "The eigenvalue bounds must satisfy `0 < min_eig <= max_eig`.");
+
+        let width      = max_eig - min_eig;
+        let q: DMat<N> = DMat::new_random_orthogonal(dim, rng);
+        let eigs       = DVec::from_fn(dim, |_| min_eig + width * random::uniform(rng));
+        let d: DMat<N> = Diag::from_diag(&eigs);
+
+        q * d * Transpose::transpose_cpy(&q)
+    }
+}
+
 impl<N: One + Clone> DMat<N> {
     /// Builds a matrix filled with a given constant.
     #[inline]
@@ -0,0 +1,420 @@
+//! Dual quaternion definition.
+
+#![allow(missing_docs)] // we allow missing to avoid having to document the dispatch trait.
+
+use std::num;
+use std::num::Float;
+use std::rand::{Rand, Rng};
+use structs::{Vec3, Pnt3, Mat4, Iso3};
+use structs::quat::{Quat, UnitQuat};
+use traits::operations::{ApproxEq, Inv};
+use traits::structure::{Dim, BaseFloat, BaseNum, One};
+use traits::geometry::{Norm, Transform, ToHomogeneous};
+
+/// A dual quaternion.
+///
+/// This is a quaternion with dual numbers as components: `real + ε * dual` where `ε² = 0`.
+#[deriving(Eq, PartialEq, Encodable, Decodable, Clone, Hash, Rand, Show)]
+pub struct DualQuat<N> {
+    /// The real part of the dual quaternion.
+    pub real: Quat<N>,
+    /// The dual part of the dual quaternion.
+    pub dual: Quat<N>
+}
+
+impl<N> DualQuat<N> {
+    /// Creates a new dual quaternion from its real and dual parts.
+    #[inline]
+    pub fn new(real: Quat<N>, dual: Quat<N>) -> DualQuat<N> {
+        DualQuat {
+            real: real,
+            dual: dual
+        }
+    }
+}
+
+impl<N: Neg<N>> DualQuat<N> {
+    /// Replaces this dual quaternion by its quaternion conjugate.
+    ///
+    /// Both the real and the dual parts are conjugated.
+    #[inline]
+    pub fn conjugate(&mut self) {
+        self.real.conjugate();
+        self.dual.conjugate();
+    }
+}
+
+impl<N: BaseNum + Clone> One for DualQuat<N> {
+    #[inline]
+    fn one() -> DualQuat<N> {
+        DualQuat::new(Quat::new(::one(), ::zero(), ::zero(), ::zero()), ::zero())
+    }
+}
+
+impl<N: ApproxEq<N>> ApproxEq<N> for DualQuat<N> {
+    #[inline]
+    fn approx_epsilon(_: Option<DualQuat<N>>) -> N {
+        ApproxEq::approx_epsilon(None::<N>)
+    }
+
+    #[inline]
+    fn approx_eq(a: &DualQuat<N>, b: &DualQuat<N>) -> bool {
+        ApproxEq::approx_eq(&a.real, &b.real) && ApproxEq::approx_eq(&a.dual, &b.dual)
+    }
+
+    #[inline]
+    fn approx_eq_eps(a: &DualQuat<N>, b: &DualQuat<N>, eps: &N) -> bool {
+        ApproxEq::approx_eq_eps(&a.real, &b.real, eps) &&
+            ApproxEq::approx_eq_eps(&a.dual, &b.dual, eps)
+    }
+}
+
+impl<N: Add<N, N>> Add<DualQuat<N>, DualQuat<N>> for DualQuat<N> {
+    #[inline]
+    fn add(&self, right: &DualQuat<N>) -> DualQuat<N> {
+        DualQuat::new(self.real + right.real, self.dual + right.dual)
+    }
+}
+
+impl<N: Sub<N, N>> Sub<DualQuat<N>, DualQuat<N>> for DualQuat<N> {
+    #[inline]
+    fn sub(&self, right: &DualQuat<N>) -> DualQuat<N> {
+        DualQuat::new(self.real - right.real, self.dual - right.dual)
+    }
+}
+
+impl<N: Neg<N>> Neg<DualQuat<N>> for DualQuat<N> {
+    #[inline]
+    fn neg(&self) -> DualQuat<N> {
+        DualQuat::new(-self.real, -self.dual)
+    }
+}
+
+impl<N: Mul<N, N> + Sub<N, N> + Add<N, N>> Mul<DualQuat<N>, DualQuat<N>> for DualQuat<N> {
+    #[inline]
+    fn mul(&self, right: &DualQuat<N>) -> DualQuat<N> {
+        DualQuat::new(self.real * right.real, self.real * right.dual + self.dual * right.real)
+    }
+}
+
+impl<N: Mul<N, N>> Mul<N, DualQuat<N>> for DualQuat<N> {
+    #[inline]
+    fn mul(&self, right: &N) -> DualQuat<N> {
+        DualQuat::new(self.real * *right, self.dual * *right)
+    }
+}
+
+/// A unit dual quaternion that can represent a rigid motion, i.e., a 3D rotation followed by a
+/// 3D translation.
+#[deriving(Eq, PartialEq, Encodable, Decodable, Clone, Hash, Show)]
+pub struct UnitDualQuat<N> {
+    q: DualQuat<N>
+}
+
+impl<N: BaseFloat + Clone> UnitDualQuat<N> {
+    /// Creates a new unit dual quaternion from a translation and the axis-angle representation
+    /// of a rotation.
+    ///
+    /// The rotation is applied first.
+    #[inline]
+    pub fn new(translation: Vec3<N>, axisangle: Vec3<N>) -> UnitDualQuat<N> {
+        UnitDualQuat::new_with_rotquat(translation, UnitQuat::new(axisangle))
+    }
+
+    /// Creates a new unit dual quaternion from a translation and a rotation represented by a unit
+    /// quaternion.
+    ///
+    /// The rotation is applied first.
+    #[inline]
+    pub fn new_with_rotquat(translation: Vec3<N>, rotation: UnitQuat<N>) -> UnitDualQuat<N> {
+        let _0_5: N = num::cast(0.5f64).unwrap();
+        let real    = rotation.quat().clone();
+        let t       = Quat::new(::zero(), translation.x, translation.y, translation.z);
+
+        unsafe {
+            UnitDualQuat::new_with_unit_dual_quat(DualQuat::new(real, t * real * _0_5))
+        }
+    }
+
+    /// Creates a new unit dual quaternion from a dual quaternion.
+    ///
+    /// The input dual quaternion will be normalized. Its real part must not be zero.
+    #[inline]
+    pub fn new_with_dual_quat(q: DualQuat<N>) -> UnitDualQuat<N> {
+        let mut res = UnitDualQuat {
+            q: q
+        };
+
+        res.renormalize();
+
+        res
+    }
+
+    /// The rotational part of the rigid motion represented by this unit dual quaternion.
+    #[inline]
+    pub fn rotation(&self) -> UnitQuat<N> {
+        unsafe {
+            UnitQuat::new_with_unit_quat(self.q.real.clone())
+        }
+    }
+
+    /// The translational part of the rigid motion represented by this unit dual quaternion.
+    #[inline]
+    pub fn translation(&self) -> Vec3<N> {
+        let _2: N  = num::cast(2.0f64).unwrap();
+        let mut rc = self.q.real.clone();
+
+        rc.conjugate();
+
+        let t = self.q.dual * rc;
+
+        Vec3::new(t.i * _2, t.j * _2, t.k * _2)
+    }
+
+    /// Converts this unit dual quaternion to an isometry using a rotation matrix.
+    #[inline]
+    pub fn to_iso(&self) -> Iso3<N> {
+        Iso3::new_with_rotmat(self.translation(), self.rotation().to_rot())
+    }
+
+    /// Renormalizes this dual quaternion to remove the numerical drift accumulated after many
+    /// compositions.
+    ///
+    /// The real part is normalized and the dual part is made orthogonal to the real part.
+    pub fn renormalize(&mut self) {
+        let n    = Norm::norm(&self.q.real);
+        let real = self.q.real / n;
+        let dual = self.q.dual / n;
+
+        self.q.dual = dual - real * quat_dot(&real, &dual);
+        self.q.real = real;
+    }
+
+    /// Raises this unit dual quaternion to the power `t`.
+    ///
+    /// This scales both the angle and the translation along the screw axis of the rigid motion by
+    /// `t`.
+    pub fn powf(&self, t: N) -> UnitDualQuat<N> {
+        let _0_5: N  = num::cast(0.5f64).unwrap();
+        let _2: N    = num::cast(2.0f64).unwrap();
+        let real     = &self.q.real;
+        let dual     = &self.q.dual;
+        let rv       = real.vector();
+        let dv       = dual.vector();
+        let sin_half = Norm::norm(rv);
+
+        if sin_half < Float::epsilon() {
+            // No rotation: this is a pure translation which is scaled linearly.
+            return UnitDualQuat::new_with_rotquat(self.translation() * t, ::one())
+        }
+
+        // Screw parameters: rotation angle, pitch, axis direction, and axis moment.
+        let half   = sin_half.atan2(real.w);
+        let pitch  = -_2 * dual.w / sin_half;
+        let dir    = *rv / sin_half;
+        let moment = (*dv - dir * (pitch * _0_5 * real.w)) / sin_half;
+
+        let half_t  = half * t;
+        let pitch_t = pitch * t;
+        let (s, c)  = half_t.sin_cos();
+        let v       = dir * s;
+        let dv      = moment * s + dir * (pitch_t * _0_5 * c);
+
+        unsafe {
+            UnitDualQuat::new_with_unit_dual_quat(
+                DualQuat::new(
+                    Quat::new(c, v.x, v.y, v.z),
+                    Quat::new(-pitch_t * _0_5 * s, dv.x, dv.y, dv.z)))
+        }
+    }
+
+    /// Screw-linear interpolation (ScLERP) between `self` (for `t = 0`) and `other` (for
+    /// `t = 1`).
+    ///
+    /// The interpolation follows the shortest screw motion between the two rigid motions, i.e.,
+    /// with constant rotational and translational velocities along a fixed screw axis.
+    pub fn sclerp(&self, other: &UnitDualQuat<N>, t: N) -> UnitDualQuat<N> {
+        let mut delta = self.inv_cpy_unchecked() * *other;
+
+        if delta.q.real.w < ::zero() {
+            // Take the shortest path.
+            delta.q = -delta.q;
+        }
+
+        *self * delta.powf(t)
+    }
+
+    /// Dual quaternion linear blending (DLB) of several rigid motions.
+    ///
+    /// This computes the normalized weighted sum of `qs`. Each dual quaternion is first flipped to
+    /// the same hemisphere as `qs[0]` so that the blend follows the shortest paths. Fails if `qs`
+    /// is empty, if `qs` and `weights` do not have the same length, or if the weighted sum has a
+    /// zero real part.
+    pub fn blend(qs: &[UnitDualQuat<N>], weights: &[N]) -> UnitDualQuat<N> {
+        assert!(qs.len() == weights.len(),
+                "The number of weights must match the number of dual quaternions.");
+        assert!(!qs.is_empty(), "Cannot blend an empty set of dual quaternions.");
+
+        let pivot   = &qs[0].q.real;
+        let mut acc = DualQuat::new(::zero(), ::zero());
+
+        for (q, w) in qs.iter().zip(weights.iter()) {
+            if quat_dot(pivot, &q.q.real) < ::zero() {
+                acc = acc - q.q * *w;
+            }
+            else {
+                acc = acc + q.q * *w;
+            }
+        }
+
+        assert!(Norm::norm(&acc.real) != ::zero(),
+                "Cannot blend dual quaternions whose weighted sum has a zero real part.");
+
+        UnitDualQuat::new_with_dual_quat(acc)
+    }
+
+    #[inline]
+    fn inv_cpy_unchecked(&self) -> UnitDualQuat<N> {
+        let mut res = self.clone();
+
+        res.q.conjugate();
+
+        res
+    }
+}
+
+impl<N: BaseFloat + ApproxEq<N> + Clone> UnitDualQuat<N> {
+    /// Creates a new unit dual quaternion from an isometry using a rotation matrix.
+    #[inline]
+    pub fn new_with_iso(iso: Iso3<N>) -> UnitDualQuat<N> {
+        UnitDualQuat::new_with_rotquat(iso.translation, UnitQuat::new_with_rot(iso.rotation))
+    }
+}
+
+impl<N> UnitDualQuat<N> {
+    /// Creates a new unit dual quaternion from a dual quaternion.
+    ///
+    /// This is unsafe because the input dual quaternion will not be normalized.
+    #[inline]
+    pub unsafe fn new_with_unit_dual_quat(q: DualQuat<N>) -> UnitDualQuat<N> {
+        UnitDualQuat {
+            q: q
+        }
+    }
+
+    /// The `DualQuat` representation of this unit dual quaternion.
+    #[inline]
+    pub fn dual_quat<'a>(&'a self) -> &'a DualQuat<N> {
+        &self.q
+    }
+}
+
+impl<N: BaseNum + Clone> One for UnitDualQuat<N> {
+    #[inline]
+    fn one() -> UnitDualQuat<N> {
+        unsafe {
+            UnitDualQuat::new_with_unit_dual_quat(::one())
+        }
+    }
+}
+
+impl<N: Clone + Neg<N>> Inv for UnitDualQuat<N> {
+    #[inline]
+    fn inv_cpy(m: &UnitDualQuat<N>) -> Option<UnitDualQuat<N>> {
+        let mut cpy = m.clone();
+
+        cpy.inv();
+        Some(cpy)
+    }
+
+    #[inline]
+    fn inv(&mut self) -> bool {
+        self.q.conjugate();
+
+        true
+    }
+}
+
+impl<N: Rand + BaseFloat + Clone> Rand for UnitDualQuat<N> {
+    #[inline]
+    fn rand<R: Rng>(rng: &mut R) -> UnitDualQuat<N> {
+        UnitDualQuat::new(rng.gen(), rng.gen())
+    }
+}
+
+impl<N: ApproxEq<N>> ApproxEq<N> for UnitDualQuat<N> {
+    #[inline]
+    fn approx_epsilon(_: Option<UnitDualQuat<N>>) -> N {
+        ApproxEq::approx_epsilon(None::<N>)
+    }
+
+    #[inline]
+    fn approx_eq(a: &UnitDualQuat<N>, b: &UnitDualQuat<N>) -> bool {
+        ApproxEq::approx_eq(&a.q, &b.q)
+    }
+
+    #[inline]
+    fn approx_eq_eps(a: &UnitDualQuat<N>, b: &UnitDualQuat<N>, eps: &N) -> bool {
+        ApproxEq::approx_eq_eps(&a.q, &b.q, eps)
+    }
+}
+
+impl<N: BaseNum + Clone> Mul<UnitDualQuat<N>, UnitDualQuat<N>> for UnitDualQuat<N> {
+    #[inline]
+    fn mul(&self, right: &UnitDualQuat<N>) -> UnitDualQuat<N> {
+        UnitDualQuat { q: self.q * right.q }
+    }
+}
+
+impl<N: BaseFloat + Clone> Mul<Pnt3<N>, Pnt3<N>> for UnitDualQuat<N> {
+    #[inline]
+    fn mul(&self, right: &Pnt3<N>) -> Pnt3<N> {
+        self.rotation() * *right + self.translation()
+    }
+}
+
+impl<N: BaseFloat + Clone> Mul<Vec3<N>, Vec3<N>> for UnitDualQuat<N> {
+    #[inline]
+    fn mul(&self, right: &Vec3<N>) -> Vec3<N> {
+        // vectors are not affected by the translation
+        self.rotation() * *right
+    }
+}
+
+impl<N: BaseFloat + Clone> Transform<Pnt3<N>> for UnitDualQuat<N> {
+    #[inline]
+    fn transform(&self, p: &Pnt3<N>) -> Pnt3<N> {
+        *self * *p
+    }
+
+    #[inline]
+    fn inv_transform(&self, p: &Pnt3<N>) -> Pnt3<N> {
+        (*p - self.translation()) * self.rotation()
+    }
+}
+
+impl<N: BaseFloat + Clone> Transform<Vec3<N>> for UnitDualQuat<N> {
+    #[inline]
+    fn transform(&self, v: &Vec3<N>) -> Vec3<N> {
+        *self * *v
+    }
+
+    #[inline]
+    fn inv_transform(&self, v: &Vec3<N>) -> Vec3<N> {
+        *v * self.rotation()
+    }
+}
+
+impl<N: BaseFloat + Clone> ToHomogeneous<Mat4<N>> for UnitDualQuat<N> {
+    #[inline]
+    fn to_homogeneous(&self) -> Mat4<N> {
+        self.to_iso().to_homogeneous()
+    }
+}
+
+dim_impl!(UnitDualQuat, 3)
+
+#[inline]
+fn quat_dot<N: BaseNum>(a: &Quat<N>, b: &Quat<N>) -> N {
+    a.w * b.w + a.i * b.i + a.j * b.j + a.k * b.k
+}
@@ -2,13 +2,18 @@
 
 #![allow(missing_docs)] // we hide doc to not have to document the $trhs double dispatch trait.
 
-use std::rand::Rand;
+use std::num;
+use std::num::Float;
+use std::rand::{Rand, Rng};
 use std::rand;
 use std::slice::{Items, MutItems};
-use traits::operations::ApproxEq;
+use std::vec::MoveItems;
+use traits::operations::{ApproxEq, Absolute, CompFloat, CompReduce};
 use std::iter::FromIterator;
 use traits::geometry::{Dot, Norm};
-use traits::structure::{Iterable, IterableMut, Indexable, Shape, BaseFloat, BaseNum, Zero, One};
+use traits::structure::{Iterable, IterableMut, Indexable, Shape, CompMap, BaseFloat, BaseNum, Zero,
+                        One};
+use structs::random;
 
 /// Heap allocated, dynamically sized vector.
 #[deriving(Eq, PartialEq, Show, Clone)]
@@ -108,6 +108,36 @@ macro_rules! dvec_impl(
             }
         }
 
+        impl<N: BaseFloat + Clone> $dvec<N> {
+            /// Samples a unit vector uniformly distributed on the unit sphere.
+            ///
+            /// # Arguments
+            /// * `dim` - The dimension of the vector.
+            /// * `rng` - The random number generator used for the sampling.
+            pub fn new_random_unit<R: Rng>(dim: uint, rng: &mut R) -> $dvec<N> {
+                loop {
+                    let mut v = $dvec::from_fn(dim, |_| random::normal(rng));
+
+                    if !Norm::normalize(&mut v).is_zero() {
+                        return v
+                    }
+                }
+            }
+
+            /// Samples a vector uniformly distributed inside of the unit ball.
+            ///
+            /// # Arguments
+            /// * `dim` - The dimension of the vector.
+            /// * `rng` - The random number generator used for the sampling.
+            pub fn new_random_in_ball<R: Rng>(dim: uint, rng: &mut R) -> $dvec<N> {
+                let dir: $dvec<N> = $dvec::new_random_unit(dim, rng);
+                let u: N          = random::uniform(rng);
+                let fdim: N       = num::cast(dim as f64).unwrap();
+
+                dir * u.powf(::one::<N>() / fdim)
+            }
+        }
+
         impl<N> Iterable<N> for $dvec<N> {
             #[inline]
             fn iter<'l>(&'l self) -> Items<'l, N> {
@@ -140,6 +170,18 @@ macro_rules! dvec_impl(
                 res
             }
 
+            /// An iterator through the canonical basis for the given dimension.
+            #[inline]
+            pub fn canonical_basis_iter(dim: uint) -> MoveItems<$dvec<N>> {
+                $dvec::canonical_basis_with_dim(dim).into_iter()
+            }
+
+            /// An iterator through a basis of the space orthogonal to the vector.
+            #[inline]
+            pub fn orthonormal_subspace_basis_iter(&self) -> MoveItems<$dvec<N>> {
+                self.orthogonal_subspace_basis().into_iter()
+            }
+
             /// Computes a basis of the space orthogonal to the vector. If the input vector is of dimension
             /// `n`, this will return `n - 1` vectors.
             pub fn orthogonal_subspace_basis(&self) -> Vec<$dvec<N>> {
@@ -283,6 +325,128 @@ macro_rules! dvec_impl(
             }
         }
 
+        impl<N: Absolute<N> + Zero> Absolute<$dvec<N>> for $dvec<N> {
+            #[inline]
+            fn abs(m: &$dvec<N>) -> $dvec<N> {
+                FromIterator::from_iter(m.as_slice().iter().map(|e| ::abs(e)))
+            }
+        }
+
+        impl<N: Clone + Zero> CompMap<N> for $dvec<N> {
+            #[inline]
+            fn map(&self, f: |N| -> N) -> $dvec<N> {
+                FromIterator::from_iter(self.as_slice().iter().map(|e| f(e.clone())))
+            }
+
+            #[inline]
+            fn zip_map(&self, other: &$dvec<N>, f: |N, N| -> N) -> $dvec<N> {
+                assert!(self.len() == other.len());
+
+                let zip = self.as_slice().iter().zip(other.as_slice().iter());
+
+                FromIterator::from_iter(zip.map(|(a, b)| f(a.clone(), b.clone())))
+            }
+        }
+
+        impl<N: BaseFloat> CompFloat<N> for $dvec<N> {
+            #[inline]
+            fn floor(&self) -> $dvec<N> {
+                FromIterator::from_iter(self.as_slice().iter().map(|e| e.floor()))
+            }
+
+            #[inline]
+            fn ceil(&self) -> $dvec<N> {
+                FromIterator::from_iter(self.as_slice().iter().map(|e| e.ceil()))
+            }
+
+            #[inline]
+            fn round(&self) -> $dvec<N> {
+                FromIterator::from_iter(self.as_slice().iter().map(|e| e.round()))
+            }
+
+            #[inline]
+            fn signum(&self) -> $dvec<N> {
+                FromIterator::from_iter(self.as_slice().iter().map(|e| e.signum()))
+            }
+
+            #[inline]
+            fn clamp(&self, min: &$dvec<N>, max: &$dvec<N>) -> $dvec<N> {
+                assert!(self.len() == min.len() && self.len() == max.len());
+
+                let bounds = min.as_slice().iter().zip(max.as_slice().iter());
+                let zip    = self.as_slice().iter().zip(bounds);
+
+                FromIterator::from_iter(zip.map(|(e, (a, b))| e.max(*a).min(*b)))
+            }
+
+            #[inline]
+            fn lerp(&self, other: &$dvec<N>, t: &N) -> $dvec<N> {
+                assert!(self.len() == other.len());
+
+                let zip = self.as_slice().iter().zip(other.as_slice().iter());
+
+                FromIterator::from_iter(zip.map(|(a, b)| *a + (*b - *a) * *t))
+            }
+        }
+
+        impl<N: BaseNum + PartialOrd + Clone> CompReduce<N> for $dvec<N> {
+            #[inline]
+            fn sum(&self) -> N {
+                let mut res: N = ::zero();
+
+                for e in self.as_slice().iter() {
+                    res = res + e.clone();
+                }
+
+                res
+            }
+
+            #[inline]
+            fn product(&self) -> N {
+                let mut res: N = ::one();
+
+                for e in self.as_slice().iter() {
+                    res = res * e.clone();
+                }
+
+                res
+            }
+
+            #[inline]
+            fn min_component(&self) -> (uint, N) {
+                assert!(self.len() != 0, "Cannot compute the minimum of an empty vector.");
+
+                let mut imin = 0u;
+                let mut min  = self.as_slice()[0].clone();
+
+                for (i, e) in self.as_slice().iter().enumerate() {
+                    if *e < min {
+                        imin = i;
+                        min  = e.clone();
+                    }
+                }
+
+                (imin, min)
+            }
+
+            #[inline]
+            fn max_component(&self) -> (uint, N) {
+                assert!(self.len() != 0, "Cannot compute the maximum of an empty vector.");
+
+                let mut imax = 0u;
+                let mut max  = self.as_slice()[0].clone();
+
+                for (i, e) in self.as_slice().iter().enumerate() {
+                    if *e > max {
+                        imax = i;
+                        max  = e.clone();
+                    }
+                }
+
+                (imax, max)
+            }
+        }
+
         impl<N: Mul<N, N> + Zero> Mul<N, $dvec<N>> for $dvec<N> {
             #[inline]
             fn mul(&self, right: &N) -> $dvec<N> {
@@ -0,0 +1,247 @@
+//! Mass properties of rigid bodies.
+
+#![allow(missing_docs)] // we allow missing to avoid having to document the dispatch trait.
+
+use std::num;
+use std::num::Float;
+use structs::vec::Vec3;
+use structs::pnt::Pnt3;
+use structs::mat::Mat3;
+use structs::rot::Rot3;
+use structs::quat::UnitQuat;
+use structs::iso::Iso3;
+use linalg;
+use traits::operations::{ApproxEq, Det, Outer, Transpose};
+use traits::structure::{BaseFloat, Col, Diag, Zero};
+use traits::geometry::{Norm, Orig, Transform};
+
+/// The mass properties of a rigid body.
+///
+/// This is made of the mass of the rigid body, of its center of mass, and of its inertia tensor
+/// about its center of mass. Both the center of mass and the inertia tensor are expressed in the
+/// reference frame of the rigid body.
+#[deriving(Eq, PartialEq, Encodable, Decodable, Clone, Show)]
+pub struct Inertia3<N> {
+    /// The mass of the rigid body.
+    pub mass:           N,
+    /// The center of mass of the rigid body.
+    pub center_of_mass: Pnt3<N>,
+    /// The inertia tensor of the rigid body about its center of mass.
+    pub tensor:         Mat3<N>
+}
+
+impl<N> Inertia3<N> {
+    /// Creates new mass properties from a mass, a center of mass, and an inertia tensor about the
+    /// center of mass.
+    #[inline]
+    pub fn new(mass: N, center_of_mass: Pnt3<N>, tensor: Mat3<N>) -> Inertia3<N> {
+        Inertia3 {
+            mass:           mass,
+            center_of_mass: center_of_mass,
+            tensor:         tensor
+        }
+    }
+}
+
+impl<N: BaseFloat + Clone> Inertia3<N> {
+    /// The mass properties of a point mass located at `p`.
+    #[inline]
+    pub fn new_point_mass(mass: N, p: Pnt3<N>) -> Inertia3<N> {
+        Inertia3::new(mass, p, ::zero())
+    }
+
+    /// The mass properties of a solid ball centered at the origin.
+    pub fn new_ball(mass: N, radius: N) -> Inertia3<N> {
+        let _0_4: N = num::cast(0.4f64).unwrap();
+        let i       = mass * radius * radius * _0_4;
+
+        Inertia3::new(mass, Orig::orig(), Diag::from_diag(&Vec3::new(i, i, i)))
+    }
+
+    /// The mass properties of a solid cuboid centered at the origin and aligned with the axis.
+    pub fn new_cuboid(mass: N, half_extents: Vec3<N>) -> Inertia3<N> {
+        let _3: N = num::cast(3.0f64).unwrap();
+        let sq    = half_extents * half_extents;
+        let k     = mass / _3;
+
+        Inertia3::new(mass,
+                      Orig::orig(),
+                      Diag::from_diag(&Vec3::new(k * (sq.y + sq.z),
+                                                 k * (sq.x + sq.z),
+                                                 k * (sq.x + sq.y))))
+    }
+
+    /// The mass properties of a solid cylinder centered at the origin and with its principal axis
+    /// aligned with the `y` axis.
+    pub fn new_cylinder(mass: N, half_height: N, radius: N) -> Inertia3<N> {
+        let _2: N  = num::cast(2.0f64).unwrap();
+        let _3: N  = num::cast(3.0f64).unwrap();
+        let _4: N  = num::cast(4.0f64).unwrap();
+        let _12: N = num::cast(12.0f64).unwrap();
+        let sqr    = radius * radius;
+        let iy     = mass * sqr / _2;
+        let ixz    = mass * (sqr * _3 + half_height * half_height * _4) / _12;
+
+        Inertia3::new(mass, Orig::orig(), Diag::from_diag(&Vec3::new(ixz, iy, ixz)))
+    }
+
+    /// The inertia tensor of the rigid body about the point `p`.
+    ///
+    /// This is computed using the parallel axis theorem.
+    pub fn tensor_at(&self, p: &Pnt3<N>) -> Mat3<N> {
+        let d              = self.center_of_mass - *p;
+        let id: Mat3<N>    = ::one();
+        let outer: Mat3<N> = Outer::outer(&d, &d);
+
+        self.tensor + (id * Norm::sqnorm(&d) - outer) * self.mass
+    }
+}
+
+impl<N: BaseFloat + ApproxEq<N> + Clone> Inertia3<N> {
+    /// The principal moments of inertia, and the rotation which maps the principal axes of
+    /// inertia to the reference frame.
+    ///
+    /// The principal moments are sorted in increasing order, and satisfy
+    /// `self.tensor == rot * from_diag(&moments) * transpose(rot)`.
+    pub fn principal_axes(&self) -> (Vec3<N>, Rot3<N>) {
+        let eps: N = Float::epsilon();
+        let trace  = self.tensor.m11 + self.tensor.m22 + self.tensor.m33;
+
+        let (mut axes, mut moments): (Mat3<N>, Vec3<N>) =
+            linalg::eigen_sym(&self.tensor, &(eps * trace.abs()), 50);
+
+        // Sort the moments, swapping the axes accordingly.
+        for i in range(0u, 2) {
+            for j in range(0u, 2 - i) {
+                if moments[j] > moments[j + 1] {
+                    let mj  = moments[j].clone();
+                    let cj  = axes.col(j);
+                    let cj1 = axes.col(j + 1);
+
+                    moments[j]     = moments[j + 1].clone();
+                    moments[j + 1] = mj;
+                    axes.set_col(j, cj1);
+                    axes.set_col(j + 1, cj);
+                }
+            }
+        }
+
+        // Ensure the axes form a right-handed frame.
+        if Det::det(&axes) < ::zero() {
+            let c = axes.col(2);
+
+            axes.set_col(2, -c);
+        }
+
+        (moments, unsafe { Rot3::new_with_mat(axes) })
+    }
+}
+
+/// The combined mass properties of two rigid bodies.
+///
+/// The center of mass of the result is the origin if the total mass is zero.
+impl<N: BaseFloat + Clone> Add<Inertia3<N>, Inertia3<N>> for Inertia3<N> {
+    fn add(&self, right: &Inertia3<N>) -> Inertia3<N> {
+        let mass = self.mass + right.mass;
+
+        let center_of_mass = if mass.is_zero() {
+            Orig::orig()
+        }
+        else {
+            (self.center_of_mass * self.mass + *right.center_of_mass.as_vec() * right.mass) / mass
+        };
+
+        Inertia3::new(mass,
+                      center_of_mass.clone(),
+                      self.tensor_at(&center_of_mass) + right.tensor_at(&center_of_mass))
+    }
+}
+
+impl<N: BaseFloat + Clone> Zero for Inertia3<N> {
+    #[inline]
+    fn zero() -> Inertia3<N> {
+        Inertia3::new(::zero(), Orig::orig(), ::zero())
+    }
+
+    #[inline]
+    fn is_zero(&self) -> bool {
+        self.mass.is_zero() && self.center_of_mass.is_orig() && self.tensor.is_zero()
+    }
+}
+
+impl<N: ApproxEq<N>> ApproxEq<N> for Inertia3<N> {
+    #[inline]
+    fn approx_epsilon(_: Option<Inertia3<N>>) -> N {
+        ApproxEq::approx_epsilon(None::<N>)
+    }
+
+    #[inline]
+    fn approx_eq(a: &Inertia3<N>, b: &Inertia3<N>) -> bool {
+        ApproxEq::approx_eq(&a.mass, &b.mass) &&
+            ApproxEq::approx_eq(&a.center_of_mass, &b.center_of_mass) &&
+            ApproxEq::approx_eq(&a.tensor, &b.tensor)
+    }
+
+    #[inline]
+    fn approx_eq_eps(a: &Inertia3<N>, b: &Inertia3<N>, eps: &N) -> bool {
+        ApproxEq::approx_eq_eps(&a.mass, &b.mass, eps) &&
+            ApproxEq::approx_eq_eps(&a.center_of_mass, &b.center_of_mass, eps) &&
+            ApproxEq::approx_eq_eps(&a.tensor, &b.tensor, eps)
+    }
+}
+
+/// Expresses mass properties given in the local frame of the rotation in the global frame.
+impl<N: BaseFloat + Clone> Transform<Inertia3<N>> for Rot3<N> {
+    #[inline]
+    fn transform(&self, i: &Inertia3<N>) -> Inertia3<N> {
+        Inertia3::new(i.mass.clone(), *self * i.center_of_mass, rotate_tensor(self, &i.tensor))
+    }
+
+    #[inline]
+    fn inv_transform(&self, i: &Inertia3<N>) -> Inertia3<N> {
+        let inv = Transpose::transpose_cpy(self);
+
+        inv.transform(i)
+    }
+}
+
+/// Expresses mass properties given in the local frame of the rotation in the global frame.
+impl<N: BaseFloat + Clone> Transform<Inertia3<N>> for UnitQuat<N> {
+    #[inline]
+    fn transform(&self, i: &Inertia3<N>) -> Inertia3<N> {
+        self.to_rot().transform(i)
+    }
+
+    #[inline]
+    fn inv_transform(&self, i: &Inertia3<N>) -> Inertia3<N> {
+        self.to_rot().inv_transform(i)
+    }
+}
+
+/// Expresses mass properties given in the local frame of the isometry in the global frame.
+impl<N: BaseFloat + Clone> Transform<Inertia3<N>> for Iso3<N> {
+    #[inline]
+    fn transform(&self, i: &Inertia3<N>) -> Inertia3<N> {
+        let mut res = self.rotation.transform(i);
+
+        res.center_of_mass = res.center_of_mass + self.translation;
+
+        res
+    }
+
+    #[inline]
+    fn inv_transform(&self, i: &Inertia3<N>) -> Inertia3<N> {
+        let mut res = i.clone();
+
+        res.center_of_mass = res.center_of_mass - self.translation;
+
+        self.rotation.inv_transform(&res)
+    }
+}
+
+#[inline]
+fn rotate_tensor<N: BaseFloat + Clone>(rot: &Rot3<N>, tensor: &Mat3<N>) -> Mat3<N> {
+    let r = rot.submat();
+
+    *r * *tensor * Transpose::transpose_cpy(r)
+}
@@ -3,15 +3,18 @@
 #![allow(missing_docs)]
 
 use std::rand::{Rand, Rng};
-use structs::mat::{Mat3, Mat4, Mat5};
+use structs::mat::{Mat2, Mat3, Mat4, Mat5};
 use traits::structure::{Cast, Dim, Col, BaseFloat, BaseNum, One};
 use traits::operations::{Inv, ApproxEq};
 use traits::geometry::{RotationMatrix, Rotation, Rotate, AbsoluteRotate, Transform, Transformation,
-                       Translate, Translation, ToHomogeneous};
+                       Translate, Translation, ToHomogeneous, Norm};
 
 use structs::vec::{Vec1, Vec2, Vec3, Vec4};
 use structs::pnt::{Pnt2, Pnt3, Pnt4};
 use structs::rot::{Rot2, Rot3, Rot4};
+use structs::complex::UnitComplex;
+use structs::quat::UnitQuat;
+use structs::dual_quat::UnitDualQuat;
 
 
 /// Two dimensional isometry.
@@ -26,6 +29,18 @@ pub struct Iso2<N> {
     pub translation: Vec2<N>
 }
 
+/// Two dimensional isometry with a rotation represented by a unit complex number.
+///
+/// This is the composition of a rotation followed by a translation. This is cheaper to compose
+/// and to renormalize than `Iso2`.
+#[deriving(Eq, PartialEq, Encodable, Decodable, Clone, Show)]
+pub struct ComplexIso2<N> {
+    /// The rotation applicable by this isometry.
+    pub rotation:    UnitComplex<N>,
+    /// The translation applicable by this isometry.
+    pub translation: Vec2<N>
+}
+
 /// Three dimensional isometry.
 ///
 /// This is the composition of a rotation followed by a translation.
@@ -38,6 +53,18 @@ pub struct Iso3<N> {
     pub translation: Vec3<N>
 }
 
+/// Three dimensional isometry with a rotation represented by a unit quaternion.
+///
+/// This is the composition of a rotation followed by a translation. This is cheaper to compose
+/// and to renormalize than `Iso3`.
+#[deriving(Eq, PartialEq, Encodable, Decodable, Clone, Show)]
+pub struct QuatIso3<N> {
+    /// The rotation applicable by this isometry.
+    pub rotation:    UnitQuat<N>,
+    /// The translation applicable by this isometry.
+    pub translation: Vec3<N>
+}
+
 /// Four dimensional isometry.
 ///
 /// Isometries conserve angles and distances, hence do not allow shearing nor scaling.
@@ -78,6 +105,118 @@ impl<N: Clone + BaseFloat> Iso3<N> {
         self.rotation.look_at_z(&(*at - *eye), up);
         self.translation = eye.as_vec().clone();
     }
+
+    /// Integrates the constant velocities `linvel` and `angvel` during the time step `dt`.
+    ///
+    /// Both velocities are expressed in the global frame: `linvel` is the velocity of the
+    /// origin of the local frame, i.e., of the translation of this isometry, and `angvel` is the
+    /// angular velocity around this origin. The rotation is integrated using the exponential map.
+    #[inline]
+    pub fn integrate(&self, linvel: &Vec3<N>, angvel: &Vec3<N>, dt: N) -> Iso3<N> {
+        Iso3::new_with_rotmat(self.translation + *linvel * dt, self.rotation.integrate(angvel, dt))
+    }
+}
+
+impl<N: Clone + BaseFloat> ComplexIso2<N> {
+    /// Creates a new isometry from an isometry using a rotation matrix.
+    #[inline]
+    pub fn new_with_iso(iso: Iso2<N>) -> ComplexIso2<N> {
+        ComplexIso2::new_with_rotmat(iso.translation, UnitComplex::new_with_rot(iso.rotation))
+    }
+
+    /// Converts this isometry to an isometry using a rotation matrix.
+    #[inline]
+    pub fn to_iso(&self) -> Iso2<N> {
+        Iso2::new_with_rotmat(self.translation.clone(), self.rotation.to_rot())
+    }
+}
+
+impl<N: Cast<f64> + BaseFloat + BaseNum + Clone>
+RotationMatrix<N, Vec2<N>, Vec1<N>, Rot2<N>> for ComplexIso2<N> {
+    #[inline]
+    fn to_rot_mat(&self) -> Rot2<N> {
+        self.rotation.to_rot()
+    }
+}
+
+impl<N: Clone + BaseFloat + ApproxEq<N>> QuatIso3<N> {
+    /// Creates a new isometry from an isometry using a rotation matrix.
+    #[inline]
+    pub fn new_with_iso(iso: Iso3<N>) -> QuatIso3<N> {
+        QuatIso3::new_with_rotmat(iso.translation, UnitQuat::new_with_rot(iso.rotation))
+    }
+}
+
+impl<N: Clone + BaseFloat> QuatIso3<N> {
+    /// Converts this isometry to an isometry using a rotation matrix.
+    #[inline]
+    pub fn to_iso(&self) -> Iso3<N> {
+        Iso3::new_with_rotmat(self.translation.clone(), self.rotation.to_rot())
+    }
+
+    /// Integrates the constant velocities `linvel` and `angvel` during the time step `dt`.
+    ///
+    /// See `Iso3::integrate` for details.
+    #[inline]
+    pub fn integrate(&self, linvel: &Vec3<N>, angvel: &Vec3<N>, dt: N) -> QuatIso3<N> {
+        QuatIso3::new_with_rotmat(self.translation + *linvel * dt,
+                                  self.rotation.integrate(angvel, dt))
+    }
+}
+
+impl<N: Cast<f64> + BaseFloat + BaseNum + Clone>
+RotationMatrix<N, Vec3<N>, Vec3<N>, Rot3<N>> for QuatIso3<N> {
+    #[inline]
+    fn to_rot_mat(&self) -> Rot3<N> {
+        self.rotation.to_rot()
+    }
+}
+
+impl<N: BaseFloat + Clone> ToHomogeneous<Mat4<N>> for QuatIso3<N> {
+    fn to_homogeneous(&self) -> Mat4<N> {
+        let mut res = self.rotation.to_homogeneous();
+
+        res.set_col(3, self.translation.as_pnt().to_homogeneous().to_vec());
+
+        res
+    }
+}
+
+impl<N: Clone + BaseFloat + ApproxEq<N>> Iso2<N> {
+    /// Interpolates between two isometries along the screw motion that maps `self` to `other`.
+    ///
+    /// In 2D, this is a rotation around a fixed point at constant angular velocity, or a
+    /// translation at constant velocity if `self` and `other` have the same rotation. Returns
+    /// `self` for `t = 0` and `other` for `t = 1`.
+    pub fn sclerp(&self, other: &Iso2<N>, t: N) -> Iso2<N> {
+        let delta = Inv::inv_cpy(self).unwrap() * *other;
+        let angle = delta.rotation.rotation();
+        let rot_t = Rot2::new(angle * t);
+
+        if ::approx_eq(&angle.x, &::zero()) {
+            return *self * Iso2::new_with_rotmat(delta.translation * t, rot_t)
+        }
+
+        // The center of rotation is the fixed point `c` of `delta`, i.e., `R * c + t = c`.
+        let i_r    = ::one::<Mat2<N>>() - *delta.rotation.submat();
+        let center = Inv::inv_cpy(&i_r).unwrap() * delta.translation;
+
+        *self * Iso2::new_with_rotmat(center - rot_t * center, rot_t)
+    }
+}
+
+impl<N: Clone + BaseFloat + ApproxEq<N>> Iso3<N> {
+    /// Interpolates between two isometries along the screw motion that maps `self` to `other`.
+    ///
+    /// This is a rotation around a fixed axis at constant angular velocity, combined with a
+    /// translation along the same axis at constant velocity. Returns `self` for `t = 0` and
+    /// `other` for `t = 1`.
+    pub fn sclerp(&self, other: &Iso3<N>, t: N) -> Iso3<N> {
+        let a = UnitDualQuat::new_with_iso(self.clone());
+        let b = UnitDualQuat::new_with_iso(other.clone());
+
+        a.sclerp(&b, t).to_iso()
+    }
 }
 
 impl<N> Iso4<N> {
@@ -109,7 +248,29 @@ translation_impl!(Iso2, Vec2)
 translate_impl!(Iso2, Pnt2)
 iso_mul_iso_impl!(Iso2)
 iso_mul_pnt_impl!(Iso2, Pnt2)
+iso_mul_vec_impl!(Iso2, Vec2)
 pnt_mul_iso_impl!(Iso2, Pnt2)
+iso_interpolation_impl!(Iso2, Rot2, Vec2, Vec1)
+
+iso_impl!(ComplexIso2, UnitComplex, Vec2, Vec1)
+rotation_impl!(ComplexIso2, UnitComplex, Vec1)
+dim_impl!(ComplexIso2, 2)
+one_impl!(ComplexIso2)
+absolute_rotate_impl!(ComplexIso2, Vec2)
+rand_impl!(ComplexIso2)
+approx_eq_impl!(ComplexIso2)
+to_homogeneous_impl!(ComplexIso2, Mat3)
+inv_impl!(ComplexIso2)
+transform_impl!(ComplexIso2, Pnt2)
+transformation_impl!(ComplexIso2)
+rotate_impl!(ComplexIso2, Vec2)
+translation_impl!(ComplexIso2, Vec2)
+translate_impl!(ComplexIso2, Pnt2)
+iso_mul_iso_impl!(ComplexIso2)
+iso_mul_pnt_impl!(ComplexIso2, Pnt2)
+iso_mul_vec_impl!(ComplexIso2, Vec2)
+pnt_mul_iso_impl!(ComplexIso2, Pnt2)
+iso_interpolation_impl!(ComplexIso2, UnitComplex, Vec2, Vec1)
 
 iso_impl!(Iso3, Rot3, Vec3, Vec3)
 rotation_matrix_impl!(Iso3, Rot3, Vec3, Vec3)
@@ -128,7 +289,28 @@ translation_impl!(Iso3, Vec3)
 translate_impl!(Iso3, Pnt3)
 iso_mul_iso_impl!(Iso3)
 iso_mul_pnt_impl!(Iso3, Pnt3)
+iso_mul_vec_impl!(Iso3, Vec3)
 pnt_mul_iso_impl!(Iso3, Pnt3)
+iso_interpolation_impl!(Iso3, Rot3, Vec3, Vec3)
+
+iso_impl!(QuatIso3, UnitQuat, Vec3, Vec3)
+rotation_impl!(QuatIso3, UnitQuat, Vec3)
+dim_impl!(QuatIso3, 3)
+one_impl!(QuatIso3)
+absolute_rotate_impl!(QuatIso3, Vec3)
+rand_impl!(QuatIso3)
+approx_eq_impl!(QuatIso3)
+inv_impl!(QuatIso3)
+transform_impl!(QuatIso3, Pnt3)
+transformation_impl!(QuatIso3)
+rotate_impl!(QuatIso3, Vec3)
+translation_impl!(QuatIso3, Vec3)
+translate_impl!(QuatIso3, Pnt3)
+iso_mul_iso_impl!(QuatIso3)
+iso_mul_pnt_impl!(QuatIso3, Pnt3)
+iso_mul_vec_impl!(QuatIso3, Vec3)
+pnt_mul_iso_impl!(QuatIso3, Pnt3)
+iso_interpolation_impl!(QuatIso3, UnitQuat, Vec3, Vec3)
 
 // iso_impl!(Iso4, Rot4, Vec4, Vec4)
 // rotation_matrix_impl!(Iso4, Rot4, Vec4, Vec4)
@@ -83,6 +83,18 @@ macro_rules! iso_mul_pnt_impl(
     )
 )
 
+macro_rules! iso_mul_vec_impl(
+    ($t: ident, $tv: ident) => (
+        impl<N: BaseNum + Clone> Mul<$tv<N>, $tv<N>> for $t<N> {
+            #[inline]
+            fn mul(&self, right: &$tv<N>) -> $tv<N> {
+                // vectors are not affected by the translation
+                self.rotation * *right
+            }
+        }
+    )
+)
+
 macro_rules! pnt_mul_iso_impl(
     ($t: ident, $tv: ident) => (
         impl<N: Clone + BaseNum> Mul<$t<N>, $tv<N>> for $tv<N> {
@@ -355,3 +367,68 @@ macro_rules! absolute_rotate_impl(
         }
     )
 )
+
+macro_rules! iso_interpolation_impl(
+    ($t: ident, $trot: ident, $tv: ident, $tav: ident) => (
+        impl<N: Cast<f64> + BaseFloat + Clone> $t<N> {
+            /// Interpolates between two isometries using a linear interpolation of their
+            /// translations and a spherical linear interpolation of their rotations.
+            ///
+            /// Returns `self` for `t = 0` and `other` for `t = 1`.
+            pub fn lerp_slerp(&self, other: &$t<N>, t: N) -> $t<N> {
+                let _1: N          = ::one();
+                let translation    = self.translation * (_1 - t) + other.translation * t;
+                let inv_rot        = Inv::inv_cpy(&self.rotation).unwrap();
+                let delta: $tav<N> = (inv_rot * other.rotation).rotation();
+
+                $t::new_with_rotmat(translation, self.rotation * $trot::new(delta * t))
+            }
+
+            /// Computes the weighted average of a set of isometries.
+            ///
+            /// The translations are averaged linearly and the rotations are averaged with respect
+            /// to the geodesic distance on the rotation group. The weights must be non-negative
+            /// and the rotations must be close enough to each other (less than `pi` apart) for
+            /// the average to be unique. Fails if `isos` is empty, if `isos` and `weights` do not
+            /// have the same length, or if the weights sum up to zero.
+            pub fn weighted_average(isos: &[$t<N>], weights: &[N]) -> $t<N> {
+                assert!(isos.len() == weights.len(),
+                        "The number of weights must match the number of isometries.");
+                assert!(!isos.is_empty(), "Cannot average an empty set of isometries.");
+
+                let mut total: N            = ::zero();
+                let mut translation: $tv<N> = ::zero();
+
+                for (iso, w) in isos.iter().zip(weights.iter()) {
+                    total       = total + *w;
+                    translation = translation + iso.translation * *w;
+                }
+
+                assert!(!::is_zero(&total), "The sum of the weights must not be zero.");
+
+                translation = translation / total;
+
+                // Fixed-point iteration converging to the Karcher mean of the rotations.
+                let eps: N       = Cast::from(1.0e-12);
+                let mut rotation = isos[0].rotation.clone();
+
+                for _ in range(0u, 100) {
+                    let inv_rot = Inv::inv_cpy(&rotation).unwrap();
+                    let mut delta: $tav<N> = ::zero();
+
+                    for (iso, w) in isos.iter().zip(weights.iter()) {
+                        delta = delta + (inv_rot * iso.rotation).rotation() * (*w / total);
+                    }
+
+                    rotation = rotation * $trot::new(delta.clone());
+
+                    if Norm::sqnorm(&delta) <= eps {
+                        break
+                    }
+                }
+
+                $t::new_with_rotmat(translation, rotation)
+            }
+        }
+    )
+)
@@ -0,0 +1,266 @@
+//! Lie group utilities for 3D rotations and rigid body motions.
+//!
+//! The tangent space of the group of 3D rotations (SO(3)) is parametrized by axis-angle vectors
+//! `phi`. The tangent space of the group of 3D rigid body motions (SE(3)) is parametrized by
+//! twists `xi` stored as `Vec6`: the translational part `rho = (xi.x, xi.y, xi.z)` comes first,
+//! the rotational part `phi = (xi.w, xi.a, xi.b)` comes last. This is the layout expected by the
+//! cross matrix (`hat`) and `Vee` operators of `Vec6` and `Mat4`.
+
+use std::num;
+use std::num::Float;
+use structs::vec::{Vec3, Vec6};
+use structs::mat::{Mat3, Mat6};
+use structs::rot::Rot3;
+use structs::quat::UnitQuat;
+use structs::iso::Iso3;
+use traits::structure::{Cast, BaseFloat, BaseNum};
+use traits::geometry::{Norm, Rotation, CrossMatrix};
+
+impl<N: BaseFloat + Clone + Cast<f64>> Rot3<N> {
+    /// The exponential map of SO(3): the rotation of `phi.norm()` radians around `phi`.
+    #[inline]
+    pub fn exp(phi: &Vec3<N>) -> Rot3<N> {
+        Rot3::new(phi.clone())
+    }
+
+    /// The logarithm map of SO(3): the axis-angle representation of this rotation.
+    ///
+    /// The norm of the result is the rotation angle, in `[0, pi]`.
+    #[inline]
+    pub fn log(&self) -> Vec3<N> {
+        self.rotation()
+    }
+
+    /// The adjoint representation of this rotation, i.e., its rotation matrix.
+    ///
+    /// This maps a tangent vector expressed in the local frame of the rotation to the global
+    /// frame: `r * Rot3::exp(&phi) * r⁻¹ == Rot3::exp(&(r.adjoint() * phi))`.
+    #[inline]
+    pub fn adjoint(&self) -> Mat3<N> {
+        self.submat().clone()
+    }
+
+    /// The left Jacobian of SO(3) at `phi`.
+    ///
+    /// To the first order, `Rot3::exp(&(phi + d)) == Rot3::exp(&(J * d)) * Rot3::exp(&phi)`
+    /// where `J` is the left Jacobian.
+    pub fn left_jacobian(phi: &Vec3<N>) -> Mat3<N> {
+        let sqang = Norm::sqnorm(phi);
+
+        let (a, b) = if small_angle(&sqang) {
+            (cst::<N>(0.5) - sqang / cst(24.0), cst::<N>(1.0 / 6.0) - sqang / cst(120.0))
+        }
+        else {
+            let ang        = sqang.sqrt();
+            let (sin, cos) = ang.sin_cos();
+            let _1: N      = ::one();
+
+            ((_1 - cos) / sqang, (ang - sin) / (sqang * ang))
+        };
+
+        let p: Mat3<N> = CrossMatrix::cross_matrix(phi);
+
+        ::one::<Mat3<N>>() + p * a + p * p * b
+    }
+
+    /// The right Jacobian of SO(3) at `phi`.
+    ///
+    /// To the first order, `Rot3::exp(&(phi + d)) == Rot3::exp(&phi) * Rot3::exp(&(J * d))`
+    /// where `J` is the right Jacobian.
+    #[inline]
+    pub fn right_jacobian(phi: &Vec3<N>) -> Mat3<N> {
+        Rot3::left_jacobian(&-*phi)
+    }
+
+    /// The inverse of the left Jacobian of SO(3) at `phi`.
+    ///
+    /// The result is not defined if the norm of `phi` is a nonzero multiple of `2 * pi`.
+    pub fn inv_left_jacobian(phi: &Vec3<N>) -> Mat3<N> {
+        let sqang = Norm::sqnorm(phi);
+
+        let c = if small_angle(&sqang) {
+            cst::<N>(1.0 / 12.0) + sqang / cst(720.0)
+        }
+        else {
+            let ang        = sqang.sqrt();
+            let _1: N      = ::one();
+            let _2: N      = cst(2.0);
+            let (sin, cos) = (ang / _2).sin_cos();
+
+            _1 / sqang - cos / (sin * _2 * ang)
+        };
+
+        let p: Mat3<N> = CrossMatrix::cross_matrix(phi);
+
+        ::one::<Mat3<N>>() - p * cst::<N>(0.5) + p * p * c
+    }
+
+    /// The inverse of the right Jacobian of SO(3) at `phi`.
+    ///
+    /// The result is not defined if the norm of `phi` is a nonzero multiple of `2 * pi`.
+    #[inline]
+    pub fn inv_right_jacobian(phi: &Vec3<N>) -> Mat3<N> {
+        Rot3::inv_left_jacobian(&-*phi)
+    }
+}
+
+impl<N: BaseFloat + Clone> UnitQuat<N> {
+    /// The exponential map of SO(3): the rotation of `phi.norm()` radians around `phi`.
+    #[inline]
+    pub fn exp(phi: &Vec3<N>) -> UnitQuat<N> {
+        UnitQuat::new(phi.clone())
+    }
+
+    /// The logarithm map of SO(3): the axis-angle representation of this rotation.
+    ///
+    /// The norm of the result is the rotation angle, in `[0, pi]`.
+    #[inline]
+    pub fn log(&self) -> Vec3<N> {
+        self.rotation()
+    }
+}
+
+impl<N: BaseFloat + Clone + Cast<f64>> Iso3<N> {
+    /// The exponential map of SE(3): the isometry obtained by following the twist `xi` during a
+    /// unit of time.
+    pub fn exp(xi: &Vec6<N>) -> Iso3<N> {
+        let (rho, phi) = split_twist(xi);
+
+        Iso3::new_with_rotmat(Rot3::left_jacobian(&phi) * rho, Rot3::exp(&phi))
+    }
+
+    /// The logarithm map of SE(3): the twist `xi` such that `Iso3::exp(&xi) == *self`.
+    ///
+    /// The norm of the rotational part of the result is the rotation angle, in `[0, pi]`.
+    pub fn log(&self) -> Vec6<N> {
+        let phi = self.rotation.log();
+        let rho = Rot3::inv_left_jacobian(&phi) * self.translation;
+
+        join_twist(&rho, &phi)
+    }
+
+    /// The 6×6 adjoint representation of this isometry.
+    ///
+    /// This maps a twist expressed in the local frame of the isometry to the global frame:
+    /// `m * Iso3::exp(&xi) * m⁻¹ == Iso3::exp(&(m.adjoint() * xi))`.
+    pub fn adjoint(&self) -> Mat6<N> {
+        let r  = self.rotation.submat();
+        let tr = CrossMatrix::cross_matrix(&self.translation) * *r;
+
+        mat6_from_blocks(r, &tr, &::zero(), r)
+    }
+
+    /// The left Jacobian of SE(3) at `xi`.
+    ///
+    /// To the first order, `Iso3::exp(&(xi + d)) == Iso3::exp(&(J * d)) * Iso3::exp(&xi)`
+    /// where `J` is the left Jacobian.
+    pub fn left_jacobian(xi: &Vec6<N>) -> Mat6<N> {
+        let (rho, phi) = split_twist(xi);
+        let j = Rot3::left_jacobian(&phi);
+
+        mat6_from_blocks(&j, &se3_q(&rho, &phi), &::zero(), &j)
+    }
+
+    /// The right Jacobian of SE(3) at `xi`.
+    ///
+    /// To the first order, `Iso3::exp(&(xi + d)) == Iso3::exp(&xi) * Iso3::exp(&(J * d))`
+    /// where `J` is the right Jacobian.
+    #[inline]
+    pub fn right_jacobian(xi: &Vec6<N>) -> Mat6<N> {
+        Iso3::left_jacobian(&-*xi)
+    }
+
+    /// The inverse of the left Jacobian of SE(3) at `xi`.
+    ///
+    /// The result is not defined if the rotation angle of `xi` is a nonzero multiple of `2 * pi`.
+    pub fn inv_left_jacobian(xi: &Vec6<N>) -> Mat6<N> {
+        let (rho, phi) = split_twist(xi);
+        let inv_j = Rot3::inv_left_jacobian(&phi);
+        let q     = se3_q(&rho, &phi);
+
+        mat6_from_blocks(&inv_j, &(::zero::<Mat3<N>>() - inv_j * q * inv_j), &::zero(), &inv_j)
+    }
+
+    /// The inverse of the right Jacobian of SE(3) at `xi`.
+    ///
+    /// The result is not defined if the rotation angle of `xi` is a nonzero multiple of `2 * pi`.
+    #[inline]
+    pub fn inv_right_jacobian(xi: &Vec6<N>) -> Mat6<N> {
+        Iso3::inv_left_jacobian(&-*xi)
+    }
+}
+
+#[inline]
+fn cst<N: BaseFloat>(x: f64) -> N {
+    num::cast(x).unwrap()
+}
+
+// Below this squared angle, the closed-form coefficients of the Jacobians suffer from
+// catastrophic cancellation and are replaced by their Taylor expansion.
+#[inline]
+fn small_angle<N: BaseFloat>(sqang: &N) -> bool {
+    let eps: N = Float::epsilon();
+
+    *sqang < eps.sqrt()
+}
+
+#[inline]
+fn split_twist<N: Clone>(xi: &Vec6<N>) -> (Vec3<N>, Vec3<N>) {
+    (Vec3::new(xi.x.clone(), xi.y.clone(), xi.z.clone()),
+     Vec3::new(xi.w.clone(), xi.a.clone(), xi.b.clone()))
+}
+
+#[inline]
+fn join_twist<N: Clone>(rho: &Vec3<N>, phi: &Vec3<N>) -> Vec6<N> {
+    Vec6::new(rho.x.clone(), rho.y.clone(), rho.z.clone(),
+              phi.x.clone(), phi.y.clone(), phi.z.clone())
+}
+
+fn mat6_from_blocks<N: BaseNum + Clone>(m11: &Mat3<N>, m12: &Mat3<N>,
+                                        m21: &Mat3<N>, m22: &Mat3<N>) -> Mat6<N> {
+    let mut res: Mat6<N> = ::zero();
+
+    for i in range(0u, 3) {
+        for j in range(0u, 3) {
+            res[(i, j)]         = m11[(i, j)].clone();
+            res[(i, j + 3)]     = m12[(i, j)].clone();
+            res[(i + 3, j)]     = m21[(i, j)].clone();
+            res[(i + 3, j + 3)] = m22[(i, j)].clone();
+        }
+    }
+
+    res
+}
+
+// The upper-right block of the left Jacobian of SE(3).
+fn se3_q<N: BaseFloat + Clone>(rho: &Vec3<N>, phi: &Vec3<N>) -> Mat3<N> {
+    let sqang = Norm::sqnorm(phi);
+
+    let (c1, c2, c3) = if small_angle(&sqang) {
+        (cst::<N>(1.0 / 6.0)   - sqang / cst(120.0),
+         cst::<N>(1.0 / 24.0)  - sqang / cst(720.0),
+         cst::<N>(1.0 / 120.0) - sqang / cst(2520.0))
+    }
+    else {
+        let ang        = sqang.sqrt();
+        let (sin, cos) = ang.sin_cos();
+        let _2: N      = cst(2.0);
+        let _3: N      = cst(3.0);
+        let sqang2     = sqang * sqang;
+
+        ((ang - sin) / (sqang * ang),
+         (sqang + _2 * cos - _2) / (_2 * sqang2),
+         (_2 * ang - _3 * sin + ang * cos) / (_2 * sqang2 * ang))
+    };
+
+    let r: Mat3<N> = CrossMatrix::cross_matrix(rho);
+    let p: Mat3<N> = CrossMatrix::cross_matrix(phi);
+    let pr  = p * r;
+    let rp  = r * p;
+    let prp = pr * p;
+
+    r * cst::<N>(0.5) +
+    (pr + rp + prp) * c1 +
+    (p * pr + rp * p - prp * cst::<N>(3.0)) * c2 +
+    (prp * p + p * prp) * c3
+}
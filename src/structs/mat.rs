@@ -7,6 +7,7 @@ use traits::operations::ApproxEq;
 use std::slice::{Items, MutItems};
 use structs::vec::{Vec1, Vec2, Vec3, Vec4, Vec5, Vec6};
 use structs::pnt::{Pnt1, Pnt4, Pnt5, Pnt6};
+use structs::dmat::DMat;
 use structs::dvec::{DVec1, DVec2, DVec3, DVec4, DVec5, DVec6};
 
 use traits::structure::{Cast, Row, Col, Iterable, IterableMut, Dim, Indexable,
@@ -38,6 +39,7 @@ eye_impl!(Mat1, 1, m11)
 
 mat_impl!(Mat1, m11)
 as_array_impl!(Mat1, 1)
+dmat_conversion_impl!(Mat1, 1, 1)
 mat_cast_impl!(Mat1, m11)
 add_impl!(Mat1, m11)
 sub_impl!(Mat1, m11)
@@ -84,6 +86,7 @@ eye_impl!(Mat2, 2, m11, m22)
 mat_impl!(Mat2, m11, m12,
                 m21, m22)
 as_array_impl!(Mat2, 2)
+dmat_conversion_impl!(Mat2, 2, 2)
 mat_cast_impl!(Mat2, m11, m12,
                      m21, m22)
 add_impl!(Mat2, m11, m12, m21, m22)
@@ -134,6 +137,7 @@ mat_impl!(Mat3, m11, m12, m13,
                 m21, m22, m23,
                 m31, m32, m33)
 as_array_impl!(Mat3, 3)
+dmat_conversion_impl!(Mat3, 3, 3)
 mat_cast_impl!(Mat3, m11, m12, m13,
                      m21, m22, m23,
                      m31, m32, m33)
@@ -220,6 +224,7 @@ mat_impl!(Mat4,
   m41, m42, m43, m44
 )
 as_array_impl!(Mat4, 4)
+dmat_conversion_impl!(Mat4, 4, 4)
 mat_cast_impl!(Mat4,
   m11, m12, m13, m14,
   m21, m22, m23, m24,
@@ -322,6 +327,7 @@ mat_impl!(Mat5,
   m51, m52, m53, m54, m55
 )
 as_array_impl!(Mat5, 5)
+dmat_conversion_impl!(Mat5, 5, 5)
 mat_cast_impl!(Mat5,
   m11, m12, m13, m14, m15,
   m21, m22, m23, m24, m25,
@@ -438,6 +444,7 @@ mat_impl!(Mat6,
   m61, m62, m63, m64, m65, m66
 )
 as_array_impl!(Mat6, 6)
+dmat_conversion_impl!(Mat6, 6, 6)
 mat_cast_impl!(Mat6,
   m11, m12, m13, m14, m15, m16,
   m21, m22, m23, m24, m25, m26,
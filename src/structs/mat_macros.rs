@@ -667,8 +667,8 @@ macro_rules! from_homogeneous_impl(
         fn from(m: &$t2<N>) -> $t<N> {
             let mut res: $t<N> = ::one();
 
-            for i in range(0u, $dim2) {
-                for j in range(0u, $dim2) {
+            for i in range(0u, $dim) {
+                for j in range(0u, $dim) {
                     res.set((i, j), m.at((i, j)))
                 }
             }
@@ -711,3 +711,37 @@ macro_rules! eigen_qr_impl(
         }
     )
 )
+
+macro_rules! dmat_conversion_impl(
+    ($t: ident, $nrows: expr, $ncols: expr) => (
+        impl<N: Clone> $t<N> {
+            /// Converts this matrix to a dynamically sized matrix.
+            #[inline]
+            pub fn to_dmat(&self) -> DMat<N> {
+                DMat::from_fn($nrows, $ncols, |i, j| self[(i, j)].clone())
+            }
+        }
+
+        impl<N: Clone + Zero> $t<N> {
+            /// Builds a matrix from the components of a dynamically sized matrix.
+            ///
+            /// Returns `None` if the shape of `m` is not the same as the shape of `Self`.
+            #[inline]
+            pub fn from_dmat(m: &DMat<N>) -> Option<$t<N>> {
+                if m.nrows() != $nrows || m.ncols() != $ncols {
+                    return None
+                }
+
+                let mut res: $t<N> = ::zero();
+
+                for i in range(0u, $nrows) {
+                    for j in range(0u, $ncols) {
+                        res[(i, j)] = m[(i, j)].clone();
+                    }
+                }
+
+                Some(res)
+            }
+        }
+    )
+)
@@ -5,12 +5,27 @@ pub use self::dvec::{DVec, DVec1, DVec2, DVec3, DVec4, DVec5, DVec6};
 pub use self::vec::{Vec0, Vec1, Vec2, Vec3, Vec4, Vec5, Vec6};
 pub use self::pnt::{Pnt0, Pnt1, Pnt2, Pnt3, Pnt4, Pnt5, Pnt6};
 pub use self::mat::{Identity, Mat1, Mat2, Mat3, Mat4, Mat5, Mat6};
+pub use self::rect_mat::{Mat1x2, Mat1x3, Mat1x4, Mat1x5, Mat1x6,
+                         Mat2x1, Mat2x3, Mat2x4, Mat2x5, Mat2x6,
+                         Mat3x1, Mat3x2, Mat3x4, Mat3x5, Mat3x6,
+                         Mat4x1, Mat4x2, Mat4x3, Mat4x5, Mat4x6,
+                         Mat5x1, Mat5x2, Mat5x3, Mat5x4, Mat5x6,
+                         Mat6x1, Mat6x2, Mat6x3, Mat6x4, Mat6x5};
 pub use self::rot::{Rot2, Rot3, Rot4};
-pub use self::iso::{Iso2, Iso3, Iso4};
+pub use self::iso::{Iso2, Iso3, Iso4, ComplexIso2, QuatIso3};
+pub use self::inertia::Inertia3;
+pub use self::sim::{Sim2, Sim3};
+pub use self::aff::{Aff2, Aff3};
 pub use self::persp::{Persp3, PerspMat3};
 pub use self::ortho::{Ortho3, OrthoMat3};
 pub use self::quat::{Quat, UnitQuat};
+pub use self::complex::UnitComplex;
+pub use self::dual_quat::{DualQuat, UnitDualQuat};
+pub use self::spatial::{Twist, Wrench};
+pub use self::aabb::Aabb;
+pub use self::shape::{Ray, Line, Segment, Plane, Triangle, Tetrahedron};
 
+mod random;
 mod dmat;
 mod dvec_macros;
 mod dvec;
@@ -21,12 +36,26 @@ mod pnt;
 mod quat;
 mod mat_macros;
 mod mat;
+mod rect_mat_macros;
+mod rect_mat;
 mod rot_macros;
 mod rot;
+mod complex;
 mod iso_macros;
 mod iso;
+mod inertia;
+mod sim_macros;
+mod sim;
+mod aff_macros;
+mod aff;
+mod dual_quat;
+mod lie;
+mod spatial_macros;
+mod spatial;
 mod persp;
 mod ortho;
+mod aabb;
+mod shape;
 
 // specialization for some 1d, 2d and 3d operations
 #[doc(hidden)]
@@ -36,5 +65,5 @@ mod spec {
     mod vec0;
     mod vec;
     mod primitives;
-    // mod complex;
+    mod sphere_sampling;
 }
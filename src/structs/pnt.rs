@@ -6,11 +6,12 @@ use std::mem;
 use std::slice::{Items, MutItems};
 use std::iter::{Iterator, FromIterator};
 use traits::operations::{ApproxEq, POrd, POrdering, Axpy, ScalarAdd, ScalarSub, ScalarMul,
-                         ScalarDiv};
-use traits::structure::{Cast, Dim, Indexable, Iterable, IterableMut, PntAsVec, Shape,
+                         ScalarDiv, Absolute, CompFloat, CompReduce};
+use traits::structure::{Cast, CompMap, Dim, Indexable, Iterable, IterableMut, PntAsVec, Shape,
                         NumPnt, FloatPnt, BaseFloat, BaseNum, Zero, One, Bounded};
 use traits::geometry::{Orig, FromHomogeneous, ToHomogeneous};
 use structs::vec::{Vec1, Vec2, Vec3, Vec4, Vec5, Vec6};
+use structs::dvec::{DVec, DVec1, DVec2, DVec3, DVec4, DVec5, DVec6};
 
 
 /// Point of dimension 0.
@@ -29,6 +30,25 @@ impl<N> Pnt0<N> {
     pub fn new_repeat(_: N) -> Pnt0<N> {
         Pnt0
     }
+
+    /// Converts this point to an empty heap-allocated, dynamically sized vector.
+    #[inline]
+    pub fn to_dvec(&self) -> DVec<N> {
+        DVec { at: Vec::new() }
+    }
+
+    /// Builds a point from a dynamically sized vector.
+    ///
+    /// Returns `None` if `v` is not empty.
+    #[inline]
+    pub fn from_dvec<V: Indexable<uint, N>>(v: &V) -> Option<Pnt0<N>> {
+        if v.shape() != 0 {
+            None
+        }
+        else {
+            Some(Pnt0)
+        }
+    }
 }
 
 /// Point of dimension 1.
@@ -47,6 +67,7 @@ scalar_add_impl!(Pnt1, x)
 scalar_sub_impl!(Pnt1, x)
 vec_cast_impl!(Pnt1, x)
 as_array_impl!(Pnt1, 1)
+dvec_conversion_impl!(Pnt1, DVec1, to_dvec1, 1)
 index_impl!(Pnt1)
 indexable_impl!(Pnt1, 1)
 at_fast_impl!(Pnt1, 1)
@@ -68,6 +89,10 @@ iterable_mut_impl!(Pnt1, 1)
 pnt_to_homogeneous_impl!(Pnt1, Pnt2, y, x)
 pnt_from_homogeneous_impl!(Pnt1, Pnt2, y, x)
 num_float_pnt_impl!(Pnt1, Vec1)
+absolute_vec_impl!(Pnt1, x)
+comp_map_impl!(Pnt1, x)
+comp_float_impl!(Pnt1, x)
+comp_reduce_impl!(Pnt1, x)
 
 /// Point of dimension 2.
 #[deriving(Eq, PartialEq, Encodable, Decodable, Clone, Hash, Rand, Show)]
@@ -87,6 +112,7 @@ scalar_add_impl!(Pnt2, x, y)
 scalar_sub_impl!(Pnt2, x, y)
 vec_cast_impl!(Pnt2, x, y)
 as_array_impl!(Pnt2, 2)
+dvec_conversion_impl!(Pnt2, DVec2, to_dvec2, 2)
 index_impl!(Pnt2)
 indexable_impl!(Pnt2, 2)
 at_fast_impl!(Pnt2, 2)
@@ -108,6 +134,12 @@ iterable_mut_impl!(Pnt2, 2)
 pnt_to_homogeneous_impl!(Pnt2, Pnt3, z, x, y)
 pnt_from_homogeneous_impl!(Pnt2, Pnt3, z, x, y)
 num_float_pnt_impl!(Pnt2, Vec2)
+absolute_vec_impl!(Pnt2, x, y)
+comp_map_impl!(Pnt2, x, y)
+comp_float_impl!(Pnt2, x, y)
+comp_reduce_impl!(Pnt2, x, y)
+swizzle_impl!(Pnt2, Pnt2,
+              xy => x, y; yx => y, x)
 
 /// Point of dimension 3.
 #[deriving(Eq, PartialEq, Encodable, Decodable, Clone, Hash, Rand, Show)]
@@ -129,6 +161,7 @@ scalar_add_impl!(Pnt3, x, y, z)
 scalar_sub_impl!(Pnt3, x, y, z)
 vec_cast_impl!(Pnt3, x, y, z)
 as_array_impl!(Pnt3, 3)
+dvec_conversion_impl!(Pnt3, DVec3, to_dvec3, 3)
 index_impl!(Pnt3)
 indexable_impl!(Pnt3, 3)
 at_fast_impl!(Pnt3, 3)
@@ -150,6 +183,16 @@ iterable_mut_impl!(Pnt3, 3)
 pnt_to_homogeneous_impl!(Pnt3, Pnt4, w, x, y, z)
 pnt_from_homogeneous_impl!(Pnt3, Pnt4, w, x, y, z)
 num_float_pnt_impl!(Pnt3, Vec3)
+absolute_vec_impl!(Pnt3, x, y, z)
+comp_map_impl!(Pnt3, x, y, z)
+comp_float_impl!(Pnt3, x, y, z)
+comp_reduce_impl!(Pnt3, x, y, z)
+extend_impl!(Pnt3, Pnt2, from_pnt2, x, y; z)
+swizzle_impl!(Pnt3, Pnt2,
+              xy => x, y; xz => x, z; yx => y, x; yz => y, z; zx => z, x; zy => z, y)
+swizzle_impl!(Pnt3, Pnt3,
+              xyz => x, y, z; xzy => x, z, y; yxz => y, x, z; yzx => y, z, x; zxy => z, x, y;
+              zyx => z, y, x)
 
 /// Point of dimension 4.
 #[deriving(Eq, PartialEq, Encodable, Decodable, Clone, Hash, Rand, Show)]
@@ -173,6 +216,7 @@ scalar_add_impl!(Pnt4, x, y, z, w)
 scalar_sub_impl!(Pnt4, x, y, z, w)
 vec_cast_impl!(Pnt4, x, y, z, w)
 as_array_impl!(Pnt4, 4)
+dvec_conversion_impl!(Pnt4, DVec4, to_dvec4, 4)
 index_impl!(Pnt4)
 indexable_impl!(Pnt4, 4)
 at_fast_impl!(Pnt4, 4)
@@ -194,6 +238,27 @@ iterable_mut_impl!(Pnt4, 4)
 pnt_to_homogeneous_impl!(Pnt4, Pnt5, a, x, y, z, w)
 pnt_from_homogeneous_impl!(Pnt4, Pnt5, a, x, y, z, w)
 num_float_pnt_impl!(Pnt4, Vec4)
+absolute_vec_impl!(Pnt4, x, y, z, w)
+comp_map_impl!(Pnt4, x, y, z, w)
+comp_float_impl!(Pnt4, x, y, z, w)
+comp_reduce_impl!(Pnt4, x, y, z, w)
+extend_impl!(Pnt4, Pnt3, from_pnt3, x, y, z; w)
+swizzle_impl!(Pnt4, Pnt2,
+              xy => x, y; xz => x, z; xw => x, w; yx => y, x; yz => y, z; yw => y, w; zx => z, x;
+              zy => z, y; zw => z, w; wx => w, x; wy => w, y; wz => w, z)
+swizzle_impl!(Pnt4, Pnt3,
+              xyz => x, y, z; xyw => x, y, w; xzy => x, z, y; xzw => x, z, w; xwy => x, w, y;
+              xwz => x, w, z; yxz => y, x, z; yxw => y, x, w; yzx => y, z, x; yzw => y, z, w;
+              ywx => y, w, x; ywz => y, w, z; zxy => z, x, y; zxw => z, x, w; zyx => z, y, x;
+              zyw => z, y, w; zwx => z, w, x; zwy => z, w, y; wxy => w, x, y; wxz => w, x, z;
+              wyx => w, y, x; wyz => w, y, z; wzx => w, z, x; wzy => w, z, y)
+swizzle_impl!(Pnt4, Pnt4,
+              xyzw => x, y, z, w; xywz => x, y, w, z; xzyw => x, z, y, w; xzwy => x, z, w, y;
+              xwyz => x, w, y, z; xwzy => x, w, z, y; yxzw => y, x, z, w; yxwz => y, x, w, z;
+              yzxw => y, z, x, w; yzwx => y, z, w, x; ywxz => y, w, x, z; ywzx => y, w, z, x;
+              zxyw => z, x, y, w; zxwy => z, x, w, y; zyxw => z, y, x, w; zywx => z, y, w, x;
+              zwxy => z, w, x, y; zwyx => z, w, y, x; wxyz => w, x, y, z; wxzy => w, x, z, y;
+              wyxz => w, y, x, z; wyzx => w, y, z, x; wzxy => w, z, x, y; wzyx => w, z, y, x)
 
 /// Point of dimension 5.
 #[deriving(Eq, PartialEq, Encodable, Decodable, Clone, Hash, Rand, Show)]
@@ -219,6 +284,7 @@ scalar_add_impl!(Pnt5, x, y, z, w, a)
 scalar_sub_impl!(Pnt5, x, y, z, w, a)
 vec_cast_impl!(Pnt5, x, y, z, w, a)
 as_array_impl!(Pnt5, 5)
+dvec_conversion_impl!(Pnt5, DVec5, to_dvec5, 5)
 index_impl!(Pnt5)
 indexable_impl!(Pnt5, 5)
 at_fast_impl!(Pnt5, 5)
@@ -240,6 +306,10 @@ iterable_mut_impl!(Pnt5, 5)
 pnt_to_homogeneous_impl!(Pnt5, Pnt6, b, x, y, z, w, a)
 pnt_from_homogeneous_impl!(Pnt5, Pnt6, b, x, y, z, w, a)
 num_float_pnt_impl!(Pnt5, Vec5)
+absolute_vec_impl!(Pnt5, x, y, z, w, a)
+comp_map_impl!(Pnt5, x, y, z, w, a)
+comp_float_impl!(Pnt5, x, y, z, w, a)
+comp_reduce_impl!(Pnt5, x, y, z, w, a)
 
 /// Point of dimension 6.
 #[deriving(Eq, PartialEq, Encodable, Decodable, Clone, Hash, Rand, Show)]
@@ -267,6 +337,7 @@ scalar_add_impl!(Pnt6, x, y, z, w, a, b)
 scalar_sub_impl!(Pnt6, x, y, z, w, a, b)
 vec_cast_impl!(Pnt6, x, y, z, w, a, b)
 as_array_impl!(Pnt6, 6)
+dvec_conversion_impl!(Pnt6, DVec6, to_dvec6, 6)
 index_impl!(Pnt6)
 indexable_impl!(Pnt6, 6)
 at_fast_impl!(Pnt6, 6)
@@ -286,3 +357,7 @@ axpy_impl!(Pnt6, x, y, z, w, a, b)
 iterable_impl!(Pnt6, 6)
 iterable_mut_impl!(Pnt6, 6)
 num_float_pnt_impl!(Pnt6, Vec6)
+absolute_vec_impl!(Pnt6, x, y, z, w, a, b)
+comp_map_impl!(Pnt6, x, y, z, w, a, b)
+comp_float_impl!(Pnt6, x, y, z, w, a, b)
+comp_reduce_impl!(Pnt6, x, y, z, w, a, b)
@@ -4,14 +4,16 @@
 
 use std::mem;
 use std::num;
+use std::num::Float;
 use std::rand::{Rand, Rng};
 use std::slice::{Items, MutItems};
-use structs::{Vec3, Pnt3, Rot3, Mat3};
+use structs::{Vec3, Vec4, Pnt3, Rot3, Mat3, Mat4};
+use linalg;
 use traits::operations::{ApproxEq, Inv, POrd, POrdering, Axpy, ScalarAdd, ScalarSub, ScalarMul,
-                         ScalarDiv};
+                         ScalarDiv, Outer};
 use traits::structure::{Cast, Indexable, Iterable, IterableMut, Dim, Shape, BaseFloat, BaseNum, Zero,
-                        One, Bounded};
-use traits::geometry::{Norm, Cross, Rotation, Rotate, Transform};
+                        One, Bounded, Basis, Col};
+use traits::geometry::{Norm, Cross, Dot, Rotation, Rotate, Transform, AbsoluteRotate, ToHomogeneous};
 
 /// A quaternion.
 #[deriving(Eq, PartialEq, Encodable, Decodable, Clone, Hash, Rand, Show)]
@@ -232,6 +234,194 @@ impl<N: BaseFloat> UnitQuat<N> {
     }
 }
 
+impl<N: BaseFloat + ApproxEq<N> + Clone> UnitQuat<N> {
+    /// Creates a new unit quaternion from a rotation matrix.
+    pub fn new_with_rot(rot: Rot3<N>) -> UnitQuat<N> {
+        let _1: N    = ::one();
+        let _0_25: N = num::cast(0.25f64).unwrap();
+        let m        = rot.submat();
+        let tr       = m.m11 + m.m22 + m.m33;
+
+        // Shepperd's method: compute first the largest component to avoid numerical instabilities.
+        let q =
+            if tr > ::zero() {
+                let s = (tr + _1).sqrt() * num::cast(2.0f64).unwrap();
+
+                Quat::new(s * _0_25, (m.m32 - m.m23) / s, (m.m13 - m.m31) / s, (m.m21 - m.m12) / s)
+            }
+            else if m.m11 > m.m22 && m.m11 > m.m33 {
+                let s = (_1 + m.m11 - m.m22 - m.m33).sqrt() * num::cast(2.0f64).unwrap();
+
+                Quat::new((m.m32 - m.m23) / s, s * _0_25, (m.m12 + m.m21) / s, (m.m13 + m.m31) / s)
+            }
+            else if m.m22 > m.m33 {
+                let s = (_1 + m.m22 - m.m11 - m.m33).sqrt() * num::cast(2.0f64).unwrap();
+
+                Quat::new((m.m13 - m.m31) / s, (m.m12 + m.m21) / s, s * _0_25, (m.m23 + m.m32) / s)
+            }
+            else {
+                let s = (_1 + m.m33 - m.m11 - m.m22).sqrt() * num::cast(2.0f64).unwrap();
+
+                Quat::new((m.m21 - m.m12) / s, (m.m13 + m.m31) / s, (m.m23 + m.m32) / s, s * _0_25)
+            };
+
+        UnitQuat::new_with_quat(q)
+    }
+
+    /// Creates a new unit quaternion from the image of the canonical basis by the rotation.
+    ///
+    /// Returns `None` if `(x, y, z)` is not a direct orthonormal basis.
+    pub fn new_with_basis(x: Vec3<N>, y: Vec3<N>, z: Vec3<N>) -> Option<UnitQuat<N>> {
+        Rot3::new_with_basis(x, y, z).map(|r| UnitQuat::new_with_rot(r))
+    }
+
+    /// Creates the unit quaternion of the smallest rotation that maps the direction of `a` to the
+    /// direction of `b`.
+    ///
+    /// If `a` and `b` point to opposite directions, a rotation of `pi` around an arbitrary axis
+    /// orthogonal to `a` is returned. Returns `None` if `a` or `b` is zero.
+    pub fn rotation_between(a: &Vec3<N>, b: &Vec3<N>) -> Option<UnitQuat<N>> {
+        let sqa = Norm::sqnorm(a);
+        let sqb = Norm::sqnorm(b);
+
+        if ::is_zero(&sqa) || ::is_zero(&sqb) {
+            return None
+        }
+
+        let na = *a / sqa.sqrt();
+        let nb = *b / sqb.sqrt();
+        // This is `2 * cos(angle / 2)^2`.
+        let w  = ::one::<N>() + Dot::dot(&na, &nb);
+
+        if w < Float::epsilon() {
+            // The vectors are opposite: their cross product cannot be used as an axis.
+            let mut axis = ::zero::<Vec3<N>>();
+
+            Basis::orthonormal_subspace_basis(&na, |e| { axis = e; false });
+
+            unsafe {
+                Some(UnitQuat::new_with_unit_quat(Quat::new(::zero(), axis.x, axis.y, axis.z)))
+            }
+        }
+        else {
+            let v = Cross::cross(&na, &nb);
+
+            Some(UnitQuat::new_with_quat(Quat::new(w, v.x, v.y, v.z)))
+        }
+    }
+
+    /// Renormalizes this quaternion to remove the numerical drift accumulated after many
+    /// compositions.
+    #[inline]
+    pub fn renormalize(&mut self) {
+        let _ = self.q.normalize();
+    }
+
+    /// The average of a set of rotations.
+    ///
+    /// See `UnitQuat::weighted_average` for details.
+    pub fn average(rotations: &[UnitQuat<N>]) -> UnitQuat<N> {
+        let weights = Vec::from_elem(rotations.len(), ::one::<N>());
+
+        UnitQuat::weighted_average(rotations, weights.as_slice())
+    }
+
+    /// The weighted average of a set of rotations.
+    ///
+    /// This is the rotation which minimizes the weighted sum of the squared chordal distances to
+    /// each rotation. It is computed as the eigenvector associated to the largest eigenvalue of
+    /// the matrix `sum(weight * outer(q, q))` (Markley et al., 2007), so the sign of each
+    /// quaternion does not matter.
+    ///
+    /// Fails if `rotations` is empty or if `rotations` and `weights` do not have the same length.
+    pub fn weighted_average(rotations: &[UnitQuat<N>], weights: &[N]) -> UnitQuat<N> {
+        assert!(rotations.len() == weights.len(),
+                "The number of rotations and of weights must be the same.");
+        assert!(!rotations.is_empty(), "Cannot average an empty set of rotations.");
+
+        let mut m: Mat4<N> = ::zero();
+        let mut total: N   = ::zero();
+
+        for (rot, w) in rotations.iter().zip(weights.iter()) {
+            let q = Vec4::new(rot.q.w.clone(), rot.q.i.clone(), rot.q.j.clone(), rot.q.k.clone());
+
+            m     = m + Outer::outer(&q, &q) * *w;
+            total = total + *w;
+        }
+
+        let eps: N = Float::epsilon();
+        let (vectors, values): (Mat4<N>, Vec4<N>) = linalg::eigen_sym(&m, &(eps * total.abs()), 50);
+
+        let mut imax = 0u;
+
+        for i in range(1u, 4) {
+            if values[i] > values[imax] {
+                imax = i;
+            }
+        }
+
+        let q = vectors.col(imax);
+
+        UnitQuat::new_with_quat(Quat::new(q.x, q.y, q.z, q.w))
+    }
+}
+
+impl<N: BaseFloat + Clone> UnitQuat<N> {
+    /// Integrates the constant angular velocity `angvel` during the time step `dt`.
+    ///
+    /// The angular velocity is expressed in the global frame. This uses the exponential map, so
+    /// the result is exact and stays normalized: `UnitQuat::new(angvel * dt) * self`.
+    #[inline]
+    pub fn integrate(&self, angvel: &Vec3<N>, dt: N) -> UnitQuat<N> {
+        UnitQuat::new(*angvel * dt) * *self
+    }
+
+    /// The time derivative `½ ω q` of this quaternion when rotating with the angular velocity
+    /// `angvel` expressed in the global frame.
+    ///
+    /// This is useful for higher-order integrators. The integrated quaternion has to be
+    /// renormalized with `UnitQuat::new_with_quat`.
+    #[inline]
+    pub fn derivative(&self, angvel: &Vec3<N>) -> Quat<N> {
+        let _0_5: N = num::cast(0.5f64).unwrap();
+        let half    = *angvel * _0_5;
+
+        Quat::new(::zero(), half.x, half.y, half.z) * self.q
+    }
+
+    /// The geodesic distance between this rotation and `other`.
+    ///
+    /// This is the angle of the smallest rotation mapping `self` to `other`, in `[0, pi]`.
+    #[inline]
+    pub fn angle_to(&self, other: &UnitQuat<N>) -> N {
+        let _2: N  = num::cast(2.0f64).unwrap();
+        let delta  = self.delta(other);
+
+        Norm::norm(delta.vector()).atan2(delta.w.abs()) * _2
+    }
+
+    /// The chordal distance between this rotation and `other`.
+    ///
+    /// This is the Frobenius norm of the difference between the rotation matrices of both
+    /// rotations, i.e., `2 * sqrt(2) * sin(angle / 2)` where `angle` is the geodesic distance.
+    #[inline]
+    pub fn chordal_distance(&self, other: &UnitQuat<N>) -> N {
+        let _8: N = num::cast(8.0f64).unwrap();
+
+        Norm::norm(self.delta(other).vector()) * _8.sqrt()
+    }
+
+    // The quaternion of the rotation mapping `self` to `other`.
+    #[inline]
+    fn delta(&self, other: &UnitQuat<N>) -> Quat<N> {
+        let mut conj = self.q.clone();
+
+        conj.conjugate();
+
+        conj * other.q
+    }
+}
+
 impl<N> UnitQuat<N> {
     /// Creates a new unit quaternion from a quaternion.
     ///
@@ -276,10 +466,16 @@ impl<N: Clone + Neg<N>> Inv for UnitQuat<N> {
     }
 }
 
+/// Samples a unit quaternion uniformly distributed with respect to the Haar measure of `SO(3)`.
 impl<N: Clone + Rand + BaseFloat> Rand for UnitQuat<N> {
     #[inline]
     fn rand<R: Rng>(rng: &mut R) -> UnitQuat<N> {
-        UnitQuat::new(rng.gen())
+        // A uniformly distributed point of the 3-sphere is a uniformly distributed rotation.
+        let v: Vec4<N> = Vec4::new_random_unit(rng);
+
+        unsafe {
+            UnitQuat::new_with_unit_quat(Quat::new(v.x, v.y, v.z, v.w))
+        }
     }
 }
 
@@ -357,14 +553,27 @@ impl<N: BaseFloat + Clone> Rotation<Vec3<N>> for UnitQuat<N> {
     #[inline]
     fn rotation(&self) -> Vec3<N> {
         let _2 = ::one::<N>() + ::one();
-        let mut v = self.q.vector().clone();
-        let ang = _2 * v.normalize().atan2(self.q.w);
 
-        if ::is_zero(&ang) {
+        // `q` and `-q` represent the same rotation. Using the one with a positive scalar part
+        // ensures the returned angle is the smallest one, i.e. lies in `[0, pi]`.
+        let (w, v) =
+            if self.q.w < ::zero() {
+                (-self.q.w, -*self.q.vector())
+            }
+            else {
+                (self.q.w.clone(), self.q.vector().clone())
+            };
+
+        // `atan2` remains accurate for angles close to both `0` and `pi`, unlike `acos(w)`.
+        let sin = Norm::norm(&v);
+
+        if ::is_zero(&sin) {
             ::zero()
         }
         else {
-            Vec3::new(v.x * ang, v.y * ang, v.z * ang)
+            let ang = _2 * sin.atan2(w);
+
+            v * (ang / sin)
         }
     }
 
@@ -447,6 +656,20 @@ impl<N: BaseNum + Clone> Transform<Pnt3<N>> for UnitQuat<N> {
     }
 }
 
+impl<N: BaseFloat> AbsoluteRotate<Vec3<N>> for UnitQuat<N> {
+    #[inline]
+    fn absolute_rotate(&self, v: &Vec3<N>) -> Vec3<N> {
+        self.to_rot().absolute_rotate(v)
+    }
+}
+
+impl<N: BaseFloat + Clone> ToHomogeneous<Mat4<N>> for UnitQuat<N> {
+    #[inline]
+    fn to_homogeneous(&self) -> Mat4<N> {
+        self.to_rot().to_homogeneous()
+    }
+}
+
 ord_impl!(Quat, w, i, j, k)
 vec_axis_impl!(Quat, w, i, j, k)
 vec_cast_impl!(Quat, w, i, j, k)
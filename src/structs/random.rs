@@ -0,0 +1,20 @@
+//! Scalar sampling utilities shared by the random generators of the data structures.
+
+use std::num;
+use std::rand::Rng;
+use std::rand::distributions::normal::StandardNormal;
+use traits::structure::BaseFloat;
+
+/// Samples a scalar from the standard normal distribution.
+#[inline]
+pub fn normal<N: BaseFloat, R: Rng>(rng: &mut R) -> N {
+    let StandardNormal(x) = rng.gen::<StandardNormal>();
+
+    num::cast(x).unwrap()
+}
+
+/// Samples a scalar uniformly in `[0, 1)`.
+#[inline]
+pub fn uniform<N: BaseFloat, R: Rng>(rng: &mut R) -> N {
+    num::cast(rng.gen::<f64>()).unwrap()
+}
@@ -0,0 +1,1601 @@
+//! Rectangular matrices with dimensions known at compile-time.
+
+#![allow(missing_docs)] // we allow missing to avoid having to document the mij components.
+
+use std::mem;
+use std::slice::{Items, MutItems};
+use traits::operations::{ApproxEq, Absolute};
+use traits::structure::{Cast, Row, Col, Iterable, IterableMut, Indexable, Shape, BaseNum, Zero};
+use structs::vec::{Vec1, Vec2, Vec3, Vec4, Vec5, Vec6};
+use structs::mat::{Mat1, Mat2, Mat3, Mat4, Mat5, Mat6};
+use structs::dmat::DMat;
+
+
+/// Matrix with 1 row and 2 columns.
+#[deriving(Eq, PartialEq, Encodable, Decodable, Clone, Hash, Rand, Show)]
+pub struct Mat1x2<N> {
+    pub m11: N,
+    pub m12: N
+}
+
+rect_mat_impl!(Mat1x2, 1, 2, m11, m12)
+mat_cast_impl!(Mat1x2, m11, m12)
+add_impl!(Mat1x2, m11, m12)
+sub_impl!(Mat1x2, m11, m12)
+scalar_add_impl!(Mat1x2, m11, m12)
+scalar_sub_impl!(Mat1x2, m11, m12)
+scalar_mul_impl!(Mat1x2, m11, m12)
+scalar_div_impl!(Mat1x2, m11, m12)
+absolute_impl!(Mat1x2, m11, m12)
+zero_impl!(Mat1x2, m11, m12)
+rect_iterable_impl!(Mat1x2, 1, 2)
+rect_indexable_impl!(Mat1x2, 1, 2)
+rect_row_impl!(Mat1x2, Vec2, 1, 2)
+rect_col_impl!(Mat1x2, Vec1, 1, 2)
+rect_transpose_impl!(Mat1x2, Mat2x1, 1, 2)
+rect_mat_mul_vec_impl!(Mat1x2, Vec2, Vec1, 1, 2)
+approx_eq_impl!(Mat1x2)
+
+/// Matrix with 1 row and 3 columns.
+#[deriving(Eq, PartialEq, Encodable, Decodable, Clone, Hash, Rand, Show)]
+pub struct Mat1x3<N> {
+    pub m11: N,
+    pub m12: N,
+    pub m13: N
+}
+
+rect_mat_impl!(Mat1x3, 1, 3, m11, m12, m13)
+mat_cast_impl!(Mat1x3, m11, m12, m13)
+add_impl!(Mat1x3, m11, m12, m13)
+sub_impl!(Mat1x3, m11, m12, m13)
+scalar_add_impl!(Mat1x3, m11, m12, m13)
+scalar_sub_impl!(Mat1x3, m11, m12, m13)
+scalar_mul_impl!(Mat1x3, m11, m12, m13)
+scalar_div_impl!(Mat1x3, m11, m12, m13)
+absolute_impl!(Mat1x3, m11, m12, m13)
+zero_impl!(Mat1x3, m11, m12, m13)
+rect_iterable_impl!(Mat1x3, 1, 3)
+rect_indexable_impl!(Mat1x3, 1, 3)
+rect_row_impl!(Mat1x3, Vec3, 1, 3)
+rect_col_impl!(Mat1x3, Vec1, 1, 3)
+rect_transpose_impl!(Mat1x3, Mat3x1, 1, 3)
+rect_mat_mul_vec_impl!(Mat1x3, Vec3, Vec1, 1, 3)
+approx_eq_impl!(Mat1x3)
+
+/// Matrix with 1 row and 4 columns.
+#[deriving(Eq, PartialEq, Encodable, Decodable, Clone, Hash, Rand, Show)]
+pub struct Mat1x4<N> {
+    pub m11: N,
+    pub m12: N,
+    pub m13: N,
+    pub m14: N
+}
+
+rect_mat_impl!(Mat1x4, 1, 4, m11, m12, m13, m14)
+mat_cast_impl!(Mat1x4, m11, m12, m13, m14)
+add_impl!(Mat1x4, m11, m12, m13, m14)
+sub_impl!(Mat1x4, m11, m12, m13, m14)
+scalar_add_impl!(Mat1x4, m11, m12, m13, m14)
+scalar_sub_impl!(Mat1x4, m11, m12, m13, m14)
+scalar_mul_impl!(Mat1x4, m11, m12, m13, m14)
+scalar_div_impl!(Mat1x4, m11, m12, m13, m14)
+absolute_impl!(Mat1x4, m11, m12, m13, m14)
+zero_impl!(Mat1x4, m11, m12, m13, m14)
+rect_iterable_impl!(Mat1x4, 1, 4)
+rect_indexable_impl!(Mat1x4, 1, 4)
+rect_row_impl!(Mat1x4, Vec4, 1, 4)
+rect_col_impl!(Mat1x4, Vec1, 1, 4)
+rect_transpose_impl!(Mat1x4, Mat4x1, 1, 4)
+rect_mat_mul_vec_impl!(Mat1x4, Vec4, Vec1, 1, 4)
+approx_eq_impl!(Mat1x4)
+
+/// Matrix with 1 row and 5 columns.
+#[deriving(Eq, PartialEq, Encodable, Decodable, Clone, Hash, Rand, Show)]
+pub struct Mat1x5<N> {
+    pub m11: N,
+    pub m12: N,
+    pub m13: N,
+    pub m14: N,
+    pub m15: N
+}
+
+rect_mat_impl!(Mat1x5, 1, 5, m11, m12, m13, m14, m15)
+mat_cast_impl!(Mat1x5, m11, m12, m13, m14, m15)
+add_impl!(Mat1x5, m11, m12, m13, m14, m15)
+sub_impl!(Mat1x5, m11, m12, m13, m14, m15)
+scalar_add_impl!(Mat1x5, m11, m12, m13, m14, m15)
+scalar_sub_impl!(Mat1x5, m11, m12, m13, m14, m15)
+scalar_mul_impl!(Mat1x5, m11, m12, m13, m14, m15)
+scalar_div_impl!(Mat1x5, m11, m12, m13, m14, m15)
+absolute_impl!(Mat1x5, m11, m12, m13, m14, m15)
+zero_impl!(Mat1x5, m11, m12, m13, m14, m15)
+rect_iterable_impl!(Mat1x5, 1, 5)
+rect_indexable_impl!(Mat1x5, 1, 5)
+rect_row_impl!(Mat1x5, Vec5, 1, 5)
+rect_col_impl!(Mat1x5, Vec1, 1, 5)
+rect_transpose_impl!(Mat1x5, Mat5x1, 1, 5)
+rect_mat_mul_vec_impl!(Mat1x5, Vec5, Vec1, 1, 5)
+approx_eq_impl!(Mat1x5)
+
+/// Matrix with 1 row and 6 columns.
+#[deriving(Eq, PartialEq, Encodable, Decodable, Clone, Hash, Rand, Show)]
+pub struct Mat1x6<N> {
+    pub m11: N,
+    pub m12: N,
+    pub m13: N,
+    pub m14: N,
+    pub m15: N,
+    pub m16: N
+}
+
+rect_mat_impl!(Mat1x6, 1, 6, m11, m12, m13, m14, m15, m16)
+mat_cast_impl!(Mat1x6, m11, m12, m13, m14, m15, m16)
+add_impl!(Mat1x6, m11, m12, m13, m14, m15, m16)
+sub_impl!(Mat1x6, m11, m12, m13, m14, m15, m16)
+scalar_add_impl!(Mat1x6, m11, m12, m13, m14, m15, m16)
+scalar_sub_impl!(Mat1x6, m11, m12, m13, m14, m15, m16)
+scalar_mul_impl!(Mat1x6, m11, m12, m13, m14, m15, m16)
+scalar_div_impl!(Mat1x6, m11, m12, m13, m14, m15, m16)
+absolute_impl!(Mat1x6, m11, m12, m13, m14, m15, m16)
+zero_impl!(Mat1x6, m11, m12, m13, m14, m15, m16)
+rect_iterable_impl!(Mat1x6, 1, 6)
+rect_indexable_impl!(Mat1x6, 1, 6)
+rect_row_impl!(Mat1x6, Vec6, 1, 6)
+rect_col_impl!(Mat1x6, Vec1, 1, 6)
+rect_transpose_impl!(Mat1x6, Mat6x1, 1, 6)
+rect_mat_mul_vec_impl!(Mat1x6, Vec6, Vec1, 1, 6)
+approx_eq_impl!(Mat1x6)
+
+/// Matrix with 2 rows and 1 column.
+#[deriving(Eq, PartialEq, Encodable, Decodable, Clone, Hash, Rand, Show)]
+pub struct Mat2x1<N> {
+    pub m11: N, pub m21: N
+}
+
+rect_mat_impl!(Mat2x1, 2, 1, m11,
+                             m21)
+mat_cast_impl!(Mat2x1, m11,
+                       m21)
+add_impl!(Mat2x1, m11, m21)
+sub_impl!(Mat2x1, m11, m21)
+scalar_add_impl!(Mat2x1, m11, m21)
+scalar_sub_impl!(Mat2x1, m11, m21)
+scalar_mul_impl!(Mat2x1, m11, m21)
+scalar_div_impl!(Mat2x1, m11, m21)
+absolute_impl!(Mat2x1, m11,
+                       m21)
+zero_impl!(Mat2x1, m11,
+                   m21)
+rect_iterable_impl!(Mat2x1, 2, 1)
+rect_indexable_impl!(Mat2x1, 2, 1)
+rect_row_impl!(Mat2x1, Vec1, 2, 1)
+rect_col_impl!(Mat2x1, Vec2, 2, 1)
+rect_transpose_impl!(Mat2x1, Mat1x2, 2, 1)
+rect_mat_mul_vec_impl!(Mat2x1, Vec1, Vec2, 2, 1)
+approx_eq_impl!(Mat2x1)
+
+/// Matrix with 2 rows and 3 columns.
+#[deriving(Eq, PartialEq, Encodable, Decodable, Clone, Hash, Rand, Show)]
+pub struct Mat2x3<N> {
+    pub m11: N, pub m21: N,
+    pub m12: N, pub m22: N,
+    pub m13: N, pub m23: N
+}
+
+rect_mat_impl!(Mat2x3, 2, 3, m11, m12, m13,
+                             m21, m22, m23)
+mat_cast_impl!(Mat2x3, m11, m12, m13,
+                       m21, m22, m23)
+add_impl!(Mat2x3, m11, m12, m13, m21, m22, m23)
+sub_impl!(Mat2x3, m11, m12, m13, m21, m22, m23)
+scalar_add_impl!(Mat2x3, m11, m12, m13, m21, m22, m23)
+scalar_sub_impl!(Mat2x3, m11, m12, m13, m21, m22, m23)
+scalar_mul_impl!(Mat2x3, m11, m12, m13, m21, m22, m23)
+scalar_div_impl!(Mat2x3, m11, m12, m13, m21, m22, m23)
+absolute_impl!(Mat2x3, m11, m12, m13,
+                       m21, m22, m23)
+zero_impl!(Mat2x3, m11, m12, m13,
+                   m21, m22, m23)
+rect_iterable_impl!(Mat2x3, 2, 3)
+rect_indexable_impl!(Mat2x3, 2, 3)
+rect_row_impl!(Mat2x3, Vec3, 2, 3)
+rect_col_impl!(Mat2x3, Vec2, 2, 3)
+rect_transpose_impl!(Mat2x3, Mat3x2, 2, 3)
+rect_mat_mul_vec_impl!(Mat2x3, Vec3, Vec2, 2, 3)
+approx_eq_impl!(Mat2x3)
+
+/// Matrix with 2 rows and 4 columns.
+#[deriving(Eq, PartialEq, Encodable, Decodable, Clone, Hash, Rand, Show)]
+pub struct Mat2x4<N> {
+    pub m11: N, pub m21: N,
+    pub m12: N, pub m22: N,
+    pub m13: N, pub m23: N,
+    pub m14: N, pub m24: N
+}
+
+rect_mat_impl!(Mat2x4, 2, 4, m11, m12, m13, m14,
+                             m21, m22, m23, m24)
+mat_cast_impl!(Mat2x4, m11, m12, m13, m14,
+                       m21, m22, m23, m24)
+add_impl!(Mat2x4, m11, m12, m13, m14, m21, m22, m23, m24)
+sub_impl!(Mat2x4, m11, m12, m13, m14, m21, m22, m23, m24)
+scalar_add_impl!(Mat2x4, m11, m12, m13, m14, m21, m22, m23, m24)
+scalar_sub_impl!(Mat2x4, m11, m12, m13, m14, m21, m22, m23, m24)
+scalar_mul_impl!(Mat2x4, m11, m12, m13, m14, m21, m22, m23, m24)
+scalar_div_impl!(Mat2x4, m11, m12, m13, m14, m21, m22, m23, m24)
+absolute_impl!(Mat2x4, m11, m12, m13, m14,
+                       m21, m22, m23, m24)
+zero_impl!(Mat2x4, m11, m12, m13, m14,
+                   m21, m22, m23, m24)
+rect_iterable_impl!(Mat2x4, 2, 4)
+rect_indexable_impl!(Mat2x4, 2, 4)
+rect_row_impl!(Mat2x4, Vec4, 2, 4)
+rect_col_impl!(Mat2x4, Vec2, 2, 4)
+rect_transpose_impl!(Mat2x4, Mat4x2, 2, 4)
+rect_mat_mul_vec_impl!(Mat2x4, Vec4, Vec2, 2, 4)
+approx_eq_impl!(Mat2x4)
+
+/// Matrix with 2 rows and 5 columns.
+#[deriving(Eq, PartialEq, Encodable, Decodable, Clone, Hash, Rand, Show)]
+pub struct Mat2x5<N> {
+    pub m11: N, pub m21: N,
+    pub m12: N, pub m22: N,
+    pub m13: N, pub m23: N,
+    pub m14: N, pub m24: N,
+    pub m15: N, pub m25: N
+}
+
+rect_mat_impl!(Mat2x5, 2, 5, m11, m12, m13, m14, m15,
+                             m21, m22, m23, m24, m25)
+mat_cast_impl!(Mat2x5, m11, m12, m13, m14, m15,
+                       m21, m22, m23, m24, m25)
+add_impl!(Mat2x5, m11, m12, m13, m14, m15, m21, m22, m23, m24, m25)
+sub_impl!(Mat2x5, m11, m12, m13, m14, m15, m21, m22, m23, m24, m25)
+scalar_add_impl!(Mat2x5, m11, m12, m13, m14, m15, m21, m22, m23, m24, m25)
+scalar_sub_impl!(Mat2x5, m11, m12, m13, m14, m15, m21, m22, m23, m24, m25)
+scalar_mul_impl!(Mat2x5, m11, m12, m13, m14, m15, m21, m22, m23, m24, m25)
+scalar_div_impl!(Mat2x5, m11, m12, m13, m14, m15, m21, m22, m23, m24, m25)
+absolute_impl!(Mat2x5, m11, m12, m13, m14, m15,
+                       m21, m22, m23, m24, m25)
+zero_impl!(Mat2x5, m11, m12, m13, m14, m15,
+                   m21, m22, m23, m24, m25)
+rect_iterable_impl!(Mat2x5, 2, 5)
+rect_indexable_impl!(Mat2x5, 2, 5)
+rect_row_impl!(Mat2x5, Vec5, 2, 5)
+rect_col_impl!(Mat2x5, Vec2, 2, 5)
+rect_transpose_impl!(Mat2x5, Mat5x2, 2, 5)
+rect_mat_mul_vec_impl!(Mat2x5, Vec5, Vec2, 2, 5)
+approx_eq_impl!(Mat2x5)
+
+/// Matrix with 2 rows and 6 columns.
+#[deriving(Eq, PartialEq, Encodable, Decodable, Clone, Hash, Rand, Show)]
+pub struct Mat2x6<N> {
+    pub m11: N, pub m21: N,
+    pub m12: N, pub m22: N,
+    pub m13: N, pub m23: N,
+    pub m14: N, pub m24: N,
+    pub m15: N, pub m25: N,
+    pub m16: N, pub m26: N
+}
+
+rect_mat_impl!(Mat2x6, 2, 6, m11, m12, m13, m14, m15, m16,
+                             m21, m22, m23, m24, m25, m26)
+mat_cast_impl!(Mat2x6, m11, m12, m13, m14, m15, m16,
+                       m21, m22, m23, m24, m25, m26)
+add_impl!(Mat2x6, m11, m12, m13, m14, m15, m16, m21, m22, m23, m24, m25, m26)
+sub_impl!(Mat2x6, m11, m12, m13, m14, m15, m16, m21, m22, m23, m24, m25, m26)
+scalar_add_impl!(Mat2x6, m11, m12, m13, m14, m15, m16, m21, m22, m23, m24, m25, m26)
+scalar_sub_impl!(Mat2x6, m11, m12, m13, m14, m15, m16, m21, m22, m23, m24, m25, m26)
+scalar_mul_impl!(Mat2x6, m11, m12, m13, m14, m15, m16, m21, m22, m23, m24, m25, m26)
+scalar_div_impl!(Mat2x6, m11, m12, m13, m14, m15, m16, m21, m22, m23, m24, m25, m26)
+absolute_impl!(Mat2x6, m11, m12, m13, m14, m15, m16,
+                       m21, m22, m23, m24, m25, m26)
+zero_impl!(Mat2x6, m11, m12, m13, m14, m15, m16,
+                   m21, m22, m23, m24, m25, m26)
+rect_iterable_impl!(Mat2x6, 2, 6)
+rect_indexable_impl!(Mat2x6, 2, 6)
+rect_row_impl!(Mat2x6, Vec6, 2, 6)
+rect_col_impl!(Mat2x6, Vec2, 2, 6)
+rect_transpose_impl!(Mat2x6, Mat6x2, 2, 6)
+rect_mat_mul_vec_impl!(Mat2x6, Vec6, Vec2, 2, 6)
+approx_eq_impl!(Mat2x6)
+
+/// Matrix with 3 rows and 1 column.
+#[deriving(Eq, PartialEq, Encodable, Decodable, Clone, Hash, Rand, Show)]
+pub struct Mat3x1<N> {
+    pub m11: N, pub m21: N, pub m31: N
+}
+
+rect_mat_impl!(Mat3x1, 3, 1, m11,
+                             m21,
+                             m31)
+mat_cast_impl!(Mat3x1, m11,
+                       m21,
+                       m31)
+add_impl!(Mat3x1, m11, m21, m31)
+sub_impl!(Mat3x1, m11, m21, m31)
+scalar_add_impl!(Mat3x1, m11, m21, m31)
+scalar_sub_impl!(Mat3x1, m11, m21, m31)
+scalar_mul_impl!(Mat3x1, m11, m21, m31)
+scalar_div_impl!(Mat3x1, m11, m21, m31)
+absolute_impl!(Mat3x1, m11,
+                       m21,
+                       m31)
+zero_impl!(Mat3x1, m11,
+                   m21,
+                   m31)
+rect_iterable_impl!(Mat3x1, 3, 1)
+rect_indexable_impl!(Mat3x1, 3, 1)
+rect_row_impl!(Mat3x1, Vec1, 3, 1)
+rect_col_impl!(Mat3x1, Vec3, 3, 1)
+rect_transpose_impl!(Mat3x1, Mat1x3, 3, 1)
+rect_mat_mul_vec_impl!(Mat3x1, Vec1, Vec3, 3, 1)
+approx_eq_impl!(Mat3x1)
+
+/// Matrix with 3 rows and 2 columns.
+#[deriving(Eq, PartialEq, Encodable, Decodable, Clone, Hash, Rand, Show)]
+pub struct Mat3x2<N> {
+    pub m11: N, pub m21: N, pub m31: N,
+    pub m12: N, pub m22: N, pub m32: N
+}
+
+rect_mat_impl!(Mat3x2, 3, 2, m11, m12,
+                             m21, m22,
+                             m31, m32)
+mat_cast_impl!(Mat3x2, m11, m12,
+                       m21, m22,
+                       m31, m32)
+add_impl!(Mat3x2, m11, m12, m21, m22, m31, m32)
+sub_impl!(Mat3x2, m11, m12, m21, m22, m31, m32)
+scalar_add_impl!(Mat3x2, m11, m12, m21, m22, m31, m32)
+scalar_sub_impl!(Mat3x2, m11, m12, m21, m22, m31, m32)
+scalar_mul_impl!(Mat3x2, m11, m12, m21, m22, m31, m32)
+scalar_div_impl!(Mat3x2, m11, m12, m21, m22, m31, m32)
+absolute_impl!(Mat3x2, m11, m12,
+                       m21, m22,
+                       m31, m32)
+zero_impl!(Mat3x2, m11, m12,
+                   m21, m22,
+                   m31, m32)
+rect_iterable_impl!(Mat3x2, 3, 2)
+rect_indexable_impl!(Mat3x2, 3, 2)
+rect_row_impl!(Mat3x2, Vec2, 3, 2)
+rect_col_impl!(Mat3x2, Vec3, 3, 2)
+rect_transpose_impl!(Mat3x2, Mat2x3, 3, 2)
+rect_mat_mul_vec_impl!(Mat3x2, Vec2, Vec3, 3, 2)
+approx_eq_impl!(Mat3x2)
+
+/// Matrix with 3 rows and 4 columns.
+#[deriving(Eq, PartialEq, Encodable, Decodable, Clone, Hash, Rand, Show)]
+pub struct Mat3x4<N> {
+    pub m11: N, pub m21: N, pub m31: N,
+    pub m12: N, pub m22: N, pub m32: N,
+    pub m13: N, pub m23: N, pub m33: N,
+    pub m14: N, pub m24: N, pub m34: N
+}
+
+rect_mat_impl!(Mat3x4, 3, 4, m11, m12, m13, m14,
+                             m21, m22, m23, m24,
+                             m31, m32, m33, m34)
+mat_cast_impl!(Mat3x4, m11, m12, m13, m14,
+                       m21, m22, m23, m24,
+                       m31, m32, m33, m34)
+add_impl!(Mat3x4, m11, m12, m13, m14, m21, m22, m23, m24, m31, m32, m33, m34)
+sub_impl!(Mat3x4, m11, m12, m13, m14, m21, m22, m23, m24, m31, m32, m33, m34)
+scalar_add_impl!(Mat3x4, m11, m12, m13, m14, m21, m22, m23, m24, m31, m32, m33, m34)
+scalar_sub_impl!(Mat3x4, m11, m12, m13, m14, m21, m22, m23, m24, m31, m32, m33, m34)
+scalar_mul_impl!(Mat3x4, m11, m12, m13, m14, m21, m22, m23, m24, m31, m32, m33, m34)
+scalar_div_impl!(Mat3x4, m11, m12, m13, m14, m21, m22, m23, m24, m31, m32, m33, m34)
+absolute_impl!(Mat3x4, m11, m12, m13, m14,
+                       m21, m22, m23, m24,
+                       m31, m32, m33, m34)
+zero_impl!(Mat3x4, m11, m12, m13, m14,
+                   m21, m22, m23, m24,
+                   m31, m32, m33, m34)
+rect_iterable_impl!(Mat3x4, 3, 4)
+rect_indexable_impl!(Mat3x4, 3, 4)
+rect_row_impl!(Mat3x4, Vec4, 3, 4)
+rect_col_impl!(Mat3x4, Vec3, 3, 4)
+rect_transpose_impl!(Mat3x4, Mat4x3, 3, 4)
+rect_mat_mul_vec_impl!(Mat3x4, Vec4, Vec3, 3, 4)
+approx_eq_impl!(Mat3x4)
+
+/// Matrix with 3 rows and 5 columns.
+#[deriving(Eq, PartialEq, Encodable, Decodable, Clone, Hash, Rand, Show)]
+pub struct Mat3x5<N> {
+    pub m11: N, pub m21: N, pub m31: N,
+    pub m12: N, pub m22: N, pub m32: N,
+    pub m13: N, pub m23: N, pub m33: N,
+    pub m14: N, pub m24: N, pub m34: N,
+    pub m15: N, pub m25: N, pub m35: N
+}
+
+rect_mat_impl!(Mat3x5, 3, 5, m11, m12, m13, m14, m15,
+                             m21, m22, m23, m24, m25,
+                             m31, m32, m33, m34, m35)
+mat_cast_impl!(Mat3x5, m11, m12, m13, m14, m15,
+                       m21, m22, m23, m24, m25,
+                       m31, m32, m33, m34, m35)
+add_impl!(Mat3x5, m11, m12, m13, m14, m15, m21, m22, m23, m24, m25, m31, m32, m33, m34, m35)
+sub_impl!(Mat3x5, m11, m12, m13, m14, m15, m21, m22, m23, m24, m25, m31, m32, m33, m34, m35)
+scalar_add_impl!(Mat3x5, m11, m12, m13, m14, m15, m21, m22, m23, m24, m25, m31, m32, m33, m34, m35)
+scalar_sub_impl!(Mat3x5, m11, m12, m13, m14, m15, m21, m22, m23, m24, m25, m31, m32, m33, m34, m35)
+scalar_mul_impl!(Mat3x5, m11, m12, m13, m14, m15, m21, m22, m23, m24, m25, m31, m32, m33, m34, m35)
+scalar_div_impl!(Mat3x5, m11, m12, m13, m14, m15, m21, m22, m23, m24, m25, m31, m32, m33, m34, m35)
+absolute_impl!(Mat3x5, m11, m12, m13, m14, m15,
+                       m21, m22, m23, m24, m25,
+                       m31, m32, m33, m34, m35)
+zero_impl!(Mat3x5, m11, m12, m13, m14, m15,
+                   m21, m22, m23, m24, m25,
+                   m31, m32, m33, m34, m35)
+rect_iterable_impl!(Mat3x5, 3, 5)
+rect_indexable_impl!(Mat3x5, 3, 5)
+rect_row_impl!(Mat3x5, Vec5, 3, 5)
+rect_col_impl!(Mat3x5, Vec3, 3, 5)
+rect_transpose_impl!(Mat3x5, Mat5x3, 3, 5)
+rect_mat_mul_vec_impl!(Mat3x5, Vec5, Vec3, 3, 5)
+approx_eq_impl!(Mat3x5)
+
+/// Matrix with 3 rows and 6 columns.
+#[deriving(Eq, PartialEq, Encodable, Decodable, Clone, Hash, Rand, Show)]
+pub struct Mat3x6<N> {
+    pub m11: N, pub m21: N, pub m31: N,
+    pub m12: N, pub m22: N, pub m32: N,
+    pub m13: N, pub m23: N, pub m33: N,
+    pub m14: N, pub m24: N, pub m34: N,
+    pub m15: N, pub m25: N, pub m35: N,
+    pub m16: N, pub m26: N, pub m36: N
+}
+
+rect_mat_impl!(Mat3x6, 3, 6, m11, m12, m13, m14, m15, m16,
+                             m21, m22, m23, m24, m25, m26,
+                             m31, m32, m33, m34, m35, m36)
+mat_cast_impl!(Mat3x6, m11, m12, m13, m14, m15, m16,
+                       m21, m22, m23, m24, m25, m26,
+                       m31, m32, m33, m34, m35, m36)
+add_impl!(Mat3x6,
+  m11, m12, m13, m14, m15, m16,
+  m21, m22, m23, m24, m25, m26,
+  m31, m32, m33, m34, m35, m36
+)
+sub_impl!(Mat3x6,
+  m11, m12, m13, m14, m15, m16,
+  m21, m22, m23, m24, m25, m26,
+  m31, m32, m33, m34, m35, m36
+)
+scalar_add_impl!(Mat3x6,
+  m11, m12, m13, m14, m15, m16,
+  m21, m22, m23, m24, m25, m26,
+  m31, m32, m33, m34, m35, m36
+)
+scalar_sub_impl!(Mat3x6,
+  m11, m12, m13, m14, m15, m16,
+  m21, m22, m23, m24, m25, m26,
+  m31, m32, m33, m34, m35, m36
+)
+scalar_mul_impl!(Mat3x6,
+  m11, m12, m13, m14, m15, m16,
+  m21, m22, m23, m24, m25, m26,
+  m31, m32, m33, m34, m35, m36
+)
+scalar_div_impl!(Mat3x6,
+  m11, m12, m13, m14, m15, m16,
+  m21, m22, m23, m24, m25, m26,
+  m31, m32, m33, m34, m35, m36
+)
+absolute_impl!(Mat3x6, m11, m12, m13, m14, m15, m16,
+                       m21, m22, m23, m24, m25, m26,
+                       m31, m32, m33, m34, m35, m36)
+zero_impl!(Mat3x6, m11, m12, m13, m14, m15, m16,
+                   m21, m22, m23, m24, m25, m26,
+                   m31, m32, m33, m34, m35, m36)
+rect_iterable_impl!(Mat3x6, 3, 6)
+rect_indexable_impl!(Mat3x6, 3, 6)
+rect_row_impl!(Mat3x6, Vec6, 3, 6)
+rect_col_impl!(Mat3x6, Vec3, 3, 6)
+rect_transpose_impl!(Mat3x6, Mat6x3, 3, 6)
+rect_mat_mul_vec_impl!(Mat3x6, Vec6, Vec3, 3, 6)
+approx_eq_impl!(Mat3x6)
+
+/// Matrix with 4 rows and 1 column.
+#[deriving(Eq, PartialEq, Encodable, Decodable, Clone, Hash, Rand, Show)]
+pub struct Mat4x1<N> {
+    pub m11: N, pub m21: N, pub m31: N, pub m41: N
+}
+
+rect_mat_impl!(Mat4x1, 4, 1, m11,
+                             m21,
+                             m31,
+                             m41)
+mat_cast_impl!(Mat4x1, m11,
+                       m21,
+                       m31,
+                       m41)
+add_impl!(Mat4x1, m11, m21, m31, m41)
+sub_impl!(Mat4x1, m11, m21, m31, m41)
+scalar_add_impl!(Mat4x1, m11, m21, m31, m41)
+scalar_sub_impl!(Mat4x1, m11, m21, m31, m41)
+scalar_mul_impl!(Mat4x1, m11, m21, m31, m41)
+scalar_div_impl!(Mat4x1, m11, m21, m31, m41)
+absolute_impl!(Mat4x1, m11,
+                       m21,
+                       m31,
+                       m41)
+zero_impl!(Mat4x1, m11,
+                   m21,
+                   m31,
+                   m41)
+rect_iterable_impl!(Mat4x1, 4, 1)
+rect_indexable_impl!(Mat4x1, 4, 1)
+rect_row_impl!(Mat4x1, Vec1, 4, 1)
+rect_col_impl!(Mat4x1, Vec4, 4, 1)
+rect_transpose_impl!(Mat4x1, Mat1x4, 4, 1)
+rect_mat_mul_vec_impl!(Mat4x1, Vec1, Vec4, 4, 1)
+approx_eq_impl!(Mat4x1)
+
+/// Matrix with 4 rows and 2 columns.
+#[deriving(Eq, PartialEq, Encodable, Decodable, Clone, Hash, Rand, Show)]
+pub struct Mat4x2<N> {
+    pub m11: N, pub m21: N, pub m31: N, pub m41: N,
+    pub m12: N, pub m22: N, pub m32: N, pub m42: N
+}
+
+rect_mat_impl!(Mat4x2, 4, 2, m11, m12,
+                             m21, m22,
+                             m31, m32,
+                             m41, m42)
+mat_cast_impl!(Mat4x2, m11, m12,
+                       m21, m22,
+                       m31, m32,
+                       m41, m42)
+add_impl!(Mat4x2, m11, m12, m21, m22, m31, m32, m41, m42)
+sub_impl!(Mat4x2, m11, m12, m21, m22, m31, m32, m41, m42)
+scalar_add_impl!(Mat4x2, m11, m12, m21, m22, m31, m32, m41, m42)
+scalar_sub_impl!(Mat4x2, m11, m12, m21, m22, m31, m32, m41, m42)
+scalar_mul_impl!(Mat4x2, m11, m12, m21, m22, m31, m32, m41, m42)
+scalar_div_impl!(Mat4x2, m11, m12, m21, m22, m31, m32, m41, m42)
+absolute_impl!(Mat4x2, m11, m12,
+                       m21, m22,
+                       m31, m32,
+                       m41, m42)
+zero_impl!(Mat4x2, m11, m12,
+                   m21, m22,
+                   m31, m32,
+                   m41, m42)
+rect_iterable_impl!(Mat4x2, 4, 2)
+rect_indexable_impl!(Mat4x2, 4, 2)
+rect_row_impl!(Mat4x2, Vec2, 4, 2)
+rect_col_impl!(Mat4x2, Vec4, 4, 2)
+rect_transpose_impl!(Mat4x2, Mat2x4, 4, 2)
+rect_mat_mul_vec_impl!(Mat4x2, Vec2, Vec4, 4, 2)
+approx_eq_impl!(Mat4x2)
+
+/// Matrix with 4 rows and 3 columns.
+#[deriving(Eq, PartialEq, Encodable, Decodable, Clone, Hash, Rand, Show)]
+pub struct Mat4x3<N> {
+    pub m11: N, pub m21: N, pub m31: N, pub m41: N,
+    pub m12: N, pub m22: N, pub m32: N, pub m42: N,
+    pub m13: N, pub m23: N, pub m33: N, pub m43: N
+}
+
+rect_mat_impl!(Mat4x3, 4, 3, m11, m12, m13,
+                             m21, m22, m23,
+                             m31, m32, m33,
+                             m41, m42, m43)
+mat_cast_impl!(Mat4x3, m11, m12, m13,
+                       m21, m22, m23,
+                       m31, m32, m33,
+                       m41, m42, m43)
+add_impl!(Mat4x3, m11, m12, m13, m21, m22, m23, m31, m32, m33, m41, m42, m43)
+sub_impl!(Mat4x3, m11, m12, m13, m21, m22, m23, m31, m32, m33, m41, m42, m43)
+scalar_add_impl!(Mat4x3, m11, m12, m13, m21, m22, m23, m31, m32, m33, m41, m42, m43)
+scalar_sub_impl!(Mat4x3, m11, m12, m13, m21, m22, m23, m31, m32, m33, m41, m42, m43)
+scalar_mul_impl!(Mat4x3, m11, m12, m13, m21, m22, m23, m31, m32, m33, m41, m42, m43)
+scalar_div_impl!(Mat4x3, m11, m12, m13, m21, m22, m23, m31, m32, m33, m41, m42, m43)
+absolute_impl!(Mat4x3, m11, m12, m13,
+                       m21, m22, m23,
+                       m31, m32, m33,
+                       m41, m42, m43)
+zero_impl!(Mat4x3, m11, m12, m13,
+                   m21, m22, m23,
+                   m31, m32, m33,
+                   m41, m42, m43)
+rect_iterable_impl!(Mat4x3, 4, 3)
+rect_indexable_impl!(Mat4x3, 4, 3)
+rect_row_impl!(Mat4x3, Vec3, 4, 3)
+rect_col_impl!(Mat4x3, Vec4, 4, 3)
+rect_transpose_impl!(Mat4x3, Mat3x4, 4, 3)
+rect_mat_mul_vec_impl!(Mat4x3, Vec3, Vec4, 4, 3)
+approx_eq_impl!(Mat4x3)
+
+/// Matrix with 4 rows and 5 columns.
+#[deriving(Eq, PartialEq, Encodable, Decodable, Clone, Hash, Rand, Show)]
+pub struct Mat4x5<N> {
+    pub m11: N, pub m21: N, pub m31: N, pub m41: N,
+    pub m12: N, pub m22: N, pub m32: N, pub m42: N,
+    pub m13: N, pub m23: N, pub m33: N, pub m43: N,
+    pub m14: N, pub m24: N, pub m34: N, pub m44: N,
+    pub m15: N, pub m25: N, pub m35: N, pub m45: N
+}
+
+rect_mat_impl!(Mat4x5, 4, 5, m11, m12, m13, m14, m15,
+                             m21, m22, m23, m24, m25,
+                             m31, m32, m33, m34, m35,
+                             m41, m42, m43, m44, m45)
+mat_cast_impl!(Mat4x5, m11, m12, m13, m14, m15,
+                       m21, m22, m23, m24, m25,
+                       m31, m32, m33, m34, m35,
+                       m41, m42, m43, m44, m45)
+add_impl!(Mat4x5,
+  m11, m12, m13, m14, m15,
+  m21, m22, m23, m24, m25,
+  m31, m32, m33, m34, m35,
+  m41, m42, m43, m44, m45
+)
+sub_impl!(Mat4x5,
+  m11, m12, m13, m14, m15,
+  m21, m22, m23, m24, m25,
+  m31, m32, m33, m34, m35,
+  m41, m42, m43, m44, m45
+)
+scalar_add_impl!(Mat4x5,
+  m11, m12, m13, m14, m15,
+  m21, m22, m23, m24, m25,
+  m31, m32, m33, m34, m35,
+  m41, m42, m43, m44, m45
+)
+scalar_sub_impl!(Mat4x5,
+  m11, m12, m13, m14, m15,
+  m21, m22, m23, m24, m25,
+  m31, m32, m33, m34, m35,
+  m41, m42, m43, m44, m45
+)
+scalar_mul_impl!(Mat4x5,
+  m11, m12, m13, m14, m15,
+  m21, m22, m23, m24, m25,
+  m31, m32, m33, m34, m35,
+  m41, m42, m43, m44, m45
+)
+scalar_div_impl!(Mat4x5,
+  m11, m12, m13, m14, m15,
+  m21, m22, m23, m24, m25,
+  m31, m32, m33, m34, m35,
+  m41, m42, m43, m44, m45
+)
+absolute_impl!(Mat4x5, m11, m12, m13, m14, m15,
+                       m21, m22, m23, m24, m25,
+                       m31, m32, m33, m34, m35,
+                       m41, m42, m43, m44, m45)
+zero_impl!(Mat4x5, m11, m12, m13, m14, m15,
+                   m21, m22, m23, m24, m25,
+                   m31, m32, m33, m34, m35,
+                   m41, m42, m43, m44, m45)
+rect_iterable_impl!(Mat4x5, 4, 5)
+rect_indexable_impl!(Mat4x5, 4, 5)
+rect_row_impl!(Mat4x5, Vec5, 4, 5)
+rect_col_impl!(Mat4x5, Vec4, 4, 5)
+rect_transpose_impl!(Mat4x5, Mat5x4, 4, 5)
+rect_mat_mul_vec_impl!(Mat4x5, Vec5, Vec4, 4, 5)
+approx_eq_impl!(Mat4x5)
+
+/// Matrix with 4 rows and 6 columns.
+#[deriving(Eq, PartialEq, Encodable, Decodable, Clone, Hash, Rand, Show)]
+pub struct Mat4x6<N> {
+    pub m11: N, pub m21: N, pub m31: N, pub m41: N,
+    pub m12: N, pub m22: N, pub m32: N, pub m42: N,
+    pub m13: N, pub m23: N, pub m33: N, pub m43: N,
+    pub m14: N, pub m24: N, pub m34: N, pub m44: N,
+    pub m15: N, pub m25: N, pub m35: N, pub m45: N,
+    pub m16: N, pub m26: N, pub m36: N, pub m46: N
+}
+
+rect_mat_impl!(Mat4x6, 4, 6, m11, m12, m13, m14, m15, m16,
+                             m21, m22, m23, m24, m25, m26,
+                             m31, m32, m33, m34, m35, m36,
+                             m41, m42, m43, m44, m45, m46)
+mat_cast_impl!(Mat4x6, m11, m12, m13, m14, m15, m16,
+                       m21, m22, m23, m24, m25, m26,
+                       m31, m32, m33, m34, m35, m36,
+                       m41, m42, m43, m44, m45, m46)
+add_impl!(Mat4x6,
+  m11, m12, m13, m14, m15, m16,
+  m21, m22, m23, m24, m25, m26,
+  m31, m32, m33, m34, m35, m36,
+  m41, m42, m43, m44, m45, m46
+)
+sub_impl!(Mat4x6,
+  m11, m12, m13, m14, m15, m16,
+  m21, m22, m23, m24, m25, m26,
+  m31, m32, m33, m34, m35, m36,
+  m41, m42, m43, m44, m45, m46
+)
+scalar_add_impl!(Mat4x6,
+  m11, m12, m13, m14, m15, m16,
+  m21, m22, m23, m24, m25, m26,
+  m31, m32, m33, m34, m35, m36,
+  m41, m42, m43, m44, m45, m46
+)
+scalar_sub_impl!(Mat4x6,
+  m11, m12, m13, m14, m15, m16,
+  m21, m22, m23, m24, m25, m26,
+  m31, m32, m33, m34, m35, m36,
+  m41, m42, m43, m44, m45, m46
+)
+scalar_mul_impl!(Mat4x6,
+  m11, m12, m13, m14, m15, m16,
+  m21, m22, m23, m24, m25, m26,
+  m31, m32, m33, m34, m35, m36,
+  m41, m42, m43, m44, m45, m46
+)
+scalar_div_impl!(Mat4x6,
+  m11, m12, m13, m14, m15, m16,
+  m21, m22, m23, m24, m25, m26,
+  m31, m32, m33, m34, m35, m36,
+  m41, m42, m43, m44, m45, m46
+)
+absolute_impl!(Mat4x6, m11, m12, m13, m14, m15, m16,
+                       m21, m22, m23, m24, m25, m26,
+                       m31, m32, m33, m34, m35, m36,
+                       m41, m42, m43, m44, m45, m46)
+zero_impl!(Mat4x6, m11, m12, m13, m14, m15, m16,
+                   m21, m22, m23, m24, m25, m26,
+                   m31, m32, m33, m34, m35, m36,
+                   m41, m42, m43, m44, m45, m46)
+rect_iterable_impl!(Mat4x6, 4, 6)
+rect_indexable_impl!(Mat4x6, 4, 6)
+rect_row_impl!(Mat4x6, Vec6, 4, 6)
+rect_col_impl!(Mat4x6, Vec4, 4, 6)
+rect_transpose_impl!(Mat4x6, Mat6x4, 4, 6)
+rect_mat_mul_vec_impl!(Mat4x6, Vec6, Vec4, 4, 6)
+approx_eq_impl!(Mat4x6)
+
+/// Matrix with 5 rows and 1 column.
+#[deriving(Eq, PartialEq, Encodable, Decodable, Clone, Hash, Rand, Show)]
+pub struct Mat5x1<N> {
+    pub m11: N, pub m21: N, pub m31: N, pub m41: N, pub m51: N
+}
+
+rect_mat_impl!(Mat5x1, 5, 1, m11,
+                             m21,
+                             m31,
+                             m41,
+                             m51)
+mat_cast_impl!(Mat5x1, m11,
+                       m21,
+                       m31,
+                       m41,
+                       m51)
+add_impl!(Mat5x1, m11, m21, m31, m41, m51)
+sub_impl!(Mat5x1, m11, m21, m31, m41, m51)
+scalar_add_impl!(Mat5x1, m11, m21, m31, m41, m51)
+scalar_sub_impl!(Mat5x1, m11, m21, m31, m41, m51)
+scalar_mul_impl!(Mat5x1, m11, m21, m31, m41, m51)
+scalar_div_impl!(Mat5x1, m11, m21, m31, m41, m51)
+absolute_impl!(Mat5x1, m11,
+                       m21,
+                       m31,
+                       m41,
+                       m51)
+zero_impl!(Mat5x1, m11,
+                   m21,
+                   m31,
+                   m41,
+                   m51)
+rect_iterable_impl!(Mat5x1, 5, 1)
+rect_indexable_impl!(Mat5x1, 5, 1)
+rect_row_impl!(Mat5x1, Vec1, 5, 1)
+rect_col_impl!(Mat5x1, Vec5, 5, 1)
+rect_transpose_impl!(Mat5x1, Mat1x5, 5, 1)
+rect_mat_mul_vec_impl!(Mat5x1, Vec1, Vec5, 5, 1)
+approx_eq_impl!(Mat5x1)
+
+/// Matrix with 5 rows and 2 columns.
+#[deriving(Eq, PartialEq, Encodable, Decodable, Clone, Hash, Rand, Show)]
+pub struct Mat5x2<N> {
+    pub m11: N, pub m21: N, pub m31: N, pub m41: N, pub m51: N,
+    pub m12: N, pub m22: N, pub m32: N, pub m42: N, pub m52: N
+}
+
+rect_mat_impl!(Mat5x2, 5, 2, m11, m12,
+                             m21, m22,
+                             m31, m32,
+                             m41, m42,
+                             m51, m52)
+mat_cast_impl!(Mat5x2, m11, m12,
+                       m21, m22,
+                       m31, m32,
+                       m41, m42,
+                       m51, m52)
+add_impl!(Mat5x2, m11, m12, m21, m22, m31, m32, m41, m42, m51, m52)
+sub_impl!(Mat5x2, m11, m12, m21, m22, m31, m32, m41, m42, m51, m52)
+scalar_add_impl!(Mat5x2, m11, m12, m21, m22, m31, m32, m41, m42, m51, m52)
+scalar_sub_impl!(Mat5x2, m11, m12, m21, m22, m31, m32, m41, m42, m51, m52)
+scalar_mul_impl!(Mat5x2, m11, m12, m21, m22, m31, m32, m41, m42, m51, m52)
+scalar_div_impl!(Mat5x2, m11, m12, m21, m22, m31, m32, m41, m42, m51, m52)
+absolute_impl!(Mat5x2, m11, m12,
+                       m21, m22,
+                       m31, m32,
+                       m41, m42,
+                       m51, m52)
+zero_impl!(Mat5x2, m11, m12,
+                   m21, m22,
+                   m31, m32,
+                   m41, m42,
+                   m51, m52)
+rect_iterable_impl!(Mat5x2, 5, 2)
+rect_indexable_impl!(Mat5x2, 5, 2)
+rect_row_impl!(Mat5x2, Vec2, 5, 2)
+rect_col_impl!(Mat5x2, Vec5, 5, 2)
+rect_transpose_impl!(Mat5x2, Mat2x5, 5, 2)
+rect_mat_mul_vec_impl!(Mat5x2, Vec2, Vec5, 5, 2)
+approx_eq_impl!(Mat5x2)
+
+/// Matrix with 5 rows and 3 columns.
+#[deriving(Eq, PartialEq, Encodable, Decodable, Clone, Hash, Rand, Show)]
+pub struct Mat5x3<N> {
+    pub m11: N, pub m21: N, pub m31: N, pub m41: N, pub m51: N,
+    pub m12: N, pub m22: N, pub m32: N, pub m42: N, pub m52: N,
+    pub m13: N, pub m23: N, pub m33: N, pub m43: N, pub m53: N
+}
+
+rect_mat_impl!(Mat5x3, 5, 3, m11, m12, m13,
+                             m21, m22, m23,
+                             m31, m32, m33,
+                             m41, m42, m43,
+                             m51, m52, m53)
+mat_cast_impl!(Mat5x3, m11, m12, m13,
+                       m21, m22, m23,
+                       m31, m32, m33,
+                       m41, m42, m43,
+                       m51, m52, m53)
+add_impl!(Mat5x3, m11, m12, m13, m21, m22, m23, m31, m32, m33, m41, m42, m43, m51, m52, m53)
+sub_impl!(Mat5x3, m11, m12, m13, m21, m22, m23, m31, m32, m33, m41, m42, m43, m51, m52, m53)
+scalar_add_impl!(Mat5x3, m11, m12, m13, m21, m22, m23, m31, m32, m33, m41, m42, m43, m51, m52, m53)
+scalar_sub_impl!(Mat5x3, m11, m12, m13, m21, m22, m23, m31, m32, m33, m41, m42, m43, m51, m52, m53)
+scalar_mul_impl!(Mat5x3, m11, m12, m13, m21, m22, m23, m31, m32, m33, m41, m42, m43, m51, m52, m53)
+scalar_div_impl!(Mat5x3, m11, m12, m13, m21, m22, m23, m31, m32, m33, m41, m42, m43, m51, m52, m53)
+absolute_impl!(Mat5x3, m11, m12, m13,
+                       m21, m22, m23,
+                       m31, m32, m33,
+                       m41, m42, m43,
+                       m51, m52, m53)
+zero_impl!(Mat5x3, m11, m12, m13,
+                   m21, m22, m23,
+                   m31, m32, m33,
+                   m41, m42, m43,
+                   m51, m52, m53)
+rect_iterable_impl!(Mat5x3, 5, 3)
+rect_indexable_impl!(Mat5x3, 5, 3)
+rect_row_impl!(Mat5x3, Vec3, 5, 3)
+rect_col_impl!(Mat5x3, Vec5, 5, 3)
+rect_transpose_impl!(Mat5x3, Mat3x5, 5, 3)
+rect_mat_mul_vec_impl!(Mat5x3, Vec3, Vec5, 5, 3)
+approx_eq_impl!(Mat5x3)
+
+/// Matrix with 5 rows and 4 columns.
+#[deriving(Eq, PartialEq, Encodable, Decodable, Clone, Hash, Rand, Show)]
+pub struct Mat5x4<N> {
+    pub m11: N, pub m21: N, pub m31: N, pub m41: N, pub m51: N,
+    pub m12: N, pub m22: N, pub m32: N, pub m42: N, pub m52: N,
+    pub m13: N, pub m23: N, pub m33: N, pub m43: N, pub m53: N,
+    pub m14: N, pub m24: N, pub m34: N, pub m44: N, pub m54: N
+}
+
+rect_mat_impl!(Mat5x4, 5, 4, m11, m12, m13, m14,
+                             m21, m22, m23, m24,
+                             m31, m32, m33, m34,
+                             m41, m42, m43, m44,
+                             m51, m52, m53, m54)
+mat_cast_impl!(Mat5x4, m11, m12, m13, m14,
+                       m21, m22, m23, m24,
+                       m31, m32, m33, m34,
+                       m41, m42, m43, m44,
+                       m51, m52, m53, m54)
+add_impl!(Mat5x4,
+  m11, m12, m13, m14,
+  m21, m22, m23, m24,
+  m31, m32, m33, m34,
+  m41, m42, m43, m44,
+  m51, m52, m53, m54
+)
+sub_impl!(Mat5x4,
+  m11, m12, m13, m14,
+  m21, m22, m23, m24,
+  m31, m32, m33, m34,
+  m41, m42, m43, m44,
+  m51, m52, m53, m54
+)
+scalar_add_impl!(Mat5x4,
+  m11, m12, m13, m14,
+  m21, m22, m23, m24,
+  m31, m32, m33, m34,
+  m41, m42, m43, m44,
+  m51, m52, m53, m54
+)
+scalar_sub_impl!(Mat5x4,
+  m11, m12, m13, m14,
+  m21, m22, m23, m24,
+  m31, m32, m33, m34,
+  m41, m42, m43, m44,
+  m51, m52, m53, m54
+)
+scalar_mul_impl!(Mat5x4,
+  m11, m12, m13, m14,
+  m21, m22, m23, m24,
+  m31, m32, m33, m34,
+  m41, m42, m43, m44,
+  m51, m52, m53, m54
+)
+scalar_div_impl!(Mat5x4,
+  m11, m12, m13, m14,
+  m21, m22, m23, m24,
+  m31, m32, m33, m34,
+  m41, m42, m43, m44,
+  m51, m52, m53, m54
+)
+absolute_impl!(Mat5x4, m11, m12, m13, m14,
+                       m21, m22, m23, m24,
+                       m31, m32, m33, m34,
+                       m41, m42, m43, m44,
+                       m51, m52, m53, m54)
+zero_impl!(Mat5x4, m11, m12, m13, m14,
+                   m21, m22, m23, m24,
+                   m31, m32, m33, m34,
+                   m41, m42, m43, m44,
+                   m51, m52, m53, m54)
+rect_iterable_impl!(Mat5x4, 5, 4)
+rect_indexable_impl!(Mat5x4, 5, 4)
+rect_row_impl!(Mat5x4, Vec4, 5, 4)
+rect_col_impl!(Mat5x4, Vec5, 5, 4)
+rect_transpose_impl!(Mat5x4, Mat4x5, 5, 4)
+rect_mat_mul_vec_impl!(Mat5x4, Vec4, Vec5, 5, 4)
+approx_eq_impl!(Mat5x4)
+
+/// Matrix with 5 rows and 6 columns.
+#[deriving(Eq, PartialEq, Encodable, Decodable, Clone, Hash, Rand, Show)]
+pub struct Mat5x6<N> {
+    pub m11: N, pub m21: N, pub m31: N, pub m41: N, pub m51: N,
+    pub m12: N, pub m22: N, pub m32: N, pub m42: N, pub m52: N,
+    pub m13: N, pub m23: N, pub m33: N, pub m43: N, pub m53: N,
+    pub m14: N, pub m24: N, pub m34: N, pub m44: N, pub m54: N,
+    pub m15: N, pub m25: N, pub m35: N, pub m45: N, pub m55: N,
+    pub m16: N, pub m26: N, pub m36: N, pub m46: N, pub m56: N
+}
+
+rect_mat_impl!(Mat5x6, 5, 6, m11, m12, m13, m14, m15, m16,
+                             m21, m22, m23, m24, m25, m26,
+                             m31, m32, m33, m34, m35, m36,
+                             m41, m42, m43, m44, m45, m46,
+                             m51, m52, m53, m54, m55, m56)
+mat_cast_impl!(Mat5x6, m11, m12, m13, m14, m15, m16,
+                       m21, m22, m23, m24, m25, m26,
+                       m31, m32, m33, m34, m35, m36,
+                       m41, m42, m43, m44, m45, m46,
+                       m51, m52, m53, m54, m55, m56)
+add_impl!(Mat5x6,
+  m11, m12, m13, m14, m15, m16,
+  m21, m22, m23, m24, m25, m26,
+  m31, m32, m33, m34, m35, m36,
+  m41, m42, m43, m44, m45, m46,
+  m51, m52, m53, m54, m55, m56
+)
+sub_impl!(Mat5x6,
+  m11, m12, m13, m14, m15, m16,
+  m21, m22, m23, m24, m25, m26,
+  m31, m32, m33, m34, m35, m36,
+  m41, m42, m43, m44, m45, m46,
+  m51, m52, m53, m54, m55, m56
+)
+scalar_add_impl!(Mat5x6,
+  m11, m12, m13, m14, m15, m16,
+  m21, m22, m23, m24, m25, m26,
+  m31, m32, m33, m34, m35, m36,
+  m41, m42, m43, m44, m45, m46,
+  m51, m52, m53, m54, m55, m56
+)
+scalar_sub_impl!(Mat5x6,
+  m11, m12, m13, m14, m15, m16,
+  m21, m22, m23, m24, m25, m26,
+  m31, m32, m33, m34, m35, m36,
+  m41, m42, m43, m44, m45, m46,
+  m51, m52, m53, m54, m55, m56
+)
+scalar_mul_impl!(Mat5x6,
+  m11, m12, m13, m14, m15, m16,
+  m21, m22, m23, m24, m25, m26,
+  m31, m32, m33, m34, m35, m36,
+  m41, m42, m43, m44, m45, m46,
+  m51, m52, m53, m54, m55, m56
+)
+scalar_div_impl!(Mat5x6,
+  m11, m12, m13, m14, m15, m16,
+  m21, m22, m23, m24, m25, m26,
+  m31, m32, m33, m34, m35, m36,
+  m41, m42, m43, m44, m45, m46,
+  m51, m52, m53, m54, m55, m56
+)
+absolute_impl!(Mat5x6, m11, m12, m13, m14, m15, m16,
+                       m21, m22, m23, m24, m25, m26,
+                       m31, m32, m33, m34, m35, m36,
+                       m41, m42, m43, m44, m45, m46,
+                       m51, m52, m53, m54, m55, m56)
+zero_impl!(Mat5x6, m11, m12, m13, m14, m15, m16,
+                   m21, m22, m23, m24, m25, m26,
+                   m31, m32, m33, m34, m35, m36,
+                   m41, m42, m43, m44, m45, m46,
+                   m51, m52, m53, m54, m55, m56)
+rect_iterable_impl!(Mat5x6, 5, 6)
+rect_indexable_impl!(Mat5x6, 5, 6)
+rect_row_impl!(Mat5x6, Vec6, 5, 6)
+rect_col_impl!(Mat5x6, Vec5, 5, 6)
+rect_transpose_impl!(Mat5x6, Mat6x5, 5, 6)
+rect_mat_mul_vec_impl!(Mat5x6, Vec6, Vec5, 5, 6)
+approx_eq_impl!(Mat5x6)
+
+/// Matrix with 6 rows and 1 column.
+#[deriving(Eq, PartialEq, Encodable, Decodable, Clone, Hash, Rand, Show)]
+pub struct Mat6x1<N> {
+    pub m11: N, pub m21: N, pub m31: N, pub m41: N, pub m51: N, pub m61: N
+}
+
+rect_mat_impl!(Mat6x1, 6, 1, m11,
+                             m21,
+                             m31,
+                             m41,
+                             m51,
+                             m61)
+mat_cast_impl!(Mat6x1, m11,
+                       m21,
+                       m31,
+                       m41,
+                       m51,
+                       m61)
+add_impl!(Mat6x1, m11, m21, m31, m41, m51, m61)
+sub_impl!(Mat6x1, m11, m21, m31, m41, m51, m61)
+scalar_add_impl!(Mat6x1, m11, m21, m31, m41, m51, m61)
+scalar_sub_impl!(Mat6x1, m11, m21, m31, m41, m51, m61)
+scalar_mul_impl!(Mat6x1, m11, m21, m31, m41, m51, m61)
+scalar_div_impl!(Mat6x1, m11, m21, m31, m41, m51, m61)
+absolute_impl!(Mat6x1, m11,
+                       m21,
+                       m31,
+                       m41,
+                       m51,
+                       m61)
+zero_impl!(Mat6x1, m11,
+                   m21,
+                   m31,
+                   m41,
+                   m51,
+                   m61)
+rect_iterable_impl!(Mat6x1, 6, 1)
+rect_indexable_impl!(Mat6x1, 6, 1)
+rect_row_impl!(Mat6x1, Vec1, 6, 1)
+rect_col_impl!(Mat6x1, Vec6, 6, 1)
+rect_transpose_impl!(Mat6x1, Mat1x6, 6, 1)
+rect_mat_mul_vec_impl!(Mat6x1, Vec1, Vec6, 6, 1)
+approx_eq_impl!(Mat6x1)
+
+/// Matrix with 6 rows and 2 columns.
+#[deriving(Eq, PartialEq, Encodable, Decodable, Clone, Hash, Rand, Show)]
+pub struct Mat6x2<N> {
+    pub m11: N, pub m21: N, pub m31: N, pub m41: N, pub m51: N, pub m61: N,
+    pub m12: N, pub m22: N, pub m32: N, pub m42: N, pub m52: N, pub m62: N
+}
+
+rect_mat_impl!(Mat6x2, 6, 2, m11, m12,
+                             m21, m22,
+                             m31, m32,
+                             m41, m42,
+                             m51, m52,
+                             m61, m62)
+mat_cast_impl!(Mat6x2, m11, m12,
+                       m21, m22,
+                       m31, m32,
+                       m41, m42,
+                       m51, m52,
+                       m61, m62)
+add_impl!(Mat6x2, m11, m12, m21, m22, m31, m32, m41, m42, m51, m52, m61, m62)
+sub_impl!(Mat6x2, m11, m12, m21, m22, m31, m32, m41, m42, m51, m52, m61, m62)
+scalar_add_impl!(Mat6x2, m11, m12, m21, m22, m31, m32, m41, m42, m51, m52, m61, m62)
+scalar_sub_impl!(Mat6x2, m11, m12, m21, m22, m31, m32, m41, m42, m51, m52, m61, m62)
+scalar_mul_impl!(Mat6x2, m11, m12, m21, m22, m31, m32, m41, m42, m51, m52, m61, m62)
+scalar_div_impl!(Mat6x2, m11, m12, m21, m22, m31, m32, m41, m42, m51, m52, m61, m62)
+absolute_impl!(Mat6x2, m11, m12,
+                       m21, m22,
+                       m31, m32,
+                       m41, m42,
+                       m51, m52,
+                       m61, m62)
+zero_impl!(Mat6x2, m11, m12,
+                   m21, m22,
+                   m31, m32,
+                   m41, m42,
+                   m51, m52,
+                   m61, m62)
+rect_iterable_impl!(Mat6x2, 6, 2)
+rect_indexable_impl!(Mat6x2, 6, 2)
+rect_row_impl!(Mat6x2, Vec2, 6, 2)
+rect_col_impl!(Mat6x2, Vec6, 6, 2)
+rect_transpose_impl!(Mat6x2, Mat2x6, 6, 2)
+rect_mat_mul_vec_impl!(Mat6x2, Vec2, Vec6, 6, 2)
+approx_eq_impl!(Mat6x2)
+
+/// Matrix with 6 rows and 3 columns.
+#[deriving(Eq, PartialEq, Encodable, Decodable, Clone, Hash, Rand, Show)]
+pub struct Mat6x3<N> {
+    pub m11: N, pub m21: N, pub m31: N, pub m41: N, pub m51: N, pub m61: N,
+    pub m12: N, pub m22: N, pub m32: N, pub m42: N, pub m52: N, pub m62: N,
+    pub m13: N, pub m23: N, pub m33: N, pub m43: N, pub m53: N, pub m63: N
+}
+
+rect_mat_impl!(Mat6x3, 6, 3, m11, m12, m13,
+                             m21, m22, m23,
+                             m31, m32, m33,
+                             m41, m42, m43,
+                             m51, m52, m53,
+                             m61, m62, m63)
+mat_cast_impl!(Mat6x3, m11, m12, m13,
+                       m21, m22, m23,
+                       m31, m32, m33,
+                       m41, m42, m43,
+                       m51, m52, m53,
+                       m61, m62, m63)
+add_impl!(Mat6x3,
+  m11, m12, m13,
+  m21, m22, m23,
+  m31, m32, m33,
+  m41, m42, m43,
+  m51, m52, m53,
+  m61, m62, m63
+)
+sub_impl!(Mat6x3,
+  m11, m12, m13,
+  m21, m22, m23,
+  m31, m32, m33,
+  m41, m42, m43,
+  m51, m52, m53,
+  m61, m62, m63
+)
+scalar_add_impl!(Mat6x3,
+  m11, m12, m13,
+  m21, m22, m23,
+  m31, m32, m33,
+  m41, m42, m43,
+  m51, m52, m53,
+  m61, m62, m63
+)
+scalar_sub_impl!(Mat6x3,
+  m11, m12, m13,
+  m21, m22, m23,
+  m31, m32, m33,
+  m41, m42, m43,
+  m51, m52, m53,
+  m61, m62, m63
+)
+scalar_mul_impl!(Mat6x3,
+  m11, m12, m13,
+  m21, m22, m23,
+  m31, m32, m33,
+  m41, m42, m43,
+  m51, m52, m53,
+  m61, m62, m63
+)
+scalar_div_impl!(Mat6x3,
+  m11, m12, m13,
+  m21, m22, m23,
+  m31, m32, m33,
+  m41, m42, m43,
+  m51, m52, m53,
+  m61, m62, m63
+)
+absolute_impl!(Mat6x3, m11, m12, m13,
+                       m21, m22, m23,
+                       m31, m32, m33,
+                       m41, m42, m43,
+                       m51, m52, m53,
+                       m61, m62, m63)
+zero_impl!(Mat6x3, m11, m12, m13,
+                   m21, m22, m23,
+                   m31, m32, m33,
+                   m41, m42, m43,
+                   m51, m52, m53,
+                   m61, m62, m63)
+rect_iterable_impl!(Mat6x3, 6, 3)
+rect_indexable_impl!(Mat6x3, 6, 3)
+rect_row_impl!(Mat6x3, Vec3, 6, 3)
+rect_col_impl!(Mat6x3, Vec6, 6, 3)
+rect_transpose_impl!(Mat6x3, Mat3x6, 6, 3)
+rect_mat_mul_vec_impl!(Mat6x3, Vec3, Vec6, 6, 3)
+approx_eq_impl!(Mat6x3)
+
+/// Matrix with 6 rows and 4 columns.
+#[deriving(Eq, PartialEq, Encodable, Decodable, Clone, Hash, Rand, Show)]
+pub struct Mat6x4<N> {
+    pub m11: N, pub m21: N, pub m31: N, pub m41: N, pub m51: N, pub m61: N,
+    pub m12: N, pub m22: N, pub m32: N, pub m42: N, pub m52: N, pub m62: N,
+    pub m13: N, pub m23: N, pub m33: N, pub m43: N, pub m53: N, pub m63: N,
+    pub m14: N, pub m24: N, pub m34: N, pub m44: N, pub m54: N, pub m64: N
+}
+
+rect_mat_impl!(Mat6x4, 6, 4, m11, m12, m13, m14,
+                             m21, m22, m23, m24,
+                             m31, m32, m33, m34,
+                             m41, m42, m43, m44,
+                             m51, m52, m53, m54,
+                             m61, m62, m63, m64)
+mat_cast_impl!(Mat6x4, m11, m12, m13, m14,
+                       m21, m22, m23, m24,
+                       m31, m32, m33, m34,
+                       m41, m42, m43, m44,
+                       m51, m52, m53, m54,
+                       m61, m62, m63, m64)
+add_impl!(Mat6x4,
+  m11, m12, m13, m14,
+  m21, m22, m23, m24,
+  m31, m32, m33, m34,
+  m41, m42, m43, m44,
+  m51, m52, m53, m54,
+  m61, m62, m63, m64
+)
+sub_impl!(Mat6x4,
+  m11, m12, m13, m14,
+  m21, m22, m23, m24,
+  m31, m32, m33, m34,
+  m41, m42, m43, m44,
+  m51, m52, m53, m54,
+  m61, m62, m63, m64
+)
+scalar_add_impl!(Mat6x4,
+  m11, m12, m13, m14,
+  m21, m22, m23, m24,
+  m31, m32, m33, m34,
+  m41, m42, m43, m44,
+  m51, m52, m53, m54,
+  m61, m62, m63, m64
+)
+scalar_sub_impl!(Mat6x4,
+  m11, m12, m13, m14,
+  m21, m22, m23, m24,
+  m31, m32, m33, m34,
+  m41, m42, m43, m44,
+  m51, m52, m53, m54,
+  m61, m62, m63, m64
+)
+scalar_mul_impl!(Mat6x4,
+  m11, m12, m13, m14,
+  m21, m22, m23, m24,
+  m31, m32, m33, m34,
+  m41, m42, m43, m44,
+  m51, m52, m53, m54,
+  m61, m62, m63, m64
+)
+scalar_div_impl!(Mat6x4,
+  m11, m12, m13, m14,
+  m21, m22, m23, m24,
+  m31, m32, m33, m34,
+  m41, m42, m43, m44,
+  m51, m52, m53, m54,
+  m61, m62, m63, m64
+)
+absolute_impl!(Mat6x4, m11, m12, m13, m14,
+                       m21, m22, m23, m24,
+                       m31, m32, m33, m34,
+                       m41, m42, m43, m44,
+                       m51, m52, m53, m54,
+                       m61, m62, m63, m64)
+zero_impl!(Mat6x4, m11, m12, m13, m14,
+                   m21, m22, m23, m24,
+                   m31, m32, m33, m34,
+                   m41, m42, m43, m44,
+                   m51, m52, m53, m54,
+                   m61, m62, m63, m64)
+rect_iterable_impl!(Mat6x4, 6, 4)
+rect_indexable_impl!(Mat6x4, 6, 4)
+rect_row_impl!(Mat6x4, Vec4, 6, 4)
+rect_col_impl!(Mat6x4, Vec6, 6, 4)
+rect_transpose_impl!(Mat6x4, Mat4x6, 6, 4)
+rect_mat_mul_vec_impl!(Mat6x4, Vec4, Vec6, 6, 4)
+approx_eq_impl!(Mat6x4)
+
+/// Matrix with 6 rows and 5 columns.
+#[deriving(Eq, PartialEq, Encodable, Decodable, Clone, Hash, Rand, Show)]
+pub struct Mat6x5<N> {
+    pub m11: N, pub m21: N, pub m31: N, pub m41: N, pub m51: N, pub m61: N,
+    pub m12: N, pub m22: N, pub m32: N, pub m42: N, pub m52: N, pub m62: N,
+    pub m13: N, pub m23: N, pub m33: N, pub m43: N, pub m53: N, pub m63: N,
+    pub m14: N, pub m24: N, pub m34: N, pub m44: N, pub m54: N, pub m64: N,
+    pub m15: N, pub m25: N, pub m35: N, pub m45: N, pub m55: N, pub m65: N
+}
+
+rect_mat_impl!(Mat6x5, 6, 5, m11, m12, m13, m14, m15,
+                             m21, m22, m23, m24, m25,
+                             m31, m32, m33, m34, m35,
+                             m41, m42, m43, m44, m45,
+                             m51, m52, m53, m54, m55,
+                             m61, m62, m63, m64, m65)
+mat_cast_impl!(Mat6x5, m11, m12, m13, m14, m15,
+                       m21, m22, m23, m24, m25,
+                       m31, m32, m33, m34, m35,
+                       m41, m42, m43, m44, m45,
+                       m51, m52, m53, m54, m55,
+                       m61, m62, m63, m64, m65)
+add_impl!(Mat6x5,
+  m11, m12, m13, m14, m15,
+  m21, m22, m23, m24, m25,
+  m31, m32, m33, m34, m35,
+  m41, m42, m43, m44, m45,
+  m51, m52, m53, m54, m55,
+  m61, m62, m63, m64, m65
+)
+sub_impl!(Mat6x5,
+  m11, m12, m13, m14, m15,
+  m21, m22, m23, m24, m25,
+  m31, m32, m33, m34, m35,
+  m41, m42, m43, m44, m45,
+  m51, m52, m53, m54, m55,
+  m61, m62, m63, m64, m65
+)
+scalar_add_impl!(Mat6x5,
+  m11, m12, m13, m14, m15,
+  m21, m22, m23, m24, m25,
+  m31, m32, m33, m34, m35,
+  m41, m42, m43, m44, m45,
+  m51, m52, m53, m54, m55,
+  m61, m62, m63, m64, m65
+)
+scalar_sub_impl!(Mat6x5,
+  m11, m12, m13, m14, m15,
+  m21, m22, m23, m24, m25,
+  m31, m32, m33, m34, m35,
+  m41, m42, m43, m44, m45,
+  m51, m52, m53, m54, m55,
+  m61, m62, m63, m64, m65
+)
+scalar_mul_impl!(Mat6x5,
+  m11, m12, m13, m14, m15,
+  m21, m22, m23, m24, m25,
+  m31, m32, m33, m34, m35,
+  m41, m42, m43, m44, m45,
+  m51, m52, m53, m54, m55,
+  m61, m62, m63, m64, m65
+)
+scalar_div_impl!(Mat6x5,
+  m11, m12, m13, m14, m15,
+  m21, m22, m23, m24, m25,
+  m31, m32, m33, m34, m35,
+  m41, m42, m43, m44, m45,
+  m51, m52, m53, m54, m55,
+  m61, m62, m63, m64, m65
+)
+absolute_impl!(Mat6x5, m11, m12, m13, m14, m15,
+                       m21, m22, m23, m24, m25,
+                       m31, m32, m33, m34, m35,
+                       m41, m42, m43, m44, m45,
+                       m51, m52, m53, m54, m55,
+                       m61, m62, m63, m64, m65)
+zero_impl!(Mat6x5, m11, m12, m13, m14, m15,
+                   m21, m22, m23, m24, m25,
+                   m31, m32, m33, m34, m35,
+                   m41, m42, m43, m44, m45,
+                   m51, m52, m53, m54, m55,
+                   m61, m62, m63, m64, m65)
+rect_iterable_impl!(Mat6x5, 6, 5)
+rect_indexable_impl!(Mat6x5, 6, 5)
+rect_row_impl!(Mat6x5, Vec5, 6, 5)
+rect_col_impl!(Mat6x5, Vec6, 6, 5)
+rect_transpose_impl!(Mat6x5, Mat5x6, 6, 5)
+rect_mat_mul_vec_impl!(Mat6x5, Vec5, Vec6, 6, 5)
+approx_eq_impl!(Mat6x5)
+
+// Products involving at least one rectangular matrix.
+rect_mat_mul_mat_impl!(Mat1, 1, 1; Mat1x2 => Mat1x2, 2;
+                                   Mat1x3 => Mat1x3, 3;
+                                   Mat1x4 => Mat1x4, 4;
+                                   Mat1x5 => Mat1x5, 5;
+                                   Mat1x6 => Mat1x6, 6)
+rect_mat_mul_mat_impl!(Mat1x2, 1, 2; Mat2x1 => Mat1, 1;
+                                     Mat2 => Mat1x2, 2;
+                                     Mat2x3 => Mat1x3, 3;
+                                     Mat2x4 => Mat1x4, 4;
+                                     Mat2x5 => Mat1x5, 5;
+                                     Mat2x6 => Mat1x6, 6)
+rect_mat_mul_mat_impl!(Mat1x3, 1, 3; Mat3x1 => Mat1, 1;
+                                     Mat3x2 => Mat1x2, 2;
+                                     Mat3 => Mat1x3, 3;
+                                     Mat3x4 => Mat1x4, 4;
+                                     Mat3x5 => Mat1x5, 5;
+                                     Mat3x6 => Mat1x6, 6)
+rect_mat_mul_mat_impl!(Mat1x4, 1, 4; Mat4x1 => Mat1, 1;
+                                     Mat4x2 => Mat1x2, 2;
+                                     Mat4x3 => Mat1x3, 3;
+                                     Mat4 => Mat1x4, 4;
+                                     Mat4x5 => Mat1x5, 5;
+                                     Mat4x6 => Mat1x6, 6)
+rect_mat_mul_mat_impl!(Mat1x5, 1, 5; Mat5x1 => Mat1, 1;
+                                     Mat5x2 => Mat1x2, 2;
+                                     Mat5x3 => Mat1x3, 3;
+                                     Mat5x4 => Mat1x4, 4;
+                                     Mat5 => Mat1x5, 5;
+                                     Mat5x6 => Mat1x6, 6)
+rect_mat_mul_mat_impl!(Mat1x6, 1, 6; Mat6x1 => Mat1, 1;
+                                     Mat6x2 => Mat1x2, 2;
+                                     Mat6x3 => Mat1x3, 3;
+                                     Mat6x4 => Mat1x4, 4;
+                                     Mat6x5 => Mat1x5, 5;
+                                     Mat6 => Mat1x6, 6)
+rect_mat_mul_mat_impl!(Mat2x1, 2, 1; Mat1 => Mat2x1, 1;
+                                     Mat1x2 => Mat2, 2;
+                                     Mat1x3 => Mat2x3, 3;
+                                     Mat1x4 => Mat2x4, 4;
+                                     Mat1x5 => Mat2x5, 5;
+                                     Mat1x6 => Mat2x6, 6)
+rect_mat_mul_mat_impl!(Mat2, 2, 2; Mat2x1 => Mat2x1, 1;
+                                   Mat2x3 => Mat2x3, 3;
+                                   Mat2x4 => Mat2x4, 4;
+                                   Mat2x5 => Mat2x5, 5;
+                                   Mat2x6 => Mat2x6, 6)
+rect_mat_mul_mat_impl!(Mat2x3, 2, 3; Mat3x1 => Mat2x1, 1;
+                                     Mat3x2 => Mat2, 2;
+                                     Mat3 => Mat2x3, 3;
+                                     Mat3x4 => Mat2x4, 4;
+                                     Mat3x5 => Mat2x5, 5;
+                                     Mat3x6 => Mat2x6, 6)
+rect_mat_mul_mat_impl!(Mat2x4, 2, 4; Mat4x1 => Mat2x1, 1;
+                                     Mat4x2 => Mat2, 2;
+                                     Mat4x3 => Mat2x3, 3;
+                                     Mat4 => Mat2x4, 4;
+                                     Mat4x5 => Mat2x5, 5;
+                                     Mat4x6 => Mat2x6, 6)
+rect_mat_mul_mat_impl!(Mat2x5, 2, 5; Mat5x1 => Mat2x1, 1;
+                                     Mat5x2 => Mat2, 2;
+                                     Mat5x3 => Mat2x3, 3;
+                                     Mat5x4 => Mat2x4, 4;
+                                     Mat5 => Mat2x5, 5;
+                                     Mat5x6 => Mat2x6, 6)
+rect_mat_mul_mat_impl!(Mat2x6, 2, 6; Mat6x1 => Mat2x1, 1;
+                                     Mat6x2 => Mat2, 2;
+                                     Mat6x3 => Mat2x3, 3;
+                                     Mat6x4 => Mat2x4, 4;
+                                     Mat6x5 => Mat2x5, 5;
+                                     Mat6 => Mat2x6, 6)
+rect_mat_mul_mat_impl!(Mat3x1, 3, 1; Mat1 => Mat3x1, 1;
+                                     Mat1x2 => Mat3x2, 2;
+                                     Mat1x3 => Mat3, 3;
+                                     Mat1x4 => Mat3x4, 4;
+                                     Mat1x5 => Mat3x5, 5;
+                                     Mat1x6 => Mat3x6, 6)
+rect_mat_mul_mat_impl!(Mat3x2, 3, 2; Mat2x1 => Mat3x1, 1;
+                                     Mat2 => Mat3x2, 2;
+                                     Mat2x3 => Mat3, 3;
+                                     Mat2x4 => Mat3x4, 4;
+                                     Mat2x5 => Mat3x5, 5;
+                                     Mat2x6 => Mat3x6, 6)
+rect_mat_mul_mat_impl!(Mat3, 3, 3; Mat3x1 => Mat3x1, 1;
+                                   Mat3x2 => Mat3x2, 2;
+                                   Mat3x4 => Mat3x4, 4;
+                                   Mat3x5 => Mat3x5, 5;
+                                   Mat3x6 => Mat3x6, 6)
+rect_mat_mul_mat_impl!(Mat3x4, 3, 4; Mat4x1 => Mat3x1, 1;
+                                     Mat4x2 => Mat3x2, 2;
+                                     Mat4x3 => Mat3, 3;
+                                     Mat4 => Mat3x4, 4;
+                                     Mat4x5 => Mat3x5, 5;
+                                     Mat4x6 => Mat3x6, 6)
+rect_mat_mul_mat_impl!(Mat3x5, 3, 5; Mat5x1 => Mat3x1, 1;
+                                     Mat5x2 => Mat3x2, 2;
+                                     Mat5x3 => Mat3, 3;
+                                     Mat5x4 => Mat3x4, 4;
+                                     Mat5 => Mat3x5, 5;
+                                     Mat5x6 => Mat3x6, 6)
+rect_mat_mul_mat_impl!(Mat3x6, 3, 6; Mat6x1 => Mat3x1, 1;
+                                     Mat6x2 => Mat3x2, 2;
+                                     Mat6x3 => Mat3, 3;
+                                     Mat6x4 => Mat3x4, 4;
+                                     Mat6x5 => Mat3x5, 5;
+                                     Mat6 => Mat3x6, 6)
+rect_mat_mul_mat_impl!(Mat4x1, 4, 1; Mat1 => Mat4x1, 1;
+                                     Mat1x2 => Mat4x2, 2;
+                                     Mat1x3 => Mat4x3, 3;
+                                     Mat1x4 => Mat4, 4;
+                                     Mat1x5 => Mat4x5, 5;
+                                     Mat1x6 => Mat4x6, 6)
+rect_mat_mul_mat_impl!(Mat4x2, 4, 2; Mat2x1 => Mat4x1, 1;
+                                     Mat2 => Mat4x2, 2;
+                                     Mat2x3 => Mat4x3, 3;
+                                     Mat2x4 => Mat4, 4;
+                                     Mat2x5 => Mat4x5, 5;
+                                     Mat2x6 => Mat4x6, 6)
+rect_mat_mul_mat_impl!(Mat4x3, 4, 3; Mat3x1 => Mat4x1, 1;
+                                     Mat3x2 => Mat4x2, 2;
+                                     Mat3 => Mat4x3, 3;
+                                     Mat3x4 => Mat4, 4;
+                                     Mat3x5 => Mat4x5, 5;
+                                     Mat3x6 => Mat4x6, 6)
+rect_mat_mul_mat_impl!(Mat4, 4, 4; Mat4x1 => Mat4x1, 1;
+                                   Mat4x2 => Mat4x2, 2;
+                                   Mat4x3 => Mat4x3, 3;
+                                   Mat4x5 => Mat4x5, 5;
+                                   Mat4x6 => Mat4x6, 6)
+rect_mat_mul_mat_impl!(Mat4x5, 4, 5; Mat5x1 => Mat4x1, 1;
+                                     Mat5x2 => Mat4x2, 2;
+                                     Mat5x3 => Mat4x3, 3;
+                                     Mat5x4 => Mat4, 4;
+                                     Mat5 => Mat4x5, 5;
+                                     Mat5x6 => Mat4x6, 6)
+rect_mat_mul_mat_impl!(Mat4x6, 4, 6; Mat6x1 => Mat4x1, 1;
+                                     Mat6x2 => Mat4x2, 2;
+                                     Mat6x3 => Mat4x3, 3;
+                                     Mat6x4 => Mat4, 4;
+                                     Mat6x5 => Mat4x5, 5;
+                                     Mat6 => Mat4x6, 6)
+rect_mat_mul_mat_impl!(Mat5x1, 5, 1; Mat1 => Mat5x1, 1;
+                                     Mat1x2 => Mat5x2, 2;
+                                     Mat1x3 => Mat5x3, 3;
+                                     Mat1x4 => Mat5x4, 4;
+                                     Mat1x5 => Mat5, 5;
+                                     Mat1x6 => Mat5x6, 6)
+rect_mat_mul_mat_impl!(Mat5x2, 5, 2; Mat2x1 => Mat5x1, 1;
+                                     Mat2 => Mat5x2, 2;
+                                     Mat2x3 => Mat5x3, 3;
+                                     Mat2x4 => Mat5x4, 4;
+                                     Mat2x5 => Mat5, 5;
+                                     Mat2x6 => Mat5x6, 6)
+rect_mat_mul_mat_impl!(Mat5x3, 5, 3; Mat3x1 => Mat5x1, 1;
+                                     Mat3x2 => Mat5x2, 2;
+                                     Mat3 => Mat5x3, 3;
+                                     Mat3x4 => Mat5x4, 4;
+                                     Mat3x5 => Mat5, 5;
+                                     Mat3x6 => Mat5x6, 6)
+rect_mat_mul_mat_impl!(Mat5x4, 5, 4; Mat4x1 => Mat5x1, 1;
+                                     Mat4x2 => Mat5x2, 2;
+                                     Mat4x3 => Mat5x3, 3;
+                                     Mat4 => Mat5x4, 4;
+                                     Mat4x5 => Mat5, 5;
+                                     Mat4x6 => Mat5x6, 6)
+rect_mat_mul_mat_impl!(Mat5, 5, 5; Mat5x1 => Mat5x1, 1;
+                                   Mat5x2 => Mat5x2, 2;
+                                   Mat5x3 => Mat5x3, 3;
+                                   Mat5x4 => Mat5x4, 4;
+                                   Mat5x6 => Mat5x6, 6)
+rect_mat_mul_mat_impl!(Mat5x6, 5, 6; Mat6x1 => Mat5x1, 1;
+                                     Mat6x2 => Mat5x2, 2;
+                                     Mat6x3 => Mat5x3, 3;
+                                     Mat6x4 => Mat5x4, 4;
+                                     Mat6x5 => Mat5, 5;
+                                     Mat6 => Mat5x6, 6)
+rect_mat_mul_mat_impl!(Mat6x1, 6, 1; Mat1 => Mat6x1, 1;
+                                     Mat1x2 => Mat6x2, 2;
+                                     Mat1x3 => Mat6x3, 3;
+                                     Mat1x4 => Mat6x4, 4;
+                                     Mat1x5 => Mat6x5, 5;
+                                     Mat1x6 => Mat6, 6)
+rect_mat_mul_mat_impl!(Mat6x2, 6, 2; Mat2x1 => Mat6x1, 1;
+                                     Mat2 => Mat6x2, 2;
+                                     Mat2x3 => Mat6x3, 3;
+                                     Mat2x4 => Mat6x4, 4;
+                                     Mat2x5 => Mat6x5, 5;
+                                     Mat2x6 => Mat6, 6)
+rect_mat_mul_mat_impl!(Mat6x3, 6, 3; Mat3x1 => Mat6x1, 1;
+                                     Mat3x2 => Mat6x2, 2;
+                                     Mat3 => Mat6x3, 3;
+                                     Mat3x4 => Mat6x4, 4;
+                                     Mat3x5 => Mat6x5, 5;
+                                     Mat3x6 => Mat6, 6)
+rect_mat_mul_mat_impl!(Mat6x4, 6, 4; Mat4x1 => Mat6x1, 1;
+                                     Mat4x2 => Mat6x2, 2;
+                                     Mat4x3 => Mat6x3, 3;
+                                     Mat4 => Mat6x4, 4;
+                                     Mat4x5 => Mat6x5, 5;
+                                     Mat4x6 => Mat6, 6)
+rect_mat_mul_mat_impl!(Mat6x5, 6, 5; Mat5x1 => Mat6x1, 1;
+                                     Mat5x2 => Mat6x2, 2;
+                                     Mat5x3 => Mat6x3, 3;
+                                     Mat5x4 => Mat6x4, 4;
+                                     Mat5 => Mat6x5, 5;
+                                     Mat5x6 => Mat6, 6)
+rect_mat_mul_mat_impl!(Mat6, 6, 6; Mat6x1 => Mat6x1, 1;
+                                   Mat6x2 => Mat6x2, 2;
+                                   Mat6x3 => Mat6x3, 3;
+                                   Mat6x4 => Mat6x4, 4;
+                                   Mat6x5 => Mat6x5, 5)
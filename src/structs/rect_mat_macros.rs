@@ -0,0 +1,246 @@
+#![macro_escape]
+
+macro_rules! rect_mat_impl(
+  ($t: ident, $nrows: expr, $ncols: expr, $comp0: ident $(,$compN: ident)*) => (
+    impl<N> $t<N> {
+        /// Creates a new matrix. The components are given in row-major order.
+        #[inline]
+        pub fn new($comp0: N $(, $compN: N )*) -> $t<N> {
+            $t {
+                $comp0: $comp0
+                $(, $compN: $compN )*
+            }
+        }
+    }
+
+    dmat_conversion_impl!($t, $nrows, $ncols)
+  )
+)
+
+macro_rules! rect_indexable_impl(
+  ($t: ident, $nrows: expr, $ncols: expr) => (
+    impl<N> Shape<(uint, uint), N> for $t<N> {
+        #[inline]
+        fn shape(&self) -> (uint, uint) {
+            ($nrows, $ncols)
+        }
+    }
+
+    impl<N: Clone> Indexable<(uint, uint), N> for $t<N> {
+        #[inline]
+        fn at(&self, (i, j): (uint, uint)) -> N {
+            self[(i, j)].clone()
+        }
+
+        #[inline]
+        fn set(&mut self, (i, j): (uint, uint), val: N) {
+            self[(i, j)] = val
+        }
+
+        #[inline]
+        fn swap(&mut self, (i1, j1): (uint, uint), (i2, j2): (uint, uint)) {
+            unsafe {
+              mem::transmute::<&mut $t<N>, &mut [N, ..$nrows * $ncols]>(self)
+                .swap(i1 + j1 * $nrows, i2 + j2 * $nrows)
+            }
+        }
+
+        #[inline]
+        unsafe fn unsafe_at(&self, (i, j): (uint, uint)) -> N {
+            (*mem::transmute::<&$t<N>, &[N, ..$nrows * $ncols]>(self)
+             .unsafe_get(i + j * $nrows)).clone()
+        }
+
+        #[inline]
+        unsafe fn unsafe_set(&mut self, (i, j): (uint, uint), val: N) {
+            (*mem::transmute::<&mut $t<N>, &mut [N, ..$nrows * $ncols]>(self)
+             .unsafe_mut(i + j * $nrows)) = val
+        }
+    }
+
+    impl<N> Index<(uint, uint), N> for $t<N> {
+        fn index(&self, &(i, j): &(uint, uint)) -> &N {
+            assert!(i < $nrows && j < $ncols, "Matrix index out of bounds.");
+
+            unsafe {
+                &mem::transmute::<&$t<N>, &[N, ..$nrows * $ncols]>(self)[i + j * $nrows]
+            }
+        }
+    }
+
+    impl<N> IndexMut<(uint, uint), N> for $t<N> {
+        fn index_mut(&mut self, &(i, j): &(uint, uint)) -> &mut N {
+            assert!(i < $nrows && j < $ncols, "Matrix index out of bounds.");
+
+            unsafe {
+                let data = mem::transmute::<&mut $t<N>, &mut [N, ..$nrows * $ncols]>(self);
+
+                &mut data[i + j * $nrows]
+            }
+        }
+    }
+  )
+)
+
+macro_rules! rect_iterable_impl(
+  ($t: ident, $nrows: expr, $ncols: expr) => (
+    impl<N> Iterable<N> for $t<N> {
+        #[inline]
+        fn iter<'l>(&'l self) -> Items<'l, N> {
+            unsafe {
+                mem::transmute::<&'l $t<N>, &'l [N, ..$nrows * $ncols]>(self).iter()
+            }
+        }
+    }
+
+    impl<N> IterableMut<N> for $t<N> {
+        #[inline]
+        fn iter_mut<'l>(&'l mut self) -> MutItems<'l, N> {
+            unsafe {
+                mem::transmute::<&'l mut $t<N>, &'l mut [N, ..$nrows * $ncols]>(self).iter_mut()
+            }
+        }
+    }
+  )
+)
+
+macro_rules! rect_row_impl(
+  ($t: ident, $tv: ident, $nrows: expr, $ncols: expr) => (
+    impl<N: Clone + Zero> Row<$tv<N>> for $t<N> {
+        #[inline]
+        fn nrows(&self) -> uint {
+            $nrows
+        }
+
+        #[inline]
+        fn set_row(&mut self, row: uint, v: $tv<N>) {
+            for j in range(0u, $ncols) {
+                self[(row, j)] = v[j].clone();
+            }
+        }
+
+        #[inline]
+        fn row(&self, row: uint) -> $tv<N> {
+            let mut res: $tv<N> = ::zero();
+
+            for j in range(0u, $ncols) {
+                res[j] = self[(row, j)].clone();
+            }
+
+            res
+        }
+    }
+  )
+)
+
+macro_rules! rect_col_impl(
+  ($t: ident, $tv: ident, $nrows: expr, $ncols: expr) => (
+    impl<N: Clone + Zero> Col<$tv<N>> for $t<N> {
+        #[inline]
+        fn ncols(&self) -> uint {
+            $ncols
+        }
+
+        #[inline]
+        fn set_col(&mut self, col: uint, v: $tv<N>) {
+            for i in range(0u, $nrows) {
+                self[(i, col)] = v[i].clone();
+            }
+        }
+
+        #[inline]
+        fn col(&self, col: uint) -> $tv<N> {
+            let mut res: $tv<N> = ::zero();
+
+            for i in range(0u, $nrows) {
+                res[i] = self[(i, col)].clone();
+            }
+
+            res
+        }
+    }
+  )
+)
+
+macro_rules! rect_transpose_impl(
+  ($t: ident, $tt: ident, $nrows: expr, $ncols: expr) => (
+    impl<N: Clone + Zero> $t<N> {
+        /// The transpose of this matrix.
+        #[inline]
+        pub fn transpose(&self) -> $tt<N> {
+            let mut res: $tt<N> = ::zero();
+
+            for i in range(0u, $nrows) {
+                for j in range(0u, $ncols) {
+                    res[(j, i)] = self[(i, j)].clone();
+                }
+            }
+
+            res
+        }
+    }
+  )
+)
+
+// Implements the products of the `$nrows x $ninner` matrix `$t` with each `$ninner x $ncols`
+// matrix `$right`, giving the `$nrows x $ncols` matrix `$res`.
+macro_rules! rect_mat_mul_mat_impl(
+  ($t: ident, $nrows: expr, $ninner: expr; $($right: ident => $res: ident, $ncols: expr);*) => (
+    $(
+    impl<N: Clone + BaseNum> Mul<$right<N>, $res<N>> for $t<N> {
+        #[inline]
+        fn mul(&self, right: &$right<N>) -> $res<N> {
+            let mut res: $res<N> = ::zero();
+
+            for i in range(0u, $nrows) {
+                for j in range(0u, $ncols) {
+                    let mut acc: N = ::zero();
+
+                    for k in range(0u, $ninner) {
+                        acc = acc + self[(i, k)] * right[(k, j)];
+                    }
+
+                    res[(i, j)] = acc;
+                }
+            }
+
+            res
+        }
+    }
+    )*
+  )
+)
+
+macro_rules! rect_mat_mul_vec_impl(
+  ($t: ident, $vrow: ident, $vcol: ident, $nrows: expr, $ncols: expr) => (
+    impl<N: Clone + BaseNum> Mul<$vrow<N>, $vcol<N>> for $t<N> {
+        #[inline]
+        fn mul(&self, right: &$vrow<N>) -> $vcol<N> {
+            let mut res: $vcol<N> = ::zero();
+
+            for i in range(0u, $nrows) {
+                for j in range(0u, $ncols) {
+                    res[i] = res[i] + self[(i, j)] * right[j];
+                }
+            }
+
+            res
+        }
+    }
+
+    impl<N: Clone + BaseNum> Mul<$t<N>, $vrow<N>> for $vcol<N> {
+        #[inline]
+        fn mul(&self, right: &$t<N>) -> $vrow<N> {
+            let mut res: $vrow<N> = ::zero();
+
+            for j in range(0u, $ncols) {
+                for i in range(0u, $nrows) {
+                    res[j] = res[j] + self[i] * right[(i, j)];
+                }
+            }
+
+            res
+        }
+    }
+  )
+)
@@ -4,12 +4,14 @@
 
 use std::rand::{Rand, Rng};
 use traits::geometry::{Rotate, Rotation, AbsoluteRotate, RotationMatrix, Transform, ToHomogeneous,
-                       Norm, Cross};
+                       Norm, Cross, Dot};
 use traits::structure::{Cast, Dim, Row, Col, BaseFloat, BaseNum, Zero, One};
-use traits::operations::{Absolute, Inv, Transpose, ApproxEq};
+use traits::operations::{Absolute, Inv, Transpose, ApproxEq, Det};
 use structs::vec::{Vec1, Vec2, Vec3, Vec4};
 use structs::pnt::{Pnt2, Pnt3, Pnt4};
 use structs::mat::{Mat2, Mat3, Mat4, Mat5};
+use structs::dmat::DMat;
+use structs::quat::UnitQuat;
 
 
 /// Two dimensional rotation matrix.
@@ -27,6 +29,79 @@ impl<N: Clone + BaseFloat + Neg<N>> Rot2<N> {
             submat: Mat2::new(coa.clone(), -sia, sia.clone(), coa)
         }
     }
+
+    /// Builds a rotation matrix from an orthogonal matrix.
+    ///
+    /// This is unsafe because the orthogonality of `mat` is not checked.
+    pub unsafe fn new_with_mat(mat: Mat2<N>) -> Rot2<N> {
+        Rot2 {
+            submat: mat
+        }
+    }
+}
+
+impl<N: Clone + BaseFloat + ApproxEq<N>> Rot2<N> {
+    /// Builds the rotation that maps the direction of `a` to the direction of `b`.
+    ///
+    /// Returns `None` if `a` or `b` is zero.
+    pub fn rotation_between(a: &Vec2<N>, b: &Vec2<N>) -> Option<Rot2<N>> {
+        if ::is_zero(&Norm::sqnorm(a)) || ::is_zero(&Norm::sqnorm(b)) {
+            None
+        }
+        else {
+            let perp: Vec1<N> = Cross::cross(a, b);
+
+            Some(Rot2::new(Vec1::new(perp.x.atan2(Dot::dot(a, b)))))
+        }
+    }
+
+    /// Builds a rotation matrix from the image of the canonical basis by the rotation.
+    ///
+    /// Returns `None` if `(x, y)` is not a direct orthonormal basis.
+    pub fn new_with_basis(x: Vec2<N>, y: Vec2<N>) -> Option<Rot2<N>> {
+        let det: Vec1<N> = Cross::cross(&x, &y);
+
+        if !ApproxEq::approx_eq(&Norm::sqnorm(&x), &::one()) ||
+           !ApproxEq::approx_eq(&Norm::sqnorm(&y), &::one()) ||
+           !ApproxEq::approx_eq(&Dot::dot(&x, &y), &::zero()) ||
+           !ApproxEq::approx_eq(&det.x, &::one()) {
+            None
+        }
+        else {
+            Some(Rot2 {
+                submat: Mat2::new(x.x, y.x, x.y, y.y)
+            })
+        }
+    }
+
+    /// Projects this matrix back to the closest rotation matrix.
+    ///
+    /// This removes the numerical drift accumulated after many compositions.
+    pub fn renormalize(&mut self) {
+        let angle = (self.submat.m21 - self.submat.m12).atan2(self.submat.m11 + self.submat.m22);
+
+        *self = Rot2::new(Vec1::new(angle))
+    }
+}
+
+impl<N: Clone + BaseFloat> Rot2<N> {
+    /// The geodesic distance between this rotation and `other`.
+    ///
+    /// This is the angle of the smallest rotation mapping `self` to `other`, in `[0, pi]`.
+    #[inline]
+    pub fn angle_to(&self, other: &Rot2<N>) -> N {
+        (Transpose::transpose_cpy(self) * *other).rotation().x.abs()
+    }
+
+    /// The chordal distance between this rotation and `other`.
+    ///
+    /// This is the Frobenius norm of the difference between both rotation matrices.
+    #[inline]
+    pub fn chordal_distance(&self, other: &Rot2<N>) -> N {
+        let diff = self.submat - other.submat;
+
+        (Norm::sqnorm(&diff.col(0)) + Norm::sqnorm(&diff.col(1))).sqrt()
+    }
 }
 
 impl<N: BaseFloat + Clone> Rotation<Vec1<N>> for Rot2<N> {
@@ -66,10 +141,13 @@ impl<N: BaseFloat + Clone> Rotation<Vec1<N>> for Rot2<N> {
     }
 }
 
+/// Samples a rotation uniformly distributed with respect to the Haar measure of `SO(2)`.
 impl<N: Clone + Rand + BaseFloat + Neg<N>> Rand for Rot2<N> {
     #[inline]
     fn rand<R: Rng>(rng: &mut R) -> Rot2<N> {
-        Rot2::new(rng.gen())
+        let u: N = rng.gen();
+
+        Rot2::new(Vec1::new(u * BaseFloat::two_pi() - BaseFloat::pi()))
     }
 }
 
@@ -163,6 +241,15 @@ impl<N: Clone + BaseFloat> Rot3<N> {
             )
         }
     }
+
+    /// Integrates the constant angular velocity `angvel` during the time step `dt`.
+    ///
+    /// The angular velocity is expressed in the global frame. This uses the exponential map, so
+    /// the result is exact and stays orthogonal: `Rot3::new(angvel * dt) * self`.
+    #[inline]
+    pub fn integrate(&self, angvel: &Vec3<N>, dt: N) -> Rot3<N> {
+        Rot3::new(*angvel * dt) * *self
+    }
 }
 
 impl<N: Clone + BaseFloat> Rot3<N> {
@@ -204,37 +291,133 @@ impl<N: Clone + BaseFloat> Rot3<N> {
     }
 }
 
-impl<N: Clone + BaseFloat + Cast<f64>>
-Rotation<Vec3<N>> for Rot3<N> {
-    #[inline]
-    fn rotation(&self) -> Vec3<N> {
-        let angle = ((self.submat.m11 + self.submat.m22 + self.submat.m33 - ::one()) / Cast::from(2.0)).acos();
+impl<N: Clone + BaseFloat + ApproxEq<N>> Rot3<N> {
+    /// Builds the smallest rotation that maps the direction of `a` to the direction of `b`.
+    ///
+    /// If `a` and `b` point to opposite directions, a rotation of `pi` around an arbitrary axis
+    /// orthogonal to `a` is returned. Returns `None` if `a` or `b` is zero.
+    pub fn rotation_between(a: &Vec3<N>, b: &Vec3<N>) -> Option<Rot3<N>> {
+        UnitQuat::rotation_between(a, b).map(|q| q.to_rot())
+    }
 
-        if angle != angle {
-            // FIXME: handle that correctly
-            ::zero()
-        }
-        else if ::is_zero(&angle) {
-            ::zero()
+    /// Builds a rotation matrix from the image of the canonical basis by the rotation.
+    ///
+    /// The vectors `x`, `y` and `z` become the columns of the rotation matrix. Returns `None` if
+    /// `(x, y, z)` is not a direct orthonormal basis.
+    pub fn new_with_basis(x: Vec3<N>, y: Vec3<N>, z: Vec3<N>) -> Option<Rot3<N>> {
+        if !ApproxEq::approx_eq(&Norm::sqnorm(&x), &::one()) ||
+           !ApproxEq::approx_eq(&Norm::sqnorm(&y), &::one()) ||
+           !ApproxEq::approx_eq(&Dot::dot(&x, &y), &::zero()) ||
+           !ApproxEq::approx_eq(&Cross::cross(&x, &y), &z) {
+            None
         }
         else {
-            let m32_m23 = self.submat.m32 - self.submat.m23;
-            let m13_m31 = self.submat.m13 - self.submat.m31;
-            let m21_m12 = self.submat.m21 - self.submat.m12;
+            Some(Rot3 {
+                submat: Mat3::new(
+                    x.x.clone(), y.x.clone(), z.x.clone(),
+                    x.y.clone(), y.y.clone(), z.y.clone(),
+                    x.z        , y.z        , z.z)
+            })
+        }
+    }
+
+    /// Projects this matrix back to a rotation matrix using the Gram-Schmidt process on its
+    /// columns.
+    ///
+    /// This removes the numerical drift accumulated after many compositions.
+    pub fn renormalize(&mut self) {
+        let xaxis = Norm::normalize_cpy(&self.submat.col(0));
+        let yaxis = self.submat.col(1);
+        let yaxis = Norm::normalize_cpy(&(yaxis - xaxis * Dot::dot(&xaxis, &yaxis)));
+        let zaxis = Cross::cross(&xaxis, &yaxis);
+
+        self.submat = Mat3::new(
+            xaxis.x.clone(), yaxis.x.clone(), zaxis.x.clone(),
+            xaxis.y.clone(), yaxis.y.clone(), zaxis.y.clone(),
+            xaxis.z        , yaxis.z        , zaxis.z)
+    }
+}
+
+impl<N: Clone + BaseFloat + Cast<f64>> Rot3<N> {
+    /// The geodesic distance between this rotation and `other`.
+    ///
+    /// This is the angle of the smallest rotation mapping `self` to `other`, in `[0, pi]`.
+    #[inline]
+    pub fn angle_to(&self, other: &Rot3<N>) -> N {
+        Norm::norm(&(Transpose::transpose_cpy(self) * *other).rotation())
+    }
 
-            let denom = (m32_m23 * m32_m23 + m13_m31 * m13_m31 + m21_m12 * m21_m12).sqrt();
+    /// The chordal distance between this rotation and `other`.
+    ///
+    /// This is the Frobenius norm of the difference between both rotation matrices. It is equal
+    /// to `2 * sqrt(2) * sin(angle / 2)` where `angle` is the geodesic distance.
+    #[inline]
+    pub fn chordal_distance(&self, other: &Rot3<N>) -> N {
+        let diff = self.submat - other.submat;
+        let sqn  = Norm::sqnorm(&diff.col(0)) + Norm::sqnorm(&diff.col(1)) +
+                   Norm::sqnorm(&diff.col(2));
+
+        sqn.sqrt()
+    }
+}
 
-            if ::is_zero(&denom) {
-                // XXX: handle that properly
-                // panic!("Internal error: singularity.")
+impl<N: Clone + BaseFloat + Cast<f64>>
+Rotation<Vec3<N>> for Rot3<N> {
+    #[inline]
+    fn rotation(&self) -> Vec3<N> {
+        let _1: N = ::one();
+        let _2: N = Cast::from(2.0);
+        let m     = &self.submat;
+
+        // The skew-symmetric part of the matrix is `sin(angle) * cross_matrix(axis)`.
+        let skew  = Vec3::new((m.m32 - m.m23) / _2, (m.m13 - m.m31) / _2, (m.m21 - m.m12) / _2);
+        // Clamping prevents rounding errors from producing a NaN.
+        let cos   = ::clamp((m.m11 + m.m22 + m.m33 - _1) / _2, -_1, _1);
+        let sin   = Norm::norm(&skew);
+        let angle = sin.atan2(cos);
+
+        if cos > ::zero() {
+            if ::is_zero(&sin) {
                 ::zero()
             }
             else {
-                let a_d = angle / denom;
-
-                Vec3::new(m32_m23 * a_d, m13_m31 * a_d, m21_m12 * a_d)
+                skew * (angle / sin)
             }
         }
+        else {
+            // Near `pi`, the skew-symmetric part vanishes and cannot give the axis accurately.
+            // Instead, we use the symmetric part which is `cos * I + (1 - cos) * axis * axis^T`.
+            let xx = m.m11 - cos;
+            let yy = m.m22 - cos;
+            let zz = m.m33 - cos;
+            let xy = (m.m12 + m.m21) / _2;
+            let xz = (m.m13 + m.m31) / _2;
+            let yz = (m.m23 + m.m32) / _2;
+
+            // Use the largest diagonal element to avoid dividing by something close to zero.
+            let mut axis =
+                if xx >= yy && xx >= zz {
+                    let x = xx.sqrt();
+                    Vec3::new(x, xy / x, xz / x)
+                }
+                else if yy >= zz {
+                    let y = yy.sqrt();
+                    Vec3::new(xy / y, y, yz / y)
+                }
+                else {
+                    let z = zz.sqrt();
+                    Vec3::new(xz / z, yz / z, z)
+                };
+
+            let _ = axis.normalize();
+
+            // The symmetric part does not give the orientation of the axis.
+            if Dot::dot(&axis, &skew) < ::zero() {
+                axis = -axis;
+            }
+
+            axis * angle
+        }
     }
 
     #[inline]
@@ -269,11 +452,14 @@ Rotation<Vec3<N>> for Rot3<N> {
     }
 }
 
+/// Samples a rotation uniformly distributed with respect to the Haar measure of `SO(3)`.
 impl<N: Clone + Rand + BaseFloat>
 Rand for Rot3<N> {
     #[inline]
     fn rand<R: Rng>(rng: &mut R) -> Rot3<N> {
-        Rot3::new(rng.gen())
+        let q: UnitQuat<N> = rng.gen();
+
+        q.to_rot()
     }
 }
 
@@ -377,6 +563,7 @@ Rotation<Vec4<N>> for Rot4<N> {
  */
 
 submat_impl!(Rot2, Mat2)
+rot_dmat_impl!(Rot2, Mat2)
 rotate_impl!(Rot2, Vec2, Pnt2)
 transform_impl!(Rot2, Vec2, Pnt2)
 dim_impl!(Rot2, 2)
@@ -397,6 +584,7 @@ transpose_impl!(Rot2)
 approx_eq_impl!(Rot2)
 
 submat_impl!(Rot3, Mat3)
+rot_dmat_impl!(Rot3, Mat3)
 rotate_impl!(Rot3, Vec3, Pnt3)
 transform_impl!(Rot3, Vec3, Pnt3)
 dim_impl!(Rot3, 3)
@@ -417,6 +605,7 @@ transpose_impl!(Rot3)
 approx_eq_impl!(Rot3)
 
 submat_impl!(Rot4, Mat4)
+rot_dmat_impl!(Rot4, Mat4)
 rotate_impl!(Rot4, Vec4, Pnt4)
 transform_impl!(Rot4, Vec4, Pnt4)
 dim_impl!(Rot4, 4)
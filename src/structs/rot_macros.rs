@@ -279,3 +279,39 @@ macro_rules! absolute_impl(
         }
     )
 )
+
+macro_rules! rot_dmat_impl(
+    ($t: ident, $submat: ident) => (
+        impl<N: Clone> $t<N> {
+            /// Converts this rotation matrix to a dynamically sized matrix.
+            #[inline]
+            pub fn to_dmat(&self) -> DMat<N> {
+                self.submat.to_dmat()
+            }
+        }
+
+        impl<N: BaseFloat + ApproxEq<N> + Clone> $t<N> {
+            /// Builds a rotation matrix from the components of a dynamically sized matrix.
+            ///
+            /// Returns `None` if the shape of `m` is not the same as the shape of `Self`, or if `m`
+            /// is not a rotation matrix, i.e., if it is not orthonormal or if its determinant is
+            /// not positive.
+            pub fn from_dmat(m: &DMat<N>) -> Option<$t<N>> {
+                let submat = match $submat::from_dmat(m) {
+                    Some(submat) => submat,
+                    None         => return None
+                };
+
+                let id: $submat<N> = ::one();
+
+                if !ApproxEq::approx_eq(&(submat * Transpose::transpose_cpy(&submat)), &id) ||
+                   Det::det(&submat) <= ::zero() {
+                    None
+                }
+                else {
+                    Some($t { submat: submat })
+                }
+            }
+        }
+    )
+)
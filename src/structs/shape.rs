@@ -0,0 +1,560 @@
+//! Simple geometric primitives: rays, lines, segments, planes, triangles and tetrahedra.
+
+use std::num;
+use std::num::Float;
+use structs::aabb::Aabb;
+use traits::operations::ApproxEq;
+use traits::structure::{BaseFloat, FloatVec, FloatPnt, Dim};
+use traits::geometry::{Dot, Norm, Cross, Rotate, Transform};
+
+/// A half-line starting at `orig` and directed toward `dir`.
+#[deriving(Eq, PartialEq, Encodable, Decodable, Clone, Show)]
+pub struct Ray<P, V> {
+    /// The starting point of the ray.
+    pub orig: P,
+    /// The direction of the ray. It does not have to be normalized.
+    pub dir:  V
+}
+
+/// An infinite line passing through `orig` and directed toward `dir`.
+#[deriving(Eq, PartialEq, Encodable, Decodable, Clone, Show)]
+pub struct Line<P, V> {
+    /// A point of the line.
+    pub orig: P,
+    /// The direction of the line. It does not have to be normalized.
+    pub dir:  V
+}
+
+/// A line segment between the points `a` and `b`.
+#[deriving(Eq, PartialEq, Encodable, Decodable, Clone, Show)]
+pub struct Segment<P> {
+    /// The first extremity of the segment.
+    pub a: P,
+    /// The second extremity of the segment.
+    pub b: P
+}
+
+/// A triangle with vertices `a`, `b` and `c`.
+#[deriving(Eq, PartialEq, Encodable, Decodable, Clone, Show)]
+pub struct Triangle<P> {
+    /// The first vertex of the triangle.
+    pub a: P,
+    /// The second vertex of the triangle.
+    pub b: P,
+    /// The third vertex of the triangle.
+    pub c: P
+}
+
+/// A tetrahedron with vertices `a`, `b`, `c` and `d`.
+#[deriving(Eq, PartialEq, Encodable, Decodable, Clone, Show)]
+pub struct Tetrahedron<P> {
+    /// The first vertex of the tetrahedron.
+    pub a: P,
+    /// The second vertex of the tetrahedron.
+    pub b: P,
+    /// The third vertex of the tetrahedron.
+    pub c: P,
+    /// The fourth vertex of the tetrahedron.
+    pub d: P
+}
+
+/// A plane (or a line in two dimensions) passing through `center` and orthogonal to `normal`.
+#[deriving(Eq, PartialEq, Encodable, Decodable, Clone, Show)]
+pub struct Plane<P, V> {
+    /// A point of the plane.
+    pub center: P,
+    /// The unit normal of the plane.
+    pub normal: V
+}
+
+impl<P, V> Ray<P, V> {
+    /// Creates a new ray from its starting point and its direction.
+    #[inline]
+    pub fn new(orig: P, dir: V) -> Ray<P, V> {
+        Ray {
+            orig: orig,
+            dir:  dir
+        }
+    }
+}
+
+impl<N: BaseFloat, V: FloatVec<N>, P: FloatPnt<N, V> + Clone> Ray<P, V> {
+    /// The point of this ray at the parameter `t`, i.e., `orig + dir * t`.
+    #[inline]
+    pub fn pnt_at(&self, t: N) -> P {
+        self.orig + self.dir * t
+    }
+
+    /// The point of this ray closest to `p`.
+    #[inline]
+    pub fn closest_pnt(&self, p: &P) -> P {
+        let t = Dot::dot(&(*p - self.orig), &self.dir) / Norm::sqnorm(&self.dir);
+
+        if t > ::zero() {
+            self.pnt_at(t)
+        }
+        else {
+            self.orig.clone()
+        }
+    }
+
+    /// The distance between this ray and the point `p`.
+    #[inline]
+    pub fn dist_to_pnt(&self, p: &P) -> N {
+        FloatPnt::dist(p, &self.closest_pnt(p))
+    }
+
+    /// Transforms this ray by `m`.
+    #[inline]
+    pub fn transform_by<M: Transform<P> + Rotate<V>>(&self, m: &M) -> Ray<P, V> {
+        Ray::new(m.transform(&self.orig), m.rotate(&self.dir))
+    }
+
+    /// Transforms this ray by the inverse of `m`.
+    #[inline]
+    pub fn inv_transform_by<M: Transform<P> + Rotate<V>>(&self, m: &M) -> Ray<P, V> {
+        Ray::new(m.inv_transform(&self.orig), m.inv_rotate(&self.dir))
+    }
+
+    /// Computes the parameter `t` of the intersection point between this ray and a plane.
+    ///
+    /// Returns `None` if the ray is parallel to the plane or does not reach it.
+    pub fn toi_with_plane(&self, plane: &Plane<P, V>) -> Option<N> {
+        let dpos = plane.center - self.orig;
+        let dn   = Dot::dot(&self.dir, &plane.normal);
+
+        if dn == ::zero() {
+            return None
+        }
+
+        let t = Dot::dot(&dpos, &plane.normal) / dn;
+
+        if t >= ::zero() {
+            Some(t)
+        }
+        else {
+            None
+        }
+    }
+
+    /// Computes the parameter `t` of the first intersection point between this ray and a solid
+    /// ball.
+    ///
+    /// Returns `Some(0)` if the ray starts inside of the ball, and `None` if it misses the ball.
+    pub fn toi_with_ball(&self, center: &P, radius: N) -> Option<N> {
+        let dpos = self.orig - *center;
+        let a    = Norm::sqnorm(&self.dir);
+        let b    = Dot::dot(&dpos, &self.dir);
+        let c    = Norm::sqnorm(&dpos) - radius * radius;
+
+        if c > ::zero() && b > ::zero() {
+            // Outside of the ball, and pointing away from it.
+            return None
+        }
+
+        let delta = b * b - a * c;
+
+        if delta < ::zero() {
+            return None
+        }
+
+        let t = (-b - delta.sqrt()) / a;
+
+        if t > ::zero() {
+            Some(t)
+        }
+        else {
+            Some(::zero())
+        }
+    }
+
+    /// Computes the parameter `t` of the first intersection point between this ray and a solid
+    /// axis-aligned box, using the slab method.
+    ///
+    /// Returns `Some(0)` if the ray starts inside of the box, and `None` if it misses the box.
+    pub fn toi_with_aabb(&self, aabb: &Aabb<P>) -> Option<N> {
+        let mut tmin: N = ::zero();
+        let mut tmax: N = Float::infinity();
+
+        for i in range(0u, Dim::dim(None::<V>)) {
+            if self.dir[i] == ::zero() {
+                if self.orig[i] < aabb.mins[i] || self.orig[i] > aabb.maxs[i] {
+                    return None
+                }
+            }
+            else {
+                let denom  = ::one::<N>() / self.dir[i];
+                let mut t1 = (aabb.mins[i] - self.orig[i]) * denom;
+                let mut t2 = (aabb.maxs[i] - self.orig[i]) * denom;
+
+                if t1 > t2 {
+                    let t = t1;
+                    t1 = t2;
+                    t2 = t;
+                }
+
+                tmin = tmin.max(t1);
+                tmax = tmax.min(t2);
+
+                if tmin > tmax {
+                    return None
+                }
+            }
+        }
+
+        Some(tmin)
+    }
+}
+
+impl<N: BaseFloat + ApproxEq<N>, V: FloatVec<N> + Cross<V>, P: FloatPnt<N, V>> Ray<P, V> {
+    /// Computes the intersection between this ray and the triangle `abc`, using the
+    /// Möller–Trumbore algorithm.
+    ///
+    /// Returns the parameter `t` of the intersection point together with its barycentric
+    /// coordinates `(u, v)`, i.e., the intersection point is `a * (1 - u - v) + b * u + c * v`.
+    /// Returns `None` if the ray misses the triangle or is parallel to its plane.
+    pub fn toi_with_triangle(&self, a: &P, b: &P, c: &P) -> Option<(N, N, N)> {
+        let ab  = *b - *a;
+        let ac  = *c - *a;
+        let p   = Cross::cross(&self.dir, &ac);
+        let det = Dot::dot(&ab, &p);
+
+        // The determinant scales with the lengths of `dir`, `ab` and `ac`, so the parallelism test
+        // must be relative to them.
+        let scale = Norm::norm(&self.dir) * Norm::norm(&ab) * Norm::norm(&ac);
+
+        if ::abs(&det) <= ApproxEq::approx_epsilon(None::<N>) * scale {
+            return None
+        }
+
+        let inv_det = ::one::<N>() / det;
+        let s       = self.orig - *a;
+        let u       = Dot::dot(&s, &p) * inv_det;
+
+        if u < ::zero() || u > ::one() {
+            return None
+        }
+
+        let q = Cross::cross(&s, &ab);
+        let v = Dot::dot(&self.dir, &q) * inv_det;
+
+        if v < ::zero() || u + v > ::one() {
+            return None
+        }
+
+        let t = Dot::dot(&ac, &q) * inv_det;
+
+        if t >= ::zero() {
+            Some((t, u, v))
+        }
+        else {
+            None
+        }
+    }
+}
+
+impl<P, V> Line<P, V> {
+    /// Creates a new line from one of its points and its direction.
+    #[inline]
+    pub fn new(orig: P, dir: V) -> Line<P, V> {
+        Line {
+            orig: orig,
+            dir:  dir
+        }
+    }
+}
+
+impl<N: BaseFloat, V: FloatVec<N>, P: FloatPnt<N, V>> Line<P, V> {
+    /// The orthogonal projection of `p` on this line.
+    #[inline]
+    pub fn project_pnt(&self, p: &P) -> P {
+        let t = Dot::dot(&(*p - self.orig), &self.dir) / Norm::sqnorm(&self.dir);
+
+        self.orig + self.dir * t
+    }
+
+    /// The distance between this line and the point `p`.
+    #[inline]
+    pub fn dist_to_pnt(&self, p: &P) -> N {
+        FloatPnt::dist(p, &self.project_pnt(p))
+    }
+
+    /// Transforms this line by `m`.
+    #[inline]
+    pub fn transform_by<M: Transform<P> + Rotate<V>>(&self, m: &M) -> Line<P, V> {
+        Line::new(m.transform(&self.orig), m.rotate(&self.dir))
+    }
+}
+
+impl<P> Segment<P> {
+    /// Creates a new segment from its extremities.
+    #[inline]
+    pub fn new(a: P, b: P) -> Segment<P> {
+        Segment {
+            a: a,
+            b: b
+        }
+    }
+}
+
+impl<N: BaseFloat, V: FloatVec<N>, P: FloatPnt<N, V> + Clone> Segment<P> {
+    /// The length of this segment.
+    #[inline]
+    pub fn length(&self) -> N {
+        FloatPnt::dist(&self.a, &self.b)
+    }
+
+    /// The point of this segment closest to `p`.
+    pub fn closest_pnt(&self, p: &P) -> P {
+        let ab    = self.b - self.a;
+        let sqlen = Norm::sqnorm(&ab);
+
+        if sqlen == ::zero() {
+            return self.a.clone()
+        }
+
+        let t = Dot::dot(&(*p - self.a), &ab) / sqlen;
+
+        if t <= ::zero() {
+            self.a.clone()
+        }
+        else if t >= ::one() {
+            self.b.clone()
+        }
+        else {
+            self.a + ab * t
+        }
+    }
+
+    /// The distance between this segment and the point `p`.
+    #[inline]
+    pub fn dist_to_pnt(&self, p: &P) -> N {
+        FloatPnt::dist(p, &self.closest_pnt(p))
+    }
+
+    /// Transforms this segment by `m`.
+    #[inline]
+    pub fn transform_by<M: Transform<P>>(&self, m: &M) -> Segment<P> {
+        Segment::new(m.transform(&self.a), m.transform(&self.b))
+    }
+}
+
+impl<N: BaseFloat, V: FloatVec<N>, P: FloatPnt<N, V>> Plane<P, V> {
+    /// Creates a new plane passing through `center` and orthogonal to `normal`.
+    ///
+    /// The normal is normalized by this constructor.
+    #[inline]
+    pub fn new(center: P, normal: V) -> Plane<P, V> {
+        Plane {
+            center: center,
+            normal: Norm::normalize_cpy(&normal)
+        }
+    }
+
+    /// The signed distance between this plane and the point `p`.
+    ///
+    /// It is positive if `p` is on the side of the plane pointed by its normal.
+    #[inline]
+    pub fn signed_dist_to_pnt(&self, p: &P) -> N {
+        Dot::dot(&(*p - self.center), &self.normal)
+    }
+
+    /// The distance between this plane and the point `p`.
+    #[inline]
+    pub fn dist_to_pnt(&self, p: &P) -> N {
+        self.signed_dist_to_pnt(p).abs()
+    }
+
+    /// The orthogonal projection of `p` on this plane.
+    #[inline]
+    pub fn project_pnt(&self, p: &P) -> P {
+        *p + self.normal * (-self.signed_dist_to_pnt(p))
+    }
+
+    /// Transforms this plane by `m`.
+    #[inline]
+    pub fn transform_by<M: Transform<P> + Rotate<V>>(&self, m: &M) -> Plane<P, V> {
+        Plane {
+            center: m.transform(&self.center),
+            normal: m.rotate(&self.normal)
+        }
+    }
+}
+
+impl<P> Triangle<P> {
+    /// Creates a new triangle from its vertices.
+    #[inline]
+    pub fn new(a: P, b: P, c: P) -> Triangle<P> {
+        Triangle {
+            a: a,
+            b: b,
+            c: c
+        }
+    }
+}
+
+impl<N: BaseFloat + ApproxEq<N>, V: FloatVec<N>, P: FloatPnt<N, V>> Triangle<P> {
+    /// The barycentric coordinates `(u, v, w)` of the point `p` with regard to this triangle, i.e.,
+    /// the coordinates such that `p = a * u + b * v + c * w` and `u + v + w = 1`.
+    ///
+    /// In three dimensions, `p` is first projected on the plane of the triangle. Returns `None` if
+    /// the triangle is degenerate.
+    pub fn barycentric_coordinates(&self, p: &P) -> Option<(N, N, N)> {
+        let ab  = self.b - self.a;
+        let ac  = self.c - self.a;
+        let ap  = *p - self.a;
+        let d00 = Dot::dot(&ab, &ab);
+        let d01 = Dot::dot(&ab, &ac);
+        let d11 = Dot::dot(&ac, &ac);
+        let d20 = Dot::dot(&ap, &ab);
+        let d21 = Dot::dot(&ap, &ac);
+
+        let denom = d00 * d11 - d01 * d01;
+
+        // `denom` is the squared area of the parallelogram spanned by `ab` and `ac`, so the
+        // epsilon is squared too.
+        let eps = ApproxEq::approx_epsilon(None::<N>);
+
+        if denom <= eps * eps * d00 * d11 {
+            return None
+        }
+
+        let v = (d11 * d20 - d01 * d21) / denom;
+        let w = (d00 * d21 - d01 * d20) / denom;
+
+        Some((::one::<N>() - v - w, v, w))
+    }
+
+    /// The center of the circle passing through the three vertices of this triangle.
+    ///
+    /// Returns `None` if the triangle is degenerate.
+    pub fn circumcenter(&self) -> Option<P> {
+        let ab  = self.b - self.a;
+        let ac  = self.c - self.a;
+        let d00 = Dot::dot(&ab, &ab);
+        let d01 = Dot::dot(&ab, &ac);
+        let d11 = Dot::dot(&ac, &ac);
+
+        let denom = d00 * d11 - d01 * d01;
+
+        // `denom` is the squared area of the parallelogram spanned by `ab` and `ac`, so the
+        // epsilon is squared too.
+        let eps = ApproxEq::approx_epsilon(None::<N>);
+
+        if denom <= eps * eps * d00 * d11 {
+            return None
+        }
+
+        let _2 = ::one::<N>() + ::one();
+        let s  = d11 * (d00 - d01) / (denom * _2);
+        let t  = d00 * (d11 - d01) / (denom * _2);
+
+        Some(self.a + ab * s + ac * t)
+    }
+
+    /// The center of mass of this triangle.
+    #[inline]
+    pub fn centroid(&self) -> P {
+        let _3 = ::one::<N>() + ::one() + ::one();
+
+        self.a + ((self.b - self.a) + (self.c - self.a)) / _3
+    }
+}
+
+impl<N: BaseFloat, V: FloatVec<N> + Cross<AV>, AV: Norm<N>, P: FloatPnt<N, V>> Triangle<P> {
+    /// The normal of this triangle, i.e., the cross product of its edges `ab` and `ac`.
+    ///
+    /// Its norm is twice the area of the triangle. In two dimensions, this is a one-dimensional
+    /// vector whose sign gives the orientation of the triangle.
+    #[inline]
+    pub fn scaled_normal(&self) -> AV {
+        Cross::cross(&(self.b - self.a), &(self.c - self.a))
+    }
+
+    /// The unit normal of this triangle.
+    ///
+    /// The result is not finite if the triangle is degenerate.
+    #[inline]
+    pub fn normal(&self) -> AV {
+        Norm::normalize_cpy(&self.scaled_normal())
+    }
+
+    /// The area of this triangle.
+    #[inline]
+    pub fn area(&self) -> N {
+        let _2 = ::one::<N>() + ::one();
+
+        Norm::norm(&self.scaled_normal()) / _2
+    }
+}
+
+impl<P> Tetrahedron<P> {
+    /// Creates a new tetrahedron from its vertices.
+    #[inline]
+    pub fn new(a: P, b: P, c: P, d: P) -> Tetrahedron<P> {
+        Tetrahedron {
+            a: a,
+            b: b,
+            c: c,
+            d: d
+        }
+    }
+}
+
+impl<N: BaseFloat + ApproxEq<N>, V: FloatVec<N> + Cross<V>, P: FloatPnt<N, V>> Tetrahedron<P> {
+    /// The signed volume of this tetrahedron.
+    ///
+    /// It is positive if `d` lies on the side of the triangle `abc` pointed by its normal
+    /// `(b - a) × (c - a)`, and negative if it lies on the other side.
+    #[inline]
+    pub fn signed_volume(&self) -> N {
+        let _6 = num::cast::<f64, N>(6.0).unwrap();
+
+        signed_volume6(&self.a, &self.b, &self.c, &self.d) / _6
+    }
+
+    /// The volume of this tetrahedron.
+    #[inline]
+    pub fn volume(&self) -> N {
+        self.signed_volume().abs()
+    }
+
+    /// The barycentric coordinates `(u, v, w, x)` of the point `p` with regard to this
+    /// tetrahedron, i.e., the coordinates such that `p = a * u + b * v + c * w + d * x` and
+    /// `u + v + w + x = 1`.
+    ///
+    /// Returns `None` if the tetrahedron is degenerate.
+    pub fn barycentric_coordinates(&self, p: &P) -> Option<(N, N, N, N)> {
+        let vol   = signed_volume6(&self.a, &self.b, &self.c, &self.d);
+        let scale = Norm::norm(&(self.b - self.a)) * Norm::norm(&(self.c - self.a)) *
+                    Norm::norm(&(self.d - self.a));
+
+        if ::abs(&vol) <= ApproxEq::approx_epsilon(None::<N>) * scale {
+            return None
+        }
+
+        let v = signed_volume6(&self.a, p, &self.c, &self.d) / vol;
+        let w = signed_volume6(&self.a, &self.b, p, &self.d) / vol;
+        let x = signed_volume6(&self.a, &self.b, &self.c, p) / vol;
+
+        Some((::one::<N>() - v - w - x, v, w, x))
+    }
+
+    /// The center of mass of this tetrahedron.
+    #[inline]
+    pub fn centroid(&self) -> P {
+        let _4 = ::one::<N>() + ::one() + ::one() + ::one();
+
+        self.a + ((self.b - self.a) + (self.c - self.a) + (self.d - self.a)) / _4
+    }
+}
+
+// Six times the signed volume of the tetrahedron `abcd`.
+#[inline]
+fn signed_volume6<N, V: Dot<N> + Cross<V>, P: Sub<P, V>>(a: &P, b: &P, c: &P, d: &P) -> N {
+    let ab = *b - *a;
+    let ac = *c - *a;
+    let ad = *d - *a;
+
+    Dot::dot(&Cross::cross(&ab, &ac), &ad)
+}
@@ -0,0 +1,146 @@
+//! Similarity transformations.
+
+#![allow(missing_docs)]
+
+use std::num;
+use std::rand::{Rand, Rng};
+use structs::mat::{Mat2, Mat3, Mat4};
+use traits::structure::{Cast, Dim, Col, BaseFloat, BaseNum, One};
+use traits::operations::{Inv, ApproxEq, Det, Transpose};
+use traits::geometry::{RotationMatrix, Rotation, Rotate, Transform, Transformation, Translate,
+                       Translation, ToHomogeneous};
+
+use structs::vec::{Vec1, Vec2, Vec3};
+use structs::pnt::{Pnt2, Pnt3};
+use structs::rot::{Rot2, Rot3};
+use structs::iso::{Iso2, Iso3};
+
+/// Two dimensional similarity.
+///
+/// This is the composition of a uniform scaling, a rotation, and a translation, applied in that
+/// order. Similarities conserve angles but not distances, hence do not allow shearing.
+#[deriving(Eq, PartialEq, Encodable, Decodable, Clone, Show)]
+pub struct Sim2<N> {
+    /// The rotation applicable by this similarity.
+    pub rotation:    Rot2<N>,
+    /// The translation applicable by this similarity.
+    pub translation: Vec2<N>,
+    /// The uniform scale factor applicable by this similarity.
+    pub scale:       N
+}
+
+/// Three dimensional similarity.
+///
+/// This is the composition of a uniform scaling, a rotation, and a translation, applied in that
+/// order. Similarities conserve angles but not distances, hence do not allow shearing.
+#[deriving(Eq, PartialEq, Encodable, Decodable, Clone, Show)]
+pub struct Sim3<N> {
+    /// The rotation applicable by this similarity.
+    pub rotation:    Rot3<N>,
+    /// The translation applicable by this similarity.
+    pub translation: Vec3<N>,
+    /// The uniform scale factor applicable by this similarity.
+    pub scale:       N
+}
+
+impl<N: Clone + BaseFloat + ApproxEq<N>> Sim2<N> {
+    /// Extracts a similarity from an homogeneous matrix.
+    ///
+    /// Returns `None` if `m` is not the homogeneous matrix of a similarity with a positive scale
+    /// factor.
+    pub fn new_with_homogeneous(m: &Mat3<N>) -> Option<Sim2<N>> {
+        if !::approx_eq(&m.m31, &::zero()) || !::approx_eq(&m.m32, &::zero()) ||
+           !::approx_eq(&m.m33, &::one()) {
+            return None
+        }
+
+        let linear = Mat2::new(m.m11.clone(), m.m12.clone(),
+                               m.m21.clone(), m.m22.clone());
+        let det    = Det::det(&linear);
+
+        if det <= ::zero() {
+            return None
+        }
+
+        let scale  = det.sqrt();
+        let rotmat = linear / scale;
+
+        if !::approx_eq(&(Transpose::transpose_cpy(&rotmat) * rotmat), &::one()) {
+            return None
+        }
+
+        let rotation = unsafe { Rot2::new_with_mat(rotmat) };
+
+        Some(Sim2::new_with_rotmat(Vec2::new(m.m13.clone(), m.m23.clone()), rotation, scale))
+    }
+}
+
+impl<N: Clone + BaseFloat + ApproxEq<N>> Sim3<N> {
+    /// Extracts a similarity from an homogeneous matrix.
+    ///
+    /// Returns `None` if `m` is not the homogeneous matrix of a similarity with a positive scale
+    /// factor.
+    pub fn new_with_homogeneous(m: &Mat4<N>) -> Option<Sim3<N>> {
+        if !::approx_eq(&m.m41, &::zero()) || !::approx_eq(&m.m42, &::zero()) ||
+           !::approx_eq(&m.m43, &::zero()) || !::approx_eq(&m.m44, &::one()) {
+            return None
+        }
+
+        let linear = Mat3::new(m.m11.clone(), m.m12.clone(), m.m13.clone(),
+                               m.m21.clone(), m.m22.clone(), m.m23.clone(),
+                               m.m31.clone(), m.m32.clone(), m.m33.clone());
+        let det    = Det::det(&linear);
+
+        if det <= ::zero() {
+            return None
+        }
+
+        let scale  = det.cbrt();
+        let rotmat = linear / scale;
+
+        if !::approx_eq(&(Transpose::transpose_cpy(&rotmat) * rotmat), &::one()) {
+            return None
+        }
+
+        let rotation    = unsafe { Rot3::new_with_mat(rotmat) };
+        let translation = Vec3::new(m.m14.clone(), m.m24.clone(), m.m34.clone());
+
+        Some(Sim3::new_with_rotmat(translation, rotation, scale))
+    }
+}
+
+sim_impl!(Sim2, Rot2, Vec2, Vec1, Iso2)
+rotation_matrix_impl!(Sim2, Rot2, Vec2, Vec1)
+sim_rotation_impl!(Sim2, Rot2, Vec1)
+dim_impl!(Sim2, 2)
+sim_one_impl!(Sim2)
+sim_rand_impl!(Sim2)
+sim_approx_eq_impl!(Sim2)
+sim_to_homogeneous_impl!(Sim2, Mat3)
+sim_inv_impl!(Sim2)
+sim_transform_impl!(Sim2, Pnt2)
+transformation_impl!(Sim2)
+rotate_impl!(Sim2, Vec2)
+sim_translation_impl!(Sim2, Vec2)
+translate_impl!(Sim2, Pnt2)
+sim_mul_sim_impl!(Sim2)
+sim_mul_pnt_impl!(Sim2, Pnt2)
+sim_mul_vec_impl!(Sim2, Vec2)
+
+sim_impl!(Sim3, Rot3, Vec3, Vec3, Iso3)
+rotation_matrix_impl!(Sim3, Rot3, Vec3, Vec3)
+sim_rotation_impl!(Sim3, Rot3, Vec3)
+dim_impl!(Sim3, 3)
+sim_one_impl!(Sim3)
+sim_rand_impl!(Sim3)
+sim_approx_eq_impl!(Sim3)
+sim_to_homogeneous_impl!(Sim3, Mat4)
+sim_inv_impl!(Sim3)
+sim_transform_impl!(Sim3, Pnt3)
+transformation_impl!(Sim3)
+rotate_impl!(Sim3, Vec3)
+sim_translation_impl!(Sim3, Vec3)
+translate_impl!(Sim3, Pnt3)
+sim_mul_sim_impl!(Sim3)
+sim_mul_pnt_impl!(Sim3, Pnt3)
+sim_mul_vec_impl!(Sim3, Vec3)
@@ -0,0 +1,287 @@
+#![macro_escape]
+
+macro_rules! sim_impl(
+    ($t: ident, $submat: ident, $subvec: ident, $subrotvec: ident, $tiso: ident) => (
+        impl<N: Clone + BaseFloat + BaseNum> $t<N> {
+            /// Creates a new similarity from a translation, a rotation vector, and a scale factor.
+            #[inline]
+            pub fn new(translation: $subvec<N>, rotation: $subrotvec<N>, scale: N) -> $t<N> {
+                $t {
+                    rotation:    $submat::new(rotation),
+                    translation: translation,
+                    scale:       scale
+                }
+            }
+
+            /// Creates a new similarity from a translation, a rotation matrix, and a scale factor.
+            #[inline]
+            pub fn new_with_rotmat(translation: $subvec<N>, rotation: $submat<N>, scale: N)
+                                   -> $t<N> {
+                $t {
+                    rotation:    rotation,
+                    translation: translation,
+                    scale:       scale
+                }
+            }
+
+            /// Creates a new similarity from an isometry.
+            ///
+            /// The scale factor of the result is one.
+            #[inline]
+            pub fn new_with_iso(iso: $tiso<N>) -> $t<N> {
+                $t::new_with_rotmat(iso.translation, iso.rotation, ::one())
+            }
+
+            /// The isometry obtained by dropping the scale factor of this similarity.
+            #[inline]
+            pub fn to_iso(&self) -> $tiso<N> {
+                $tiso::new_with_rotmat(self.translation.clone(), self.rotation.clone())
+            }
+        }
+    )
+)
+
+macro_rules! sim_one_impl(
+    ($t: ident) => (
+        impl<N: BaseFloat + Clone> One for $t<N> {
+            #[inline]
+            fn one() -> $t<N> {
+                $t::new_with_rotmat(::zero(), ::one(), ::one())
+            }
+        }
+    )
+)
+
+macro_rules! sim_mul_sim_impl(
+    ($t: ident) => (
+        impl<N: BaseFloat + Clone> Mul<$t<N>, $t<N>> for $t<N> {
+            #[inline]
+            fn mul(&self, right: &$t<N>) -> $t<N> {
+                $t::new_with_rotmat(
+                    self.translation + self.rotation * (right.translation * self.scale),
+                    self.rotation * right.rotation,
+                    self.scale * right.scale)
+            }
+        }
+    )
+)
+
+macro_rules! sim_mul_pnt_impl(
+    ($t: ident, $tv: ident) => (
+        impl<N: BaseNum + Clone> Mul<$tv<N>, $tv<N>> for $t<N> {
+            #[inline]
+            fn mul(&self, right: &$tv<N>) -> $tv<N> {
+                self.rotation * (*right * self.scale) + self.translation
+            }
+        }
+    )
+)
+
+macro_rules! sim_mul_vec_impl(
+    ($t: ident, $tv: ident) => (
+        impl<N: BaseNum + Clone> Mul<$tv<N>, $tv<N>> for $t<N> {
+            #[inline]
+            fn mul(&self, right: &$tv<N>) -> $tv<N> {
+                // vectors are scaled but not affected by the translation
+                self.rotation * (*right * self.scale)
+            }
+        }
+    )
+)
+
+macro_rules! sim_translation_impl(
+    ($t: ident, $tv: ident) => (
+        impl<N: BaseFloat + Clone> Translation<$tv<N>> for $t<N> {
+            #[inline]
+            fn translation(&self) -> $tv<N> {
+                self.translation.clone()
+            }
+
+            #[inline]
+            fn inv_translation(&self) -> $tv<N> {
+                -self.translation
+            }
+
+            #[inline]
+            fn append_translation(&mut self, t: &$tv<N>) {
+                self.translation = *t + self.translation
+            }
+
+            #[inline]
+            fn append_translation_cpy(sim: &$t<N>, t: &$tv<N>) -> $t<N> {
+                $t::new_with_rotmat(*t + sim.translation, sim.rotation.clone(), sim.scale.clone())
+            }
+
+            #[inline]
+            fn prepend_translation(&mut self, t: &$tv<N>) {
+                self.translation = self.translation + self.rotation * (*t * self.scale)
+            }
+
+            #[inline]
+            fn prepend_translation_cpy(sim: &$t<N>, t: &$tv<N>) -> $t<N> {
+                $t::new_with_rotmat(sim.translation + sim.rotation * (*t * sim.scale),
+                                    sim.rotation.clone(),
+                                    sim.scale.clone())
+            }
+
+            #[inline]
+            fn set_translation(&mut self, t: $tv<N>) {
+                self.translation = t
+            }
+        }
+    )
+)
+
+macro_rules! sim_rotation_impl(
+    ($t: ident, $trot: ident, $tav: ident) => (
+        impl<N: Cast<f64> + BaseFloat + Clone> Rotation<$tav<N>> for $t<N> {
+            #[inline]
+            fn rotation(&self) -> $tav<N> {
+                self.rotation.rotation()
+            }
+
+            #[inline]
+            fn inv_rotation(&self) -> $tav<N> {
+                self.rotation.inv_rotation()
+            }
+
+            #[inline]
+            fn append_rotation(&mut self, rot: &$tav<N>) {
+                let delta = $trot::new(rot.clone());
+
+                self.rotation    = delta * self.rotation;
+                self.translation = delta * self.translation;
+            }
+
+            #[inline]
+            fn append_rotation_cpy(t: &$t<N>, rot: &$tav<N>) -> $t<N> {
+                let delta = $trot::new(rot.clone());
+
+                $t::new_with_rotmat(delta * t.translation, delta * t.rotation, t.scale.clone())
+            }
+
+            #[inline]
+            fn prepend_rotation(&mut self, rot: &$tav<N>) {
+                let delta = $trot::new(rot.clone());
+
+                self.rotation = self.rotation * delta;
+            }
+
+            #[inline]
+            fn prepend_rotation_cpy(t: &$t<N>, rot: &$tav<N>) -> $t<N> {
+                let delta = $trot::new(rot.clone());
+
+                $t::new_with_rotmat(t.translation.clone(), t.rotation * delta, t.scale.clone())
+            }
+
+            #[inline]
+            fn set_rotation(&mut self, rot: $tav<N>) {
+                self.rotation.set_rotation(rot)
+            }
+        }
+    )
+)
+
+macro_rules! sim_transform_impl(
+    ($t: ident, $tp: ident) => (
+        impl<N: BaseNum + Clone> Transform<$tp<N>> for $t<N> {
+            #[inline]
+            fn transform(&self, p: &$tp<N>) -> $tp<N> {
+                self.rotation.transform(&(*p * self.scale)) + self.translation
+            }
+
+            #[inline]
+            fn inv_transform(&self, p: &$tp<N>) -> $tp<N> {
+                self.rotation.inv_transform(&(*p - self.translation)) / self.scale
+            }
+        }
+    )
+)
+
+macro_rules! sim_inv_impl(
+    ($t: ident) => (
+        impl<N: Clone + BaseNum> Inv for $t<N> {
+            #[inline]
+            fn inv(&mut self) -> bool {
+                if ::is_zero(&self.scale) {
+                    return false
+                }
+
+                self.scale = ::one::<N>() / self.scale;
+                self.rotation.inv();
+                self.translation = self.rotation * (-self.translation * self.scale);
+
+                true
+            }
+
+            #[inline]
+            fn inv_cpy(m: &$t<N>) -> Option<$t<N>> {
+                let mut res = m.clone();
+
+                if res.inv() {
+                    Some(res)
+                }
+                else {
+                    None
+                }
+            }
+        }
+    )
+)
+
+macro_rules! sim_to_homogeneous_impl(
+    ($t: ident, $th: ident) => (
+        impl<N: BaseNum + Clone> ToHomogeneous<$th<N>> for $t<N> {
+            fn to_homogeneous(&self) -> $th<N> {
+                let mut res = (*self.rotation.submat() * self.scale).to_homogeneous();
+
+                // copy the translation
+                let dim = Dim::dim(None::<$th<N>>);
+
+                res.set_col(dim - 1, self.translation.as_pnt().to_homogeneous().to_vec());
+
+                res
+            }
+        }
+    )
+)
+
+macro_rules! sim_approx_eq_impl(
+    ($t: ident) => (
+        impl<N: ApproxEq<N>> ApproxEq<N> for $t<N> {
+            #[inline]
+            fn approx_epsilon(_: Option<$t<N>>) -> N {
+                ApproxEq::approx_epsilon(None::<N>)
+            }
+
+            #[inline]
+            fn approx_eq(a: &$t<N>, b: &$t<N>) -> bool {
+                ApproxEq::approx_eq(&a.rotation, &b.rotation) &&
+                    ApproxEq::approx_eq(&a.translation, &b.translation) &&
+                    ApproxEq::approx_eq(&a.scale, &b.scale)
+            }
+
+            #[inline]
+            fn approx_eq_eps(a: &$t<N>, b: &$t<N>, epsilon: &N) -> bool {
+                ApproxEq::approx_eq_eps(&a.rotation, &b.rotation, epsilon) &&
+                    ApproxEq::approx_eq_eps(&a.translation, &b.translation, epsilon) &&
+                    ApproxEq::approx_eq_eps(&a.scale, &b.scale, epsilon)
+            }
+        }
+    )
+)
+
+macro_rules! sim_rand_impl(
+    ($t: ident) => (
+        impl<N: Rand + Clone + BaseFloat> Rand for $t<N> {
+            #[inline]
+            fn rand<R: Rng>(rng: &mut R) -> $t<N> {
+                // keep the scale factor away from zero
+                let _0_5: N = num::cast(0.5f64).unwrap();
+                let scale   = rng.gen::<N>() + _0_5;
+
+                $t::new(rng.gen(), rng.gen(), scale)
+            }
+        }
+    )
+)
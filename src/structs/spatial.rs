@@ -0,0 +1,142 @@
+//! Spatial vectors: twists and wrenches.
+
+#![allow(missing_docs)] // we allow missing to avoid having to document the dispatch trait.
+
+use structs::vec::{Vec3, Vec6};
+use structs::pnt::Pnt3;
+use structs::iso::Iso3;
+use traits::operations::ApproxEq;
+use traits::structure::{BaseNum, Zero};
+use traits::geometry::{Cross, Dot, Transform};
+
+/// A twist, i.e., the spatial velocity of a rigid body.
+///
+/// This is the pair of the linear velocity of the point of the rigid body located at the origin
+/// of the frame, and of the angular velocity of the rigid body. The 6D coordinates of a twist
+/// use the same layout as the twists of `Iso3::exp`: linear part first, angular part last.
+#[deriving(Eq, PartialEq, Encodable, Decodable, Clone, Hash, Rand, Show)]
+pub struct Twist<N> {
+    /// The linear velocity of the point located at the origin.
+    pub linear:  Vec3<N>,
+    /// The angular velocity.
+    pub angular: Vec3<N>
+}
+
+/// A wrench, i.e., a spatial force applied to a rigid body.
+///
+/// This is the pair of the resultant force and of the torque about the origin of the frame. The
+/// 6D coordinates of a wrench store the force first and the torque last.
+#[deriving(Eq, PartialEq, Encodable, Decodable, Clone, Hash, Rand, Show)]
+pub struct Wrench<N> {
+    /// The resultant force.
+    pub force:  Vec3<N>,
+    /// The torque about the origin.
+    pub torque: Vec3<N>
+}
+
+impl<N: BaseNum> Twist<N> {
+    /// The power developed by `wrench` applied to a rigid body moving with this twist.
+    ///
+    /// This is the dot product between a twist and a wrench, which is invariant by change of
+    /// frame.
+    #[inline]
+    pub fn power(&self, wrench: &Wrench<N>) -> N {
+        Dot::dot(&self.linear, &wrench.force) + Dot::dot(&self.angular, &wrench.torque)
+    }
+
+    /// The spatial cross product between this twist and a wrench.
+    ///
+    /// This is the rate of change of `wrench` if it is attached to a rigid body moving with this
+    /// twist. It is the dual of the cross product between twists.
+    #[inline]
+    pub fn cross_wrench(&self, wrench: &Wrench<N>) -> Wrench<N> {
+        Wrench::new(Cross::cross(&self.angular, &wrench.force),
+                    Cross::cross(&self.angular, &wrench.torque) +
+                    Cross::cross(&self.linear, &wrench.force))
+    }
+
+    /// The linear velocity of the point `p` of a rigid body moving with this twist.
+    #[inline]
+    pub fn velocity_at(&self, p: &Pnt3<N>) -> Vec3<N> {
+        self.linear + Cross::cross(&self.angular, p.as_vec())
+    }
+}
+
+impl<N: BaseNum> Wrench<N> {
+    /// The power developed by this wrench applied to a rigid body moving with `twist`.
+    #[inline]
+    pub fn power(&self, twist: &Twist<N>) -> N {
+        twist.power(self)
+    }
+
+    /// The wrench equivalent to the force `force` applied at the point `p`.
+    #[inline]
+    pub fn new_with_force_at(force: Vec3<N>, p: &Pnt3<N>) -> Wrench<N> {
+        let torque = Cross::cross(p.as_vec(), &force);
+
+        Wrench::new(force, torque)
+    }
+}
+
+/// The spatial cross product between twists.
+///
+/// This is the rate of change of `b` if it is attached to a rigid body moving with the twist
+/// `a`.
+impl<N: BaseNum> Cross<Twist<N>> for Twist<N> {
+    #[inline]
+    fn cross(a: &Twist<N>, b: &Twist<N>) -> Twist<N> {
+        Twist::new(Cross::cross(&a.angular, &b.linear) + Cross::cross(&a.linear, &b.angular),
+                   Cross::cross(&a.angular, &b.angular))
+    }
+}
+
+/// Transforms a twist expressed in the local frame of the isometry to the global frame.
+///
+/// This is the multiplication by the adjoint of the isometry.
+impl<N: BaseNum + Clone> Transform<Twist<N>> for Iso3<N> {
+    #[inline]
+    fn transform(&self, v: &Twist<N>) -> Twist<N> {
+        let angular = self.rotation * v.angular;
+        let linear  = self.rotation * v.linear + Cross::cross(&self.translation, &angular);
+
+        Twist::new(linear, angular)
+    }
+
+    #[inline]
+    fn inv_transform(&self, v: &Twist<N>) -> Twist<N> {
+        let linear = v.linear - Cross::cross(&self.translation, &v.angular);
+
+        Twist::new(self.rotation.inv_transform(&linear), self.rotation.inv_transform(&v.angular))
+    }
+}
+
+/// Transforms a wrench expressed in the local frame of the isometry to the global frame.
+///
+/// This is the multiplication by the inverse transpose of the adjoint of the isometry, so that
+/// the power between twists and wrenches is preserved.
+impl<N: BaseNum + Clone> Transform<Wrench<N>> for Iso3<N> {
+    #[inline]
+    fn transform(&self, f: &Wrench<N>) -> Wrench<N> {
+        let force  = self.rotation * f.force;
+        let torque = self.rotation * f.torque + Cross::cross(&self.translation, &force);
+
+        Wrench::new(force, torque)
+    }
+
+    #[inline]
+    fn inv_transform(&self, f: &Wrench<N>) -> Wrench<N> {
+        let torque = f.torque - Cross::cross(&self.translation, &f.force);
+
+        Wrench::new(self.rotation.inv_transform(&f.force), self.rotation.inv_transform(&torque))
+    }
+}
+
+spatial_impl!(Twist, linear, angular)
+spatial_zero_impl!(Twist, linear, angular)
+spatial_arith_impl!(Twist, linear, angular)
+spatial_approx_eq_impl!(Twist, linear, angular)
+
+spatial_impl!(Wrench, force, torque)
+spatial_zero_impl!(Wrench, force, torque)
+spatial_arith_impl!(Wrench, force, torque)
+spatial_approx_eq_impl!(Wrench, force, torque)
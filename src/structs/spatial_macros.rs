@@ -0,0 +1,107 @@
+#![macro_escape]
+
+macro_rules! spatial_impl(
+    ($t: ident, $lin: ident, $ang: ident) => (
+        impl<N> $t<N> {
+            /// Creates a new spatial vector from its linear and angular parts.
+            #[inline]
+            pub fn new($lin: Vec3<N>, $ang: Vec3<N>) -> $t<N> {
+                $t {
+                    $lin: $lin,
+                    $ang: $ang
+                }
+            }
+        }
+
+        impl<N: Clone> $t<N> {
+            /// Creates a new spatial vector from its 6D coordinates.
+            ///
+            /// The linear part is made of the first three components of `v`, the angular part
+            /// is made of the last three.
+            #[inline]
+            pub fn new_with_vec6(v: &Vec6<N>) -> $t<N> {
+                $t::new(Vec3::new(v.x.clone(), v.y.clone(), v.z.clone()),
+                        Vec3::new(v.w.clone(), v.a.clone(), v.b.clone()))
+            }
+
+            /// The 6D coordinates of this spatial vector.
+            ///
+            /// The linear part is stored first, followed by the angular part.
+            #[inline]
+            pub fn to_vec6(&self) -> Vec6<N> {
+                Vec6::new(self.$lin.x.clone(), self.$lin.y.clone(), self.$lin.z.clone(),
+                          self.$ang.x.clone(), self.$ang.y.clone(), self.$ang.z.clone())
+            }
+        }
+    )
+)
+
+macro_rules! spatial_zero_impl(
+    ($t: ident, $lin: ident, $ang: ident) => (
+        impl<N: Zero> Zero for $t<N> {
+            #[inline]
+            fn zero() -> $t<N> {
+                $t::new(::zero(), ::zero())
+            }
+
+            #[inline]
+            fn is_zero(&self) -> bool {
+                self.$lin.is_zero() && self.$ang.is_zero()
+            }
+        }
+    )
+)
+
+macro_rules! spatial_arith_impl(
+    ($t: ident, $lin: ident, $ang: ident) => (
+        impl<N: Add<N, N>> Add<$t<N>, $t<N>> for $t<N> {
+            #[inline]
+            fn add(&self, right: &$t<N>) -> $t<N> {
+                $t::new(self.$lin + right.$lin, self.$ang + right.$ang)
+            }
+        }
+
+        impl<N: Sub<N, N>> Sub<$t<N>, $t<N>> for $t<N> {
+            #[inline]
+            fn sub(&self, right: &$t<N>) -> $t<N> {
+                $t::new(self.$lin - right.$lin, self.$ang - right.$ang)
+            }
+        }
+
+        impl<N: Neg<N>> Neg<$t<N>> for $t<N> {
+            #[inline]
+            fn neg(&self) -> $t<N> {
+                $t::new(-self.$lin, -self.$ang)
+            }
+        }
+
+        impl<N: Mul<N, N>> Mul<N, $t<N>> for $t<N> {
+            #[inline]
+            fn mul(&self, right: &N) -> $t<N> {
+                $t::new(self.$lin * *right, self.$ang * *right)
+            }
+        }
+    )
+)
+
+macro_rules! spatial_approx_eq_impl(
+    ($t: ident, $lin: ident, $ang: ident) => (
+        impl<N: ApproxEq<N>> ApproxEq<N> for $t<N> {
+            #[inline]
+            fn approx_epsilon(_: Option<$t<N>>) -> N {
+                ApproxEq::approx_epsilon(None::<N>)
+            }
+
+            #[inline]
+            fn approx_eq(a: &$t<N>, b: &$t<N>) -> bool {
+                ApproxEq::approx_eq(&a.$lin, &b.$lin) && ApproxEq::approx_eq(&a.$ang, &b.$ang)
+            }
+
+            #[inline]
+            fn approx_eq_eps(a: &$t<N>, b: &$t<N>, eps: &N) -> bool {
+                ApproxEq::approx_eq_eps(&a.$lin, &b.$lin, eps) &&
+                    ApproxEq::approx_eq_eps(&a.$ang, &b.$ang, eps)
+            }
+        }
+    )
+)
@@ -1,8 +1,11 @@
-use structs::vec::{Vec2, Vec3};
+use structs::vec::{Vec2, Vec3, Vec6};
 use structs::pnt::{Pnt2, Pnt3};
-use structs::mat::{Mat1, Mat2, Mat3};
-use traits::operations::{Inv, Det, ApproxEq};
-use traits::structure::{Row, Col, BaseNum};
+use structs::mat::{Mat1, Mat2, Mat3, Mat4};
+use structs::rot::Rot3;
+use structs::quat::UnitQuat;
+use traits::operations::{Inv, Det, ApproxEq, Transpose};
+use traits::structure::{Row, Col, BaseNum, BaseFloat};
+use traits::geometry::{Norm, FromHomogeneous, ToHomogeneous, Vee, Transform, AbsoluteRotate};
 
 // some specializations:
 impl<N: BaseNum + ApproxEq<N> + Clone> Inv for Mat1<N> {
@@ -131,6 +134,43 @@ impl<N: BaseNum> Det<N> for Mat3<N> {
     }
 }
 
+impl<N: BaseNum> Det<N> for Mat4<N> {
+    #[inline]
+    fn det(m: &Mat4<N>) -> N {
+        // Laplace expansion along the first two rows.
+        let s0 = m.m11 * m.m22 - m.m12 * m.m21;
+        let s1 = m.m11 * m.m23 - m.m13 * m.m21;
+        let s2 = m.m11 * m.m24 - m.m14 * m.m21;
+        let s3 = m.m12 * m.m23 - m.m13 * m.m22;
+        let s4 = m.m12 * m.m24 - m.m14 * m.m22;
+        let s5 = m.m13 * m.m24 - m.m14 * m.m23;
+
+        let c0 = m.m31 * m.m42 - m.m32 * m.m41;
+        let c1 = m.m31 * m.m43 - m.m33 * m.m41;
+        let c2 = m.m31 * m.m44 - m.m34 * m.m41;
+        let c3 = m.m32 * m.m43 - m.m33 * m.m42;
+        let c4 = m.m32 * m.m44 - m.m34 * m.m42;
+        let c5 = m.m33 * m.m44 - m.m34 * m.m43;
+
+        s0 * c5 - s1 * c4 + s2 * c3 + s3 * c2 - s4 * c1 + s5 * c0
+    }
+}
+
+impl<N: Clone> Vee<Vec3<N>> for Mat3<N> {
+    #[inline]
+    fn vee(m: &Mat3<N>) -> Vec3<N> {
+        Vec3::new(m.m32.clone(), m.m13.clone(), m.m21.clone())
+    }
+}
+
+impl<N: Clone> Vee<Vec6<N>> for Mat4<N> {
+    #[inline]
+    fn vee(m: &Mat4<N>) -> Vec6<N> {
+        Vec6::new(m.m14.clone(), m.m24.clone(), m.m34.clone(),
+                  m.m32.clone(), m.m13.clone(), m.m21.clone())
+    }
+}
+
 impl<N: Clone> Row<Vec3<N>> for Mat3<N> {
     #[inline]
     fn nrows(&self) -> uint {
@@ -326,3 +366,128 @@ impl<N: Mul<N, N> + Add<N, N>> Mul<Pnt2<N>, Pnt2<N>> for Mat2<N> {
         )
     }
 }
+
+impl<N: BaseFloat + Clone> Mat4<N> {
+    /// Creates the homogeneous matrix of a non-uniform scaling, followed by a rotation, followed
+    /// by a translation.
+    pub fn new_with_trs(translation: Vec3<N>, rotation: Rot3<N>, scale: Vec3<N>) -> Mat4<N> {
+        let r = rotation.submat();
+
+        Mat4::new(
+            r.m11 * scale.x, r.m12 * scale.y, r.m13 * scale.z, translation.x,
+            r.m21 * scale.x, r.m22 * scale.y, r.m23 * scale.z, translation.y,
+            r.m31 * scale.x, r.m32 * scale.y, r.m33 * scale.z, translation.z,
+            ::zero(),        ::zero(),        ::zero(),        ::one()
+        )
+    }
+
+    /// Creates the homogeneous matrix of a non-uniform scaling, followed by a rotation
+    /// represented by a unit quaternion, followed by a translation.
+    #[inline]
+    pub fn new_with_trs_quat(translation: Vec3<N>, rotation: UnitQuat<N>, scale: Vec3<N>)
+                             -> Mat4<N> {
+        Mat4::new_with_trs(translation, rotation.to_rot(), scale)
+    }
+}
+
+/// The matrix is interpreted as a 3D homogeneous transformation.
+impl<N: BaseFloat + ApproxEq<N> + Clone> Transform<Pnt3<N>> for Mat4<N> {
+    #[inline]
+    fn transform(&self, p: &Pnt3<N>) -> Pnt3<N> {
+        FromHomogeneous::from(&(*self * p.to_homogeneous()))
+    }
+
+    #[inline]
+    fn inv_transform(&self, p: &Pnt3<N>) -> Pnt3<N> {
+        let inv: Mat4<N> = Inv::inv_cpy(self).expect(
+            "Cannot invert a singular homogeneous transformation.");
+
+        FromHomogeneous::from(&(inv * p.to_homogeneous()))
+    }
+}
+
+/// The matrix is interpreted as a 3D affine homogeneous transformation: only its upper-left 3×3
+/// part, divided by its bottom-right component, is used.
+///
+/// Projective transformations are not supported: this fails if the last row of the matrix is not
+/// of the form `[ 0, 0, 0, w ]` with a non-zero `w`.
+impl<N: BaseFloat + ApproxEq<N>> AbsoluteRotate<Vec3<N>> for Mat4<N> {
+    #[inline]
+    fn absolute_rotate(&self, v: &Vec3<N>) -> Vec3<N> {
+        assert!(::approx_eq(&self.m41, &::zero()) && ::approx_eq(&self.m42, &::zero()) &&
+                ::approx_eq(&self.m43, &::zero()) && self.m44 != ::zero(),
+                "Cannot absolute-rotate with a projective transformation.");
+
+        let w = ::abs(&self.m44);
+
+        Vec3::new(
+            (::abs(&self.m11) * v.x + ::abs(&self.m12) * v.y + ::abs(&self.m13) * v.z) / w,
+            (::abs(&self.m21) * v.x + ::abs(&self.m22) * v.y + ::abs(&self.m23) * v.z) / w,
+            (::abs(&self.m31) * v.x + ::abs(&self.m32) * v.y + ::abs(&self.m33) * v.z) / w
+        )
+    }
+}
+
+impl<N: BaseFloat + ApproxEq<N> + Clone> Mat4<N> {
+    /// Decomposes this homogeneous matrix into a translation, a rotation, and a non-uniform
+    /// scaling.
+    ///
+    /// This is the inverse of `Mat4::new_with_trs`. If the matrix contains a reflection, the
+    /// scaling factor along the `x` axis is negative. Returns `None` if this matrix is not affine,
+    /// if its upper-left 3×3 part is singular, or if it contains some shearing.
+    pub fn decompose(&self) -> Option<(Vec3<N>, Rot3<N>, Vec3<N>)> {
+        if !::approx_eq(&self.m41, &::zero()) || !::approx_eq(&self.m42, &::zero()) ||
+           !::approx_eq(&self.m43, &::zero()) || !::approx_eq(&self.m44, &::one()) {
+            return None
+        }
+
+        let linear: Mat3<N> = FromHomogeneous::from(self);
+        let c1 = linear.col(0);
+        let c2 = linear.col(1);
+        let c3 = linear.col(2);
+
+        let mut sx = Norm::norm(&c1);
+        let sy     = Norm::norm(&c2);
+        let sz     = Norm::norm(&c3);
+        let det    = Det::det(&linear);
+
+        // The singularity test is relative to the column norms, so that matrices with a tiny
+        // scale can still be decomposed.
+        if ::abs(&det) <= ApproxEq::approx_epsilon(None::<N>) * sx * sy * sz {
+            return None
+        }
+
+        if det < ::zero() {
+            // Move the reflection to the scaling factor.
+            sx = -sx;
+        }
+
+        let c1 = c1 / sx;
+        let c2 = c2 / sy;
+        let c3 = c3 / sz;
+
+        let rotmat = Mat3::new(c1.x.clone(), c2.x.clone(), c3.x.clone(),
+                               c1.y.clone(), c2.y.clone(), c3.y.clone(),
+                               c1.z.clone(), c2.z.clone(), c3.z.clone());
+
+        if !::approx_eq(&(Transpose::transpose_cpy(&rotmat) * rotmat), &::one()) {
+            return None
+        }
+
+        let translation = Vec3::new(self.m14.clone(), self.m24.clone(), self.m34.clone());
+        let rotation    = unsafe { Rot3::new_with_mat(rotmat) };
+
+        Some((translation, rotation, Vec3::new(sx, sy, sz)))
+    }
+
+    /// The matrix that transforms the normals of the surfaces transformed by this homogeneous
+    /// matrix, i.e., the inverse-transpose of its upper-left 3×3 part.
+    ///
+    /// Returns `None` if the upper-left 3×3 part is singular.
+    #[inline]
+    pub fn normal_matrix(&self) -> Option<Mat3<N>> {
+        let linear: Mat3<N> = FromHomogeneous::from(self);
+
+        Inv::inv_cpy(&linear).map(|m| Transpose::transpose_cpy(&m))
+    }
+}
@@ -0,0 +1,152 @@
+//! Generation of quasi-uniform direction sets on the unit sphere of any dimension.
+
+use std::f64;
+use std::num::Float;
+use std::rc::Rc;
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+// The generated direction sets, indexed by dimension and count. Each set is stored as a flat
+// array of `count * dim` components.
+local_data_key!(DIRECTIONS_CACHE: RefCell<HashMap<(uint, uint), Rc<Vec<f64>>>>)
+
+// The first primes, used as the bases of the Halton sequence.
+static PRIMES: [uint, ..6] = [ 2, 3, 5, 7, 11, 13 ];
+
+/// Returns `count` quasi-uniformly distributed unit vectors of dimension `dim`, stored as a flat
+/// array of `count * dim` components.
+///
+/// The result is cached so that requesting the same set again does not regenerate it.
+pub fn directions(dim: uint, count: uint) -> Rc<Vec<f64>> {
+    if DIRECTIONS_CACHE.get().is_none() {
+        let _ = DIRECTIONS_CACHE.replace(Some(RefCell::new(HashMap::new())));
+    }
+
+    let cache  = DIRECTIONS_CACHE.get().unwrap();
+    let cached = cache.borrow().get(&(dim, count)).map(|dirs| dirs.clone());
+
+    match cached {
+        Some(dirs) => dirs,
+        None       => {
+            let dirs = Rc::new(generate(dim, count));
+
+            let _ = cache.borrow_mut().insert((dim, count), dirs.clone());
+
+            dirs
+        }
+    }
+}
+
+fn generate(dim: uint, count: uint) -> Vec<f64> {
+    let mut res = Vec::with_capacity(dim * count);
+
+    match dim {
+        0 => { },
+        1 => {
+            // The unit sphere is made of only two points.
+            for i in range(0u, count) {
+                res.push(if i % 2 == 0 { 1.0 } else { -1.0 })
+            }
+        },
+        2 => {
+            // Evenly spaced angles.
+            for i in range(0u, count) {
+                let (s, c) = (2.0 * f64::consts::PI * (i as f64) / (count as f64)).sin_cos();
+
+                res.push(c);
+                res.push(s);
+            }
+        },
+        3 => {
+            // Fibonacci lattice.
+            let golden_angle = f64::consts::PI * (3.0 - 5.0f64.sqrt());
+
+            for i in range(0u, count) {
+                let z      = 1.0 - (2.0 * (i as f64) + 1.0) / (count as f64);
+                let r      = (1.0 - z * z).sqrt();
+                let (s, c) = (golden_angle * (i as f64)).sin_cos();
+
+                res.push(r * c);
+                res.push(r * s);
+                res.push(z);
+            }
+        },
+        _ => {
+            // Points of the Halton sequence, mapped to normally distributed vectors which are then
+            // projected on the sphere.
+            assert!(dim <= PRIMES.len(), "Sphere sampling is not supported for this dimension.");
+
+            for i in range(0u, count) {
+                let start      = res.len();
+                let mut sqnorm = 0.0;
+
+                for d in range(0u, dim) {
+                    let x = inv_normal_cdf(radical_inverse(i + 1, PRIMES[d]));
+
+                    sqnorm = sqnorm + x * x;
+                    res.push(x);
+                }
+
+                let norm = sqnorm.sqrt();
+
+                for x in res.slice_from_mut(start).iter_mut() {
+                    *x = *x / norm;
+                }
+            }
+        }
+    }
+
+    res
+}
+
+// The `i`-th element of the van der Corput sequence in base `base`.
+fn radical_inverse(mut i: uint, base: uint) -> f64 {
+    let inv_base = 1.0 / (base as f64);
+    let mut f    = inv_base;
+    let mut res  = 0.0;
+
+    while i > 0 {
+        res = res + f * ((i % base) as f64);
+        i   = i / base;
+        f   = f * inv_base;
+    }
+
+    res
+}
+
+// Inverse of the cumulative distribution function of the standard normal distribution.
+//
+// This uses the rational approximation of Peter J. Acklam, with a relative error smaller than
+// 1.15e-9. `p` must be in the open interval `(0, 1)`.
+fn inv_normal_cdf(p: f64) -> f64 {
+    static A: [f64, ..6] = [ -3.969683028665376e+01,  2.209460984245205e+02,
+                             -2.759285104469687e+02,  1.383577518672690e+02,
+                             -3.066479806614716e+01,  2.506628277459239e+00 ];
+    static B: [f64, ..5] = [ -5.447609879822406e+01,  1.615858368580409e+02,
+                             -1.556989798598866e+02,  6.680131188771972e+01,
+                             -1.328068155288572e+01 ];
+    static C: [f64, ..6] = [ -7.784894002430293e-03, -3.223964580411365e-01,
+                             -2.400758277161838e+00, -2.549732539343734e+00,
+                              4.374664141464968e+00,  2.938163982698783e+00 ];
+    static D: [f64, ..4] = [  7.784695709041462e-03,  3.224671290700398e-01,
+                              2.445134137142996e+00,  3.754408661907416e+00 ];
+
+    let p_low = 0.02425;
+
+    if p < p_low {
+        let q = (-2.0 * p.ln()).sqrt();
+
+        (((((C[0] * q + C[1]) * q + C[2]) * q + C[3]) * q + C[4]) * q + C[5]) /
+        ((((D[0] * q + D[1]) * q + D[2]) * q + D[3]) * q + 1.0)
+    }
+    else if p > 1.0 - p_low {
+        -inv_normal_cdf(1.0 - p)
+    }
+    else {
+        let q = p - 0.5;
+        let r = q * q;
+
+        (((((A[0] * r + A[1]) * r + A[2]) * r + A[3]) * r + A[4]) * r + A[5]) * q /
+        (((((B[0] * r + B[1]) * r + B[2]) * r + B[3]) * r + B[4]) * r + 1.0)
+    }
+}
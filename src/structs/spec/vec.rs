@@ -1,7 +1,11 @@
-use traits::structure::{Cast, Row, Basis, BaseFloat, Zero, One};
+use std::num::Float;
+use std::vec::MoveItems;
+use std::iter::FromIterator;
+use traits::structure::{Cast, Row, Basis, BaseFloat, Zero, One, Dim};
 use traits::geometry::{Norm, Cross, CrossMatrix, UniformSphereSample};
-use structs::vec::{Vec1, Vec2, Vec3, Vec4};
-use structs::mat::Mat3;
+use structs::vec::{Vec1, Vec2, Vec3, Vec4, Vec5, Vec6};
+use structs::mat::{Mat3, Mat4};
+use structs::spec::sphere_sampling;
 
 impl<N: Mul<N, N> + Sub<N, N>> Cross<Vec1<N>> for Vec2<N> {
     #[inline]
@@ -40,6 +44,23 @@ impl<N: Neg<N> + Zero + Clone> CrossMatrix<Mat3<N>> for Vec3<N> {
     }
 }
 
+/// The cross matrix of a 6D vector `(v, w)` is the 4×4 matrix
+/// `[[ cross_matrix(w), v ], [ 0, 0 ]]`.
+///
+/// This is the `hat` operator mapping the twist coordinates of a rigid body motion to the matrix
+/// representation of the corresponding element of the Lie algebra of SE(3).
+impl<N: Neg<N> + Zero + Clone> CrossMatrix<Mat4<N>> for Vec6<N> {
+    #[inline]
+    fn cross_matrix(v: &Vec6<N>) -> Mat4<N> {
+        Mat4::new(
+            ::zero(),    -v.b,        v.a.clone(), v.x.clone(),
+            v.b.clone(), ::zero(),    -v.w,        v.y.clone(),
+            -v.a,        v.w.clone(), ::zero(),    v.z.clone(),
+            ::zero(),    ::zero(),    ::zero(),    ::zero()
+        )
+    }
+}
+
 // FIXME: implement this for all other vectors
 impl<N: Clone> Row<Vec1<N>> for Vec2<N> {
     #[inline]
@@ -69,13 +90,10 @@ impl<N: Clone> Row<Vec1<N>> for Vec2<N> {
 
 impl<N: One> Basis for Vec1<N> {
     #[inline(always)]
-    fn canonical_basis(f: |Vec1<N>| -> bool) {
-        f(Vec1::new(::one()));
+    fn orthonormal_subspace_basis_iter(_: &Vec1<N>) -> MoveItems<Vec1<N>> {
+        Vec::new().into_iter()
     }
 
-    #[inline(always)]
-    fn orthonormal_subspace_basis(_: &Vec1<N>, _: |Vec1<N>| -> bool) { }
-
     #[inline]
     fn canonical_basis_element(i: uint) -> Option<Vec1<N>> {
         if i == 0 {
@@ -88,15 +106,9 @@ impl<N: One> Basis for Vec1<N> {
 }
 
 impl<N: Clone + One + Zero + Neg<N>> Basis for Vec2<N> {
-    #[inline(always)]
-    fn canonical_basis(f: |Vec2<N>| -> bool) {
-        if !f(Vec2::new(::one(), ::zero())) { return };
-        f(Vec2::new(::zero(), ::one()));
-    }
-
     #[inline]
-    fn orthonormal_subspace_basis(n: &Vec2<N>, f: |Vec2<N>| -> bool) {
-        f(Vec2::new(-n.y, n.x.clone()));
+    fn orthonormal_subspace_basis_iter(n: &Vec2<N>) -> MoveItems<Vec2<N>> {
+        vec![Vec2::new(-n.y, n.x.clone())].into_iter()
     }
 
     #[inline]
@@ -115,14 +127,7 @@ impl<N: Clone + One + Zero + Neg<N>> Basis for Vec2<N> {
 
 impl<N: BaseFloat> Basis for Vec3<N> {
     #[inline(always)]
-    fn canonical_basis(f: |Vec3<N>| -> bool) {
-        if !f(Vec3::new(::one(), ::zero(), ::zero())) { return };
-        if !f(Vec3::new(::zero(), ::one(), ::zero())) { return };
-        f(Vec3::new(::zero(), ::zero(), ::one()));
-    }
-
-    #[inline(always)]
-    fn orthonormal_subspace_basis(n: &Vec3<N>, f: |Vec3<N>| -> bool) {
+    fn orthonormal_subspace_basis_iter(n: &Vec3<N>) -> MoveItems<Vec3<N>> {
         let a = 
             if n.x.clone().abs() > n.y.clone().abs() {
                 Norm::normalize_cpy(&Vec3::new(n.z.clone(), ::zero(), -n.x))
@@ -131,8 +136,7 @@ impl<N: BaseFloat> Basis for Vec3<N> {
                 Norm::normalize_cpy(&Vec3::new(::zero(), -n.z, n.y.clone()))
             };
 
-        if !f(Cross::cross(&a, n)) { return };
-        f(a);
+        vec![Cross::cross(&a, n), a].into_iter()
     }
 
     #[inline]
@@ -223,37 +227,144 @@ static SAMPLES_3_F64: [Vec3<f64>, ..42] = [
     Vec3 { x: 0.162456 , y: 0.499995 , z: 0.850654 }
 ];
 
-impl<N: One + Clone> UniformSphereSample for Vec1<N> {
+impl<N: One + Cast<f64> + Clone> UniformSphereSample for Vec1<N> {
     #[inline(always)]
-    fn sample(f: |Vec1<N>| -> ()) {
-        f(::one())
-     }
+    fn sphere_samples() -> MoveItems<Vec1<N>> {
+        vec![::one()].into_iter()
+    }
+
+    #[inline]
+    fn sphere_samples_with_count(count: uint) -> MoveItems<Vec1<N>> {
+        sphere_samples_with_count::<Vec1<N>, Vec1<f64>>(count)
+    }
 }
 
 impl<N: Cast<f64> + Clone> UniformSphereSample for Vec2<N> {
     #[inline(always)]
-    fn sample(f: |Vec2<N>| -> ()) {
-         for sample in SAMPLES_2_F64.iter() {
-             f(Cast::from(*sample))
-         }
-     }
+    fn sphere_samples() -> MoveItems<Vec2<N>> {
+        let samples: Vec<Vec2<N>> = SAMPLES_2_F64.iter().map(|s| Cast::from(*s)).collect();
+
+        samples.into_iter()
+    }
+
+    #[inline]
+    fn sphere_samples_with_count(count: uint) -> MoveItems<Vec2<N>> {
+        sphere_samples_with_count::<Vec2<N>, Vec2<f64>>(count)
+    }
 }
 
 impl<N: Cast<f64> + Clone> UniformSphereSample for Vec3<N> {
     #[inline(always)]
-    fn sample(f: |Vec3<N>| -> ()) {
-        for sample in SAMPLES_3_F64.iter() {
-            f(Cast::from(*sample))
-        }
+    fn sphere_samples() -> MoveItems<Vec3<N>> {
+        let samples: Vec<Vec3<N>> = SAMPLES_3_F64.iter().map(|s| Cast::from(*s)).collect();
+
+        samples.into_iter()
+    }
+
+    #[inline]
+    fn sphere_samples_with_count(count: uint) -> MoveItems<Vec3<N>> {
+        sphere_samples_with_count::<Vec3<N>, Vec3<f64>>(count)
     }
 }
 
+// The even permutations of four elements.
+static EVEN_PERMUTATIONS_4: [[uint, ..4], ..12] = [
+    [0, 1, 2, 3], [0, 2, 3, 1], [0, 3, 1, 2], [1, 0, 3, 2], [1, 2, 0, 3], [1, 3, 2, 0],
+    [2, 0, 1, 3], [2, 1, 3, 0], [2, 3, 0, 1], [3, 0, 2, 1], [3, 1, 0, 2], [3, 2, 1, 0]
+];
+
+/// The samples are the 120 vertices of the regular 600-cell inscribed in the unit 3-sphere.
 impl<N: Cast<f64> + Clone> UniformSphereSample for Vec4<N> {
     #[inline(always)]
-    fn sample(_: |Vec4<N>| -> ()) {
-        panic!("UniformSphereSample::<Vec4<N>>::sample : Not yet implemented.")
-        // for sample in SAMPLES_3_F32.iter() {
-        //     f(Cast::from(*sample))
-        // }
+    fn sphere_samples() -> MoveItems<Vec4<N>> {
+        let phi                       = (1.0f64 + 5.0f64.sqrt()) / 2.0;
+        let signs                     = [1.0f64, -1.0];
+        let mut samples: Vec<Vec4<N>> = Vec::with_capacity(120);
+
+        // The 8 permutations of (±1, 0, 0, 0).
+        for i in range(0u, 4) {
+            for s in signs.iter() {
+                let mut v = [0.0f64, ..4];
+
+                v[i] = *s;
+
+                samples.push(Cast::from(Vec4::new(v[0], v[1], v[2], v[3])))
+            }
+        }
+
+        // The 16 points (±1/2, ±1/2, ±1/2, ±1/2).
+        for sx in signs.iter() {
+            for sy in signs.iter() {
+                for sz in signs.iter() {
+                    for sw in signs.iter() {
+                        samples.push(Cast::from(Vec4::new(*sx, *sy, *sz, *sw) * 0.5))
+                    }
+                }
+            }
+        }
+
+        // The 96 even permutations of (±phi, ±1, ±1/phi, 0) / 2.
+        for p in EVEN_PERMUTATIONS_4.iter() {
+            for s0 in signs.iter() {
+                for s1 in signs.iter() {
+                    for s2 in signs.iter() {
+                        let vals  = [*s0 * phi, *s1, *s2 / phi, 0.0];
+                        let mut v = [0.0f64, ..4];
+
+                        for k in range(0u, 4) {
+                            v[p[k]] = vals[k] * 0.5;
+                        }
+
+                        samples.push(Cast::from(Vec4::new(v[0], v[1], v[2], v[3])))
+                    }
+                }
+            }
+        }
+
+        samples.into_iter()
+    }
+
+    #[inline]
+    fn sphere_samples_with_count(count: uint) -> MoveItems<Vec4<N>> {
+        sphere_samples_with_count::<Vec4<N>, Vec4<f64>>(count)
+    }
+}
+
+impl<N: Cast<f64> + Clone> UniformSphereSample for Vec5<N> {
+    #[inline(always)]
+    fn sphere_samples() -> MoveItems<Vec5<N>> {
+        sphere_samples_with_count::<Vec5<N>, Vec5<f64>>(256)
+    }
+
+    #[inline]
+    fn sphere_samples_with_count(count: uint) -> MoveItems<Vec5<N>> {
+        sphere_samples_with_count::<Vec5<N>, Vec5<f64>>(count)
+    }
+}
+
+impl<N: Cast<f64> + Clone> UniformSphereSample for Vec6<N> {
+    #[inline(always)]
+    fn sphere_samples() -> MoveItems<Vec6<N>> {
+        sphere_samples_with_count::<Vec6<N>, Vec6<f64>>(512)
+    }
+
+    #[inline]
+    fn sphere_samples_with_count(count: uint) -> MoveItems<Vec6<N>> {
+        sphere_samples_with_count::<Vec6<N>, Vec6<f64>>(count)
     }
 }
+
+// Casts the cached quasi-uniform directions to the vector type `V`.
+fn sphere_samples_with_count<V, W>(count: uint) -> MoveItems<V>
+    where V: Cast<W> + Dim,
+          W: FromIterator<f64> {
+    let dim        = Dim::dim(None::<V>);
+    let directions = sphere_sampling::directions(dim, count);
+    let samples: Vec<V> = directions.as_slice().chunks(dim).map(|d| {
+        let v: W = FromIterator::from_iter(d.iter().map(|e| *e));
+
+        Cast::from(v)
+    }).collect();
+
+    samples.into_iter()
+}
@@ -1,5 +1,6 @@
 use std::mem;
 use std::slice::{Items, MutItems};
+use std::vec::MoveItems;
 use std::iter::{Iterator, FromIterator};
 use traits::operations::ApproxEq;
 use traits::structure::{Iterable, IterableMut, Indexable, Basis, Dim, Shape, BaseFloat, BaseNum,
@@ -87,10 +88,9 @@ impl<N> Dim for vec::Vec0<N> {
 
 impl<N> Basis for vec::Vec0<N> {
     #[inline(always)]
-    fn canonical_basis(_: |vec::Vec0<N>| -> bool) { }
-
-    #[inline(always)]
-    fn orthonormal_subspace_basis(_: &vec::Vec0<N>, _: |vec::Vec0<N>| -> bool) { }
+    fn orthonormal_subspace_basis_iter(_: &vec::Vec0<N>) -> MoveItems<vec::Vec0<N>> {
+        Vec::new().into_iter()
+    }
 
     #[inline(always)]
     fn canonical_basis_element(_: uint) -> Option<vec::Vec0<N>> {
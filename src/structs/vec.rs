@@ -3,14 +3,20 @@
 #![allow(missing_docs)] // we allow missing to avoid having to document the dispatch traits.
 
 use std::mem;
+use std::num;
+use std::num::Float;
+use std::rand::Rng;
 use std::slice::{Items, MutItems};
+use std::vec::MoveItems;
 use std::iter::{Iterator, FromIterator};
 use traits::operations::{ApproxEq, POrd, POrdering, Axpy, ScalarAdd, ScalarSub, ScalarMul,
-                         ScalarDiv, Absolute};
+                         ScalarDiv, Absolute, CompFloat, CompReduce};
 use traits::geometry::{Transform, Rotate, FromHomogeneous, ToHomogeneous, Dot, Norm,
                        Translation, Translate};
-use traits::structure::{Basis, Cast, Dim, Indexable, Iterable, IterableMut, VecAsPnt, Shape,
-                        NumVec, FloatVec, BaseFloat, BaseNum, Zero, One, Bounded};
+use traits::structure::{Basis, Cast, CompMap, Dim, Indexable, Iterable, IterableMut, VecAsPnt,
+                        Shape, NumVec, FloatVec, BaseFloat, BaseNum, Zero, One, Bounded};
+use structs::random;
+use structs::dvec::{DVec, DVec1, DVec2, DVec3, DVec4, DVec5, DVec6};
 use structs::pnt::{Pnt1, Pnt2, Pnt3, Pnt4, Pnt5, Pnt6};
 
 
@@ -30,6 +36,25 @@ impl<N> Vec0<N> {
     pub fn new_repeat(_: N) -> Vec0<N> {
         Vec0
     }
+
+    /// Converts this vector to an empty heap-allocated, dynamically sized vector.
+    #[inline]
+    pub fn to_dvec(&self) -> DVec<N> {
+        DVec { at: Vec::new() }
+    }
+
+    /// Builds a vector from a dynamically sized vector.
+    ///
+    /// Returns `None` if `v` is not empty.
+    #[inline]
+    pub fn from_dvec<V: Indexable<uint, N>>(v: &V) -> Option<Vec0<N>> {
+        if v.shape() != 0 {
+            None
+        }
+        else {
+            Some(Vec0)
+        }
+    }
 }
 
 /// Vector of dimension 1.
@@ -44,6 +69,7 @@ ord_impl!(Vec1, x)
 vec_axis_impl!(Vec1, x)
 vec_cast_impl!(Vec1, x)
 as_array_impl!(Vec1, 1)
+dvec_conversion_impl!(Vec1, DVec1, to_dvec1, 1)
 index_impl!(Vec1)
 indexable_impl!(Vec1, 1)
 at_fast_impl!(Vec1, 1)
@@ -80,6 +106,9 @@ transform_impl!(Vec1, Pnt1)
 vec_as_pnt_impl!(Vec1, Pnt1, x)
 num_float_vec_impl!(Vec1)
 absolute_vec_impl!(Vec1, x)
+comp_map_impl!(Vec1, x)
+comp_float_impl!(Vec1, x)
+comp_reduce_impl!(Vec1, x)
 
 /// Vector of dimension 2.
 #[deriving(Eq, PartialEq, Encodable, Decodable, Clone, Hash, Rand, Zero, Show)]
@@ -95,6 +124,7 @@ ord_impl!(Vec2, x, y)
 vec_axis_impl!(Vec2, x, y)
 vec_cast_impl!(Vec2, x, y)
 as_array_impl!(Vec2, 2)
+dvec_conversion_impl!(Vec2, DVec2, to_dvec2, 2)
 index_impl!(Vec2)
 indexable_impl!(Vec2, 2)
 at_fast_impl!(Vec2, 2)
@@ -131,6 +161,12 @@ transform_impl!(Vec2, Pnt2)
 vec_as_pnt_impl!(Vec2, Pnt2, x, y)
 num_float_vec_impl!(Vec2)
 absolute_vec_impl!(Vec2, x, y)
+comp_map_impl!(Vec2, x, y)
+comp_float_impl!(Vec2, x, y)
+comp_reduce_impl!(Vec2, x, y)
+swizzle_impl!(Vec2, Vec2,
+              xy => x, y; yx => y, x)
+random_unit_impl!(Vec2, 2, x, y)
 
 /// Vector of dimension 3.
 #[deriving(Eq, PartialEq, Encodable, Decodable, Clone, Hash, Rand, Zero, Show)]
@@ -148,6 +184,7 @@ ord_impl!(Vec3, x, y, z)
 vec_axis_impl!(Vec3, x, y, z)
 vec_cast_impl!(Vec3, x, y, z)
 as_array_impl!(Vec3, 3)
+dvec_conversion_impl!(Vec3, DVec3, to_dvec3, 3)
 index_impl!(Vec3)
 indexable_impl!(Vec3, 3)
 at_fast_impl!(Vec3, 3)
@@ -184,6 +221,16 @@ transform_impl!(Vec3, Pnt3)
 vec_as_pnt_impl!(Vec3, Pnt3, x, y, z)
 num_float_vec_impl!(Vec3)
 absolute_vec_impl!(Vec3, x, y, z)
+comp_map_impl!(Vec3, x, y, z)
+comp_float_impl!(Vec3, x, y, z)
+comp_reduce_impl!(Vec3, x, y, z)
+extend_impl!(Vec3, Vec2, from_xy, x, y; z)
+swizzle_impl!(Vec3, Vec2,
+              xy => x, y; xz => x, z; yx => y, x; yz => y, z; zx => z, x; zy => z, y)
+swizzle_impl!(Vec3, Vec3,
+              xyz => x, y, z; xzy => x, z, y; yxz => y, x, z; yzx => y, z, x; zxy => z, x, y;
+              zyx => z, y, x)
+random_unit_impl!(Vec3, 3, x, y, z)
 
 
 /// Vector of dimension 4.
@@ -204,6 +251,7 @@ ord_impl!(Vec4, x, y, z, w)
 vec_axis_impl!(Vec4, x, y, z, w)
 vec_cast_impl!(Vec4, x, y, z, w)
 as_array_impl!(Vec4, 4)
+dvec_conversion_impl!(Vec4, DVec4, to_dvec4, 4)
 index_impl!(Vec4)
 indexable_impl!(Vec4, 4)
 at_fast_impl!(Vec4, 4)
@@ -240,6 +288,27 @@ transform_impl!(Vec4, Pnt4)
 vec_as_pnt_impl!(Vec4, Pnt4, x, y, z, w)
 num_float_vec_impl!(Vec4)
 absolute_vec_impl!(Vec4, x, y, z, w)
+comp_map_impl!(Vec4, x, y, z, w)
+comp_float_impl!(Vec4, x, y, z, w)
+comp_reduce_impl!(Vec4, x, y, z, w)
+extend_impl!(Vec4, Vec3, from_xyz, x, y, z; w)
+swizzle_impl!(Vec4, Vec2,
+              xy => x, y; xz => x, z; xw => x, w; yx => y, x; yz => y, z; yw => y, w; zx => z, x;
+              zy => z, y; zw => z, w; wx => w, x; wy => w, y; wz => w, z)
+swizzle_impl!(Vec4, Vec3,
+              xyz => x, y, z; xyw => x, y, w; xzy => x, z, y; xzw => x, z, w; xwy => x, w, y;
+              xwz => x, w, z; yxz => y, x, z; yxw => y, x, w; yzx => y, z, x; yzw => y, z, w;
+              ywx => y, w, x; ywz => y, w, z; zxy => z, x, y; zxw => z, x, w; zyx => z, y, x;
+              zyw => z, y, w; zwx => z, w, x; zwy => z, w, y; wxy => w, x, y; wxz => w, x, z;
+              wyx => w, y, x; wyz => w, y, z; wzx => w, z, x; wzy => w, z, y)
+swizzle_impl!(Vec4, Vec4,
+              xyzw => x, y, z, w; xywz => x, y, w, z; xzyw => x, z, y, w; xzwy => x, z, w, y;
+              xwyz => x, w, y, z; xwzy => x, w, z, y; yxzw => y, x, z, w; yxwz => y, x, w, z;
+              yzxw => y, z, x, w; yzwx => y, z, w, x; ywxz => y, w, x, z; ywzx => y, w, z, x;
+              zxyw => z, x, y, w; zxwy => z, x, w, y; zyxw => z, y, x, w; zywx => z, y, w, x;
+              zwxy => z, w, x, y; zwyx => z, w, y, x; wxyz => w, x, y, z; wxzy => w, x, z, y;
+              wyxz => w, y, x, z; wyzx => w, y, z, x; wzxy => w, z, x, y; wzyx => w, z, y, x)
+random_unit_impl!(Vec4, 4, x, y, z, w)
 
 /// Vector of dimension 5.
 #[deriving(Eq, PartialEq, Encodable, Decodable, Clone, Hash, Rand, Zero, Show)]
@@ -261,6 +330,7 @@ ord_impl!(Vec5, x, y, z, w, a)
 vec_axis_impl!(Vec5, x, y, z, w, a)
 vec_cast_impl!(Vec5, x, y, z, w, a)
 as_array_impl!(Vec5, 5)
+dvec_conversion_impl!(Vec5, DVec5, to_dvec5, 5)
 index_impl!(Vec5)
 indexable_impl!(Vec5, 5)
 at_fast_impl!(Vec5, 5)
@@ -297,6 +367,10 @@ transform_impl!(Vec5, Pnt5)
 vec_as_pnt_impl!(Vec5, Pnt5, x, y, z, w, a)
 num_float_vec_impl!(Vec5)
 absolute_vec_impl!(Vec5, x, y, z, w, a)
+comp_map_impl!(Vec5, x, y, z, w, a)
+comp_float_impl!(Vec5, x, y, z, w, a)
+comp_reduce_impl!(Vec5, x, y, z, w, a)
+random_unit_impl!(Vec5, 5, x, y, z, w, a)
 
 /// Vector of dimension 6.
 #[deriving(Eq, PartialEq, Encodable, Decodable, Clone, Hash, Rand, Zero, Show)]
@@ -320,6 +394,7 @@ ord_impl!(Vec6, x, y, z, w, a, b)
 vec_axis_impl!(Vec6, x, y, z, w, a, b)
 vec_cast_impl!(Vec6, x, y, z, w, a, b)
 as_array_impl!(Vec6, 6)
+dvec_conversion_impl!(Vec6, DVec6, to_dvec6, 6)
 index_impl!(Vec6)
 indexable_impl!(Vec6, 6)
 at_fast_impl!(Vec6, 6)
@@ -354,3 +429,7 @@ transform_impl!(Vec6, Pnt6)
 vec_as_pnt_impl!(Vec6, Pnt6, x, y, z, w, a, b)
 num_float_vec_impl!(Vec6)
 absolute_vec_impl!(Vec6, x, y, z, w, a, b)
+comp_map_impl!(Vec6, x, y, z, w, a, b)
+comp_float_impl!(Vec6, x, y, z, w, a, b)
+comp_reduce_impl!(Vec6, x, y, z, w, a, b)
+random_unit_impl!(Vec6, 6, x, y, z, w, a, b)
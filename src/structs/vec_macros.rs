@@ -317,14 +317,7 @@ macro_rules! basis_impl(
     ($t: ident, $dim: expr) => (
         impl<N: Clone + BaseFloat + ApproxEq<N>> Basis for $t<N> {
             #[inline]
-            fn canonical_basis(f: |$t<N>| -> bool) {
-                for i in range(0u, $dim) {
-                    if !f(Basis::canonical_basis_element(i).unwrap()) { return }
-                }
-            }
-
-            #[inline]
-            fn orthonormal_subspace_basis(n: &$t<N>, f: |$t<N>| -> bool) {
+            fn orthonormal_subspace_basis_iter(n: &$t<N>) -> MoveItems<$t<N>> {
                 // compute the basis of the orthogonal subspace using Gram-Schmidt
                 // orthogonalization algorithm
                 let mut basis: Vec<$t<N>> = Vec::new();
@@ -349,13 +342,11 @@ macro_rules! basis_impl(
                     };
 
                     if !ApproxEq::approx_eq(&Norm::sqnorm(&elt), &::zero()) {
-                        let new_element = Norm::normalize_cpy(&elt);
-
-                        if !f(new_element.clone()) { return };
-
-                        basis.push(new_element);
+                        basis.push(Norm::normalize_cpy(&elt));
                     }
                 }
+
+                basis.into_iter()
             }
 
             #[inline]
@@ -867,3 +858,194 @@ macro_rules! absolute_vec_impl(
     }
   )
 )
+
+macro_rules! swizzle_impl(
+    ($t: ident, $tres: ident, $($name: ident => $($comp: ident),+);+) => (
+        impl<N: Clone> $t<N> {
+            $(
+                /// Builds a new object from the components of `self`, in the order given by the
+                /// name of this method.
+                #[inline]
+                pub fn $name(&self) -> $tres<N> {
+                    $tres::new($(self.$comp.clone()),+)
+                }
+            )+
+        }
+    )
+)
+
+macro_rules! extend_impl(
+    ($t: ident, $tlow: ident, $name: ident, $($complow: ident),+; $comp: ident) => (
+        impl<N> $t<N> {
+            /// Builds a new object from the components of an object with one less dimension, and
+            /// an additional last component.
+            #[inline]
+            pub fn $name(v: $tlow<N>, $comp: N) -> $t<N> {
+                $t::new($(v.$complow),+, $comp)
+            }
+        }
+    )
+)
+
+macro_rules! comp_map_impl(
+    ($t: ident, $comp0: ident $(,$compN: ident)*) => (
+        impl<N: Clone> CompMap<N> for $t<N> {
+            #[inline]
+            fn map(&self, f: |N| -> N) -> $t<N> {
+                $t::new(f(self.$comp0.clone()) $(, f(self.$compN.clone()))*)
+            }
+
+            #[inline]
+            fn zip_map(&self, other: &$t<N>, f: |N, N| -> N) -> $t<N> {
+                $t::new(f(self.$comp0.clone(), other.$comp0.clone())
+                        $(, f(self.$compN.clone(), other.$compN.clone()))*)
+            }
+        }
+    )
+)
+
+macro_rules! comp_float_impl(
+    ($t: ident, $comp0: ident $(,$compN: ident)*) => (
+        impl<N: BaseFloat> CompFloat<N> for $t<N> {
+            #[inline]
+            fn floor(&self) -> $t<N> {
+                $t::new(self.$comp0.floor() $(, self.$compN.floor())*)
+            }
+
+            #[inline]
+            fn ceil(&self) -> $t<N> {
+                $t::new(self.$comp0.ceil() $(, self.$compN.ceil())*)
+            }
+
+            #[inline]
+            fn round(&self) -> $t<N> {
+                $t::new(self.$comp0.round() $(, self.$compN.round())*)
+            }
+
+            #[inline]
+            fn signum(&self) -> $t<N> {
+                $t::new(self.$comp0.signum() $(, self.$compN.signum())*)
+            }
+
+            #[inline]
+            fn clamp(&self, min: &$t<N>, max: &$t<N>) -> $t<N> {
+                $t::new(self.$comp0.max(min.$comp0).min(max.$comp0)
+                        $(, self.$compN.max(min.$compN).min(max.$compN))*)
+            }
+
+            #[inline]
+            fn lerp(&self, other: &$t<N>, t: &N) -> $t<N> {
+                $t::new(self.$comp0 + (other.$comp0 - self.$comp0) * *t
+                        $(, self.$compN + (other.$compN - self.$compN) * *t)*)
+            }
+        }
+    )
+)
+
+macro_rules! comp_reduce_impl(
+    ($t: ident, $comp0: ident $(,$compN: ident)*) => (
+        impl<N: BaseNum + PartialOrd + Clone> CompReduce<N> for $t<N> {
+            #[inline]
+            fn sum(&self) -> N {
+                self.$comp0.clone() $(+ self.$compN.clone())*
+            }
+
+            #[inline]
+            fn product(&self) -> N {
+                self.$comp0.clone() $(* self.$compN.clone())*
+            }
+
+            #[inline]
+            fn min_component(&self) -> (uint, N) {
+                let mut imin = 0u;
+                let mut min  = self.$comp0.clone();
+
+                for (i, e) in self.iter().enumerate() {
+                    if *e < min {
+                        imin = i;
+                        min  = e.clone();
+                    }
+                }
+
+                (imin, min)
+            }
+
+            #[inline]
+            fn max_component(&self) -> (uint, N) {
+                let mut imax = 0u;
+                let mut max  = self.$comp0.clone();
+
+                for (i, e) in self.iter().enumerate() {
+                    if *e > max {
+                        imax = i;
+                        max  = e.clone();
+                    }
+                }
+
+                (imax, max)
+            }
+        }
+    )
+)
+
+macro_rules! random_unit_impl(
+  ($t: ident, $dim: expr, $comp0: ident $(,$compN: ident)*) => (
+    impl<N: BaseFloat> $t<N> {
+        /// Samples a unit vector uniformly distributed on the unit sphere.
+        pub fn new_random_unit<R: Rng>(rng: &mut R) -> $t<N> {
+            loop {
+                let mut v = $t {
+                    $comp0: random::normal(rng)
+                    $(, $compN: random::normal(rng) )*
+                };
+
+                if !Norm::normalize(&mut v).is_zero() {
+                    return v
+                }
+            }
+        }
+
+        /// Samples a vector uniformly distributed inside of the unit ball.
+        pub fn new_random_in_ball<R: Rng>(rng: &mut R) -> $t<N> {
+            let dir: $t<N> = $t::new_random_unit(rng);
+            let u: N       = random::uniform(rng);
+            let dim: N     = num::cast($dim as f64).unwrap();
+
+            dir * u.powf(::one::<N>() / dim)
+        }
+    }
+  )
+)
+
+macro_rules! dvec_conversion_impl(
+  ($t: ident, $dvec: ident, $to_dvec: ident, $dim: expr) => (
+    impl<N: Clone> $t<N> {
+        /// Converts this value to a heap-allocated, dynamically sized vector.
+        #[inline]
+        pub fn to_dvec(&self) -> DVec<N> {
+            DVec::from_slice($dim, self.as_array())
+        }
+
+        /// Builds a value from the components of a dynamically sized vector.
+        ///
+        /// Returns `None` if the dimension of `v` is not the same as the dimension of `Self`.
+        #[inline]
+        pub fn from_dvec<V: Indexable<uint, N>>(v: &V) -> Option<$t<N>> {
+            if v.shape() != $dim {
+                None
+            }
+            else {
+                Some(FromIterator::from_iter(range(0u, $dim).map(|i| v[i].clone())))
+            }
+        }
+    }
+
+    impl<N: Clone + Zero> $t<N> {
+        /// Converts this value to a stack-allocated, dynamically sized vector.
+        #[inline]
+        pub fn $to_dvec(&self) -> $dvec<N> {
+            $dvec::from_slice($dim, self.as_array())
+        }
+    }
+  )
+)
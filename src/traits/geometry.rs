@@ -1,6 +1,6 @@
 //! Traits of operations having a well-known or explicit geometric meaning.
 
-
+use std::vec::MoveItems;
 use traits::structure::{BaseFloat, Mat};
 
 /// Trait of object which represent a translation, and to wich new translation
@@ -241,6 +241,18 @@ pub trait CrossMatrix<M> {
     fn cross_matrix(&Self) -> M;
 }
 
+/**
+ * Trait of matrices which are the image of a vector by the cross matrix operator, i.e., the
+ * inverse of `CrossMatrix`.
+ *
+ * This is also known as the `vee` operator, the cross matrix being the `hat` operator.
+ */
+pub trait Vee<V> {
+    /// The vector `v` such that `CrossMatrix::cross_matrix(&v) == m`. The components of `m` that
+    /// are not part of the image of the cross matrix operator are ignored.
+    fn vee(&Self) -> V;
+}
+
 /// Traits of objects which can be put in homogeneous coordinates form.
 pub trait ToHomogeneous<U> {
     /// Gets the homogeneous coordinates form of this object.
@@ -261,8 +273,24 @@ pub trait FromHomogeneous<U> {
 /// The number of sample must be sufficient to approximate a sphere using a support mapping
 /// function.
 pub trait UniformSphereSample {
+    /// An iterator through the samples.
+    fn sphere_samples() -> MoveItems<Self>;
+
+    /// An iterator through `count` quasi-uniformly distributed samples.
+    ///
+    /// The generated sample sets are cached, so requesting the same number of samples again is
+    /// cheap.
+    fn sphere_samples_with_count(count: uint) -> MoveItems<Self>;
+
     /// Iterate through the samples.
-    fn sample(|Self| -> ());
+    #[inline]
+    fn sample(f: |Self| -> ()) {
+        let samples: MoveItems<Self> = UniformSphereSample::sphere_samples();
+
+        for s in samples {
+            f(s)
+        }
+    }
 }
 
 /// The zero element of a vector space, seen as an element of its embeding affine space.
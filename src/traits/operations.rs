@@ -325,6 +325,48 @@ pub trait Axpy<N> {
     fn axpy(&mut self, a: &N, x: &Self);
 }
 
+/// Component-wise floating-point operations.
+pub trait CompFloat<N> {
+    /// Rounds each component of `self` toward negative infinity.
+    fn floor(&self) -> Self;
+
+    /// Rounds each component of `self` toward positive infinity.
+    fn ceil(&self) -> Self;
+
+    /// Rounds each component of `self` to the nearest integer, away from zero for half-way cases.
+    fn round(&self) -> Self;
+
+    /// Replaces each component of `self` by a number representing its sign.
+    fn signum(&self) -> Self;
+
+    /// Clamps each component of `self` between the corresponding components of `min` and `max`.
+    fn clamp(&self, min: &Self, max: &Self) -> Self;
+
+    /// Linear interpolation between `self` (for `t = 0`) and `other` (for `t = 1`).
+    fn lerp(&self, other: &Self, t: &N) -> Self;
+}
+
+/// Reductions of the components of an object to a single scalar.
+pub trait CompReduce<N> {
+    /// The sum of the components of `self`.
+    fn sum(&self) -> N;
+
+    /// The product of the components of `self`.
+    fn product(&self) -> N;
+
+    /// The index and value of the smallest component of `self`.
+    ///
+    /// If several components are equal to the minimum, the one with the smallest index is
+    /// returned.
+    fn min_component(&self) -> (uint, N);
+
+    /// The index and value of the greatest component of `self`.
+    ///
+    /// If several components are equal to the maximum, the one with the smallest index is
+    /// returned.
+    fn max_component(&self) -> (uint, N);
+}
+
 /*
  *
  *
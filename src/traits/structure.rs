@@ -4,6 +4,7 @@ use std::f32;
 use std::f64;
 use std::num::{Int, Float, FloatMath};
 use std::slice::{Items, MutItems};
+use std::vec::MoveItems;
 use traits::operations::{RMul, LMul, Axpy, Transpose, Inv, Absolute};
 use traits::geometry::{Dot, Norm, Orig};
 
@@ -101,17 +102,59 @@ pub trait Bounded {
     fn max_value() -> Self;
 }
 
-// FIXME: return an iterator instead
 /// Traits of objects which can form a basis (typically vectors).
 pub trait Basis {
-    /// Iterates through the canonical basis of the space in which this object lives.
-    fn canonical_basis(|Self| -> bool);
+    /// An iterator through the canonical basis of the space in which this object lives.
+    #[inline]
+    fn canonical_basis_iter() -> CanonicalBasisIter<Self> {
+        CanonicalBasisIter { curr: 0 }
+    }
 
-    /// Iterates through a basis of the subspace orthogonal to `self`.
-    fn orthonormal_subspace_basis(&Self, |Self| -> bool);
+    /// An iterator through a basis of the subspace orthogonal to `self`.
+    fn orthonormal_subspace_basis_iter(&Self) -> MoveItems<Self>;
 
     /// Gets the ith element of the canonical basis.
     fn canonical_basis_element(i: uint) -> Option<Self>;
+
+    /// Iterates through the canonical basis of the space in which this object lives.
+    ///
+    /// The iteration stops as soon as `f` returns `false`.
+    #[inline]
+    fn canonical_basis(f: |Self| -> bool) {
+        let iter: CanonicalBasisIter<Self> = Basis::canonical_basis_iter();
+
+        for e in iter {
+            if !f(e) { return }
+        }
+    }
+
+    /// Iterates through a basis of the subspace orthogonal to `self`.
+    ///
+    /// The iteration stops as soon as `f` returns `false`.
+    #[inline]
+    fn orthonormal_subspace_basis(v: &Self, f: |Self| -> bool) {
+        for e in Basis::orthonormal_subspace_basis_iter(v) {
+            if !f(e) { return }
+        }
+    }
+}
+
+/// Iterator through the canonical basis of a vector space.
+pub struct CanonicalBasisIter<V> {
+    curr: uint
+}
+
+impl<V: Basis> Iterator<V> for CanonicalBasisIter<V> {
+    #[inline]
+    fn next(&mut self) -> Option<V> {
+        let res = Basis::canonical_basis_element(self.curr);
+
+        if res.is_some() {
+            self.curr = self.curr + 1;
+        }
+
+        res
+    }
 }
 
 /// Trait to access rows of a matrix or a vector.
@@ -219,6 +262,15 @@ pub trait IterableMut<N> {
     fn iter_mut<'l>(&'l mut self) -> MutItems<'l, N>;
 }
 
+/// Trait of objects whose components can be transformed one by one.
+pub trait CompMap<N> {
+    /// Applies `f` to each component of `self`.
+    fn map(&self, f: |N| -> N) -> Self;
+
+    /// Applies `f` to each pair of corresponding components of `self` and `other`.
+    fn zip_map(&self, other: &Self, f: |N, N| -> N) -> Self;
+}
+
 /*
  * Vec related traits.
  */
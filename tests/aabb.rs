@@ -0,0 +1,136 @@
+extern crate "nalgebra" as na;
+
+use std::rand::random;
+use na::{Pnt2, Pnt3, Pnt6, Vec2, Vec3, Rot3, Iso2, Iso3, Mat4, Aabb, Transform, AbsoluteRotate};
+
+fn check_transform3<M: Transform<Pnt3<f64>> + AbsoluteRotate<Vec3<f64>>>(m: &M) {
+    let aabb        = Aabb::new(Pnt3::new(-1.0f64, -2.0, -3.0), Pnt3::new(3.0, 2.0, 1.0));
+    let transformed = aabb.transform_by(m);
+    let mut corners = Vec::new();
+
+    for x in [ aabb.mins.x, aabb.maxs.x ].iter() {
+        for y in [ aabb.mins.y, aabb.maxs.y ].iter() {
+            for z in [ aabb.mins.z, aabb.maxs.z ].iter() {
+                corners.push(m.transform(&Pnt3::new(*x, *y, *z)))
+            }
+        }
+    }
+
+    let expected = Aabb::from_points(corners.into_iter()).unwrap();
+
+    assert!(na::approx_eq(&transformed.mins, &expected.mins));
+    assert!(na::approx_eq(&transformed.maxs, &expected.maxs));
+}
+
+#[test]
+fn test_aabb_from_points() {
+    for _ in range(0u, 1000) {
+        let pts: Vec<Pnt3<f64>> = range(0u, 10).map(|_| random()).collect();
+        let aabb = Aabb::from_points(pts.iter().map(|p| *p)).unwrap();
+
+        assert!(pts.iter().all(|p| aabb.contains_pnt(p)));
+        assert!(pts.iter().any(|p| p.x == aabb.mins.x));
+        assert!(pts.iter().any(|p| p.y == aabb.maxs.y));
+    }
+
+    assert!(Aabb::from_points(Vec::<Pnt2<f64>>::new().into_iter()).is_none());
+}
+
+#[test]
+fn test_aabb_merge_intersection() {
+    let a = Aabb::new(Pnt2::new(0.0f64, 0.0), Pnt2::new(2.0, 2.0));
+    let b = Aabb::new(Pnt2::new(1.0f64, -1.0), Pnt2::new(3.0, 1.0));
+    let c = Aabb::new(Pnt2::new(5.0f64, 5.0), Pnt2::new(6.0, 6.0));
+
+    let ab = a.merged(&b);
+
+    assert!(ab == Aabb::new(Pnt2::new(0.0, -1.0), Pnt2::new(3.0, 2.0)));
+    assert!(ab.contains(&a) && ab.contains(&b));
+    assert!(!a.contains(&b));
+
+    let mut merged = a.clone();
+    merged.merge(&b);
+    assert!(merged == ab);
+
+    assert!(a.intersects(&b));
+    assert!(!a.intersects(&c));
+    assert!(a.intersection(&b) == Some(Aabb::new(Pnt2::new(1.0, 0.0), Pnt2::new(2.0, 1.0))));
+    assert!(a.intersection(&c).is_none());
+}
+
+#[test]
+fn test_aabb_center_extents_volume() {
+    let a2 = Aabb::new(Pnt2::new(-1.0f64, 0.0), Pnt2::new(3.0, 2.0));
+    let a3 = Aabb::new(Pnt3::new(-1.0f64, 0.0, 1.0), Pnt3::new(3.0, 2.0, 4.0));
+    let a6 = Aabb::new(na::orig::<Pnt6<f64>>(), Pnt6::new(1.0, 2.0, 3.0, 1.0, 2.0, 3.0));
+
+    assert!(a2.center() == Pnt2::new(1.0, 1.0));
+    assert!(a2.extents() == Vec2::new(4.0, 2.0));
+    assert!(a2.volume() == 8.0);
+
+    assert!(a3.center() == Pnt3::new(1.0, 1.0, 2.5));
+    assert!(a3.half_extents() == Vec3::new(2.0, 1.0, 1.5));
+    assert!(a3.volume() == 24.0);
+
+    assert!(a6.volume() == 36.0);
+}
+
+#[test]
+fn test_aabb_transform_by_iso2() {
+    for _ in range(0u, 1000) {
+        let m: Iso2<f64> = random();
+        let aabb         = Aabb::new(Pnt2::new(-1.0f64, -2.0), Pnt2::new(3.0, 2.0));
+        let transformed  = aabb.transform_by(&m);
+        let corners      = [ aabb.mins, aabb.maxs,
+                             Pnt2::new(aabb.mins.x, aabb.maxs.y),
+                             Pnt2::new(aabb.maxs.x, aabb.mins.y) ];
+        let expected     = Aabb::from_points(corners.iter().map(|c| m.transform(c))).unwrap();
+
+        assert!(na::approx_eq(&transformed.mins, &expected.mins));
+        assert!(na::approx_eq(&transformed.maxs, &expected.maxs));
+    }
+}
+
+#[test]
+fn test_aabb_transform_by_iso3() {
+    for _ in range(0u, 1000) {
+        let m: Iso3<f64> = random();
+
+        check_transform3(&m);
+    }
+}
+
+#[test]
+fn test_aabb_transform_by_mat4() {
+    for _ in range(0u, 1000) {
+        let t: Vec3<f64> = random();
+        let r: Rot3<f64> = random();
+        let s: Vec3<f64> = random();
+
+        check_transform3(&Mat4::new_with_trs(t, r, s + 0.5f64));
+    }
+}
+
+#[test]
+fn test_aabb_transform_by_mat4_homogeneous_scale() {
+    for _ in range(0u, 1000) {
+        let t: Vec3<f64> = random();
+        let r: Rot3<f64> = random();
+        let mut m        = Mat4::new_with_trs(t, r, Vec3::new(1.0, 2.0, 3.0));
+
+        m.m44 = 0.5;
+
+        check_transform3(&m);
+    }
+}
+
+#[test]
+#[should_fail]
+fn test_aabb_transform_by_projective_mat4() {
+    let aabb  = Aabb::new(Pnt3::new(-1.0f64, -2.0, -3.0), Pnt3::new(3.0, 2.0, 1.0));
+    let mut m = na::one::<Mat4<f64>>();
+
+    m.m43 = -1.0;
+
+    let _ = aabb.transform_by(&m);
+}
@@ -0,0 +1,200 @@
+#![feature(macro_rules)]
+
+extern crate "nalgebra" as na;
+
+use na::{Pnt2, Pnt3, Vec2, Vec3, Mat2, Mat3, Mat4, Rot2, Rot3, Iso3, Sim3, Aff2, Aff3};
+use std::rand::random;
+
+#[test]
+fn test_aff3_pnt_vs_vec() {
+    for _ in range(0u, 10000) {
+        let aff: Aff3<f64> = random();
+        let p: Pnt3<f64>   = random();
+        let v: Vec3<f64>   = random();
+
+        assert!(na::approx_eq(&na::transform(&aff, &p), &(aff.linear * p + aff.translation)));
+        assert!(na::approx_eq(&na::transform(&aff, &v), &(aff.linear * v)));
+        assert!(na::approx_eq(&(aff * p), &na::transform(&aff, &p)));
+        assert!(na::approx_eq(&(aff * v), &na::transform(&aff, &v)));
+    }
+}
+
+#[test]
+fn test_aff3_inv() {
+    for _ in range(0u, 10000) {
+        let mut aff: Aff3<f64> = random();
+        let p: Pnt3<f64>       = random();
+        let v: Vec3<f64>       = random();
+
+        // make the linear part diagonally dominant so that it is well-conditioned
+        aff.linear = aff.linear + Mat3::new(2.0, 0.0, 0.0,
+                                            0.0, 2.0, 0.0,
+                                            0.0, 0.0, 2.0);
+
+        let inv = na::inv(&aff).unwrap();
+
+        assert!(na::approx_eq(&(inv * aff), &na::one()));
+        assert!(na::approx_eq(&na::inv_transform(&aff, &na::transform(&aff, &p)), &p));
+        assert!(na::approx_eq(&na::inv_transform(&aff, &na::transform(&aff, &v)), &v));
+    }
+}
+
+#[test]
+fn test_aff3_singular_is_not_invertible() {
+    let aff = Aff3::new(na::zero(), Mat3::new(1.0f64, 0.0, 0.0,
+                                              0.0,    1.0, 0.0,
+                                              0.0,    0.0, 0.0));
+
+    assert!(na::inv(&aff).is_none());
+    assert!(aff.decompose().is_none());
+}
+
+#[test]
+fn test_aff3_homogeneous() {
+    for _ in range(0u, 10000) {
+        let a1: Aff3<f64> = random();
+        let a2: Aff3<f64> = random();
+
+        let h = na::to_homogeneous(&(a1 * a2));
+
+        assert!(na::approx_eq(&h, &(na::to_homogeneous(&a1) * na::to_homogeneous(&a2))));
+        assert!(na::approx_eq(&Aff3::new_with_homogeneous(&h).unwrap(), &(a1 * a2)));
+        assert!(na::approx_eq(&na::from_homogeneous::<Mat4<f64>, Aff3<f64>>(&h), &(a1 * a2)));
+    }
+}
+
+#[test]
+fn test_aff3_homogeneous_rejects_projective() {
+    let m = Mat4::new(1.0f64, 0.0, 0.0, 0.0,
+                      0.0,    1.0, 0.0, 0.0,
+                      0.0,    0.0, 1.0, 0.0,
+                      0.0,    0.0, 1.0, 1.0);
+
+    assert!(Aff3::new_with_homogeneous(&m).is_none());
+}
+
+#[test]
+fn test_aff3_from_iso_and_sim() {
+    for _ in range(0u, 10000) {
+        let iso: Iso3<f64> = random();
+        let sim: Sim3<f64> = random();
+        let p: Pnt3<f64>   = random();
+
+        assert!(na::approx_eq(&(Aff3::new_with_iso(iso) * p), &(iso * p)));
+        assert!(na::approx_eq(&(Aff3::new_with_sim(sim) * p), &(sim * p)));
+    }
+}
+
+#[test]
+fn test_aff3_decomposition() {
+    for _ in range(0u, 10000) {
+        let translation: Vec3<f64> = random();
+        let rotation: Rot3<f64>    = random();
+        let scale: Vec3<f64>       = random();
+        let shear: Vec3<f64>       = random();
+        let scale                  = scale + Vec3::new(0.5, 0.5, -1.5);
+
+        let aff = Aff3::new_with_decomposition(translation, rotation, scale, shear);
+        let (t, r, s, sh) = aff.decompose().unwrap();
+
+        assert!(na::approx_eq(&t, &translation));
+        assert!(na::approx_eq(&r, &rotation));
+        assert!(na::approx_eq(&s, &scale));
+        assert!(na::approx_eq(&sh, &shear));
+    }
+}
+
+#[test]
+fn test_aff3_decomposition_round_trip() {
+    for _ in range(0u, 10000) {
+        let mut aff: Aff3<f64> = random();
+
+        aff.linear = aff.linear + Mat3::new(2.0, 0.0, 0.0,
+                                            0.0, 2.0, 0.0,
+                                            0.0, 0.0, 2.0);
+
+        let (t, r, s, sh) = aff.decompose().unwrap();
+
+        assert!(na::approx_eq(&Aff3::new_with_decomposition(t, r, s, sh), &aff))
+    }
+}
+
+#[test]
+fn test_aff3_decomposition_tiny_scale() {
+    for _ in range(0u, 1000) {
+        let rotation: Rot3<f64> = random();
+        let shear: Vec3<f64>    = random();
+        let scale               = Vec3::new(1.0e-7, 2.0e-7, -3.0e-7);
+
+        let aff = Aff3::new_with_decomposition(na::zero(), rotation, scale, shear);
+        let (_, r, s, sh) = aff.decompose().unwrap();
+
+        assert!(na::approx_eq(&r, &rotation));
+        assert!(na::approx_eq(&(s * 1.0e7), &(scale * 1.0e7)));
+        assert!(na::approx_eq(&sh, &shear));
+    }
+}
+
+#[test]
+fn test_aff2() {
+    for _ in range(0u, 10000) {
+        let a1: Aff2<f64> = random();
+        let a2: Aff2<f64> = random();
+        let p: Pnt2<f64>  = random();
+        let v: Vec2<f64>  = random();
+
+        assert!(na::approx_eq(&((a1 * a2) * p), &(a1 * (a2 * p))));
+        assert!(na::approx_eq(&(a1 * v), &(a1.linear * v)));
+        assert!(na::approx_eq(&na::to_homogeneous(&(a1 * a2)),
+                              &(na::to_homogeneous(&a1) * na::to_homogeneous(&a2))));
+        assert!(na::approx_eq(&Aff2::new_with_homogeneous(&na::to_homogeneous(&a1)).unwrap(),
+                              &a1));
+    }
+}
+
+#[test]
+fn test_aff2_decomposition() {
+    for _ in range(0u, 10000) {
+        let translation: Vec2<f64> = random();
+        let rotation: Rot2<f64>    = random();
+        let scale: Vec2<f64>       = random();
+        let shear: f64             = random();
+        let scale                  = scale + Vec2::new(0.5, -1.5);
+
+        let aff = Aff2::new_with_decomposition(translation, rotation, scale, shear);
+        let (t, r, s, sh) = aff.decompose().unwrap();
+
+        assert!(na::approx_eq(&t, &translation));
+        assert!(na::approx_eq(&r, &rotation));
+        assert!(na::approx_eq(&s, &scale));
+        assert!(na::approx_eq(&sh, &shear));
+    }
+}
+
+#[test]
+fn test_aff2_decomposition_tiny_scale() {
+    for _ in range(0u, 1000) {
+        let rotation: Rot2<f64> = random();
+        let shear: f64          = random();
+        let scale               = Vec2::new(1.0e-7, -2.0e-7);
+
+        let aff = Aff2::new_with_decomposition(na::zero(), rotation, scale, shear);
+        let (_, r, s, sh) = aff.decompose().unwrap();
+
+        assert!(na::approx_eq(&r, &rotation));
+        assert!(na::approx_eq(&(s * 1.0e7), &(scale * 1.0e7)));
+        assert!(na::approx_eq(&sh, &shear));
+    }
+
+    let singular = Aff2::new(na::zero(), Mat2::new(1.0e-7f64, 2.0e-7, 1.0e-7, 2.0e-7));
+    assert!(singular.decompose().is_none());
+}
+
+#[test]
+fn test_aff2_homogeneous_rejects_projective() {
+    let m = Mat3::new(1.0f64, 0.0, 0.0,
+                      0.0,    1.0, 0.0,
+                      1.0,    0.0, 1.0);
+
+    assert!(Aff2::new_with_homogeneous(&m).is_none());
+}
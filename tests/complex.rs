@@ -0,0 +1,112 @@
+#![feature(macro_rules)]
+
+extern crate "nalgebra" as na;
+
+use na::{Pnt2, Vec1, Vec2, Rot2, Iso2, ComplexIso2, UnitComplex, Rotation};
+use std::rand::random;
+
+#[test]
+fn test_complex_as_mat() {
+    for _ in range(0u, 10000) {
+        let angle: Vec1<f64> = random();
+
+        assert!(na::approx_eq(&UnitComplex::new(angle).to_rot(), &Rot2::new(angle)))
+    }
+}
+
+#[test]
+fn test_complex_mul_vec_or_pnt_as_mat() {
+    for _ in range(0u, 10000) {
+        let angle: Vec1<f64> = random();
+        let vec: Vec2<f64>   = random();
+        let pnt: Pnt2<f64>   = random();
+
+        let mat     = Rot2::new(angle);
+        let complex = UnitComplex::new(angle);
+
+        assert!(na::approx_eq(&(mat * vec), &(complex * vec)));
+        assert!(na::approx_eq(&(mat * pnt), &(complex * pnt)));
+        assert!(na::approx_eq(&(vec * mat), &(vec * complex)));
+        assert!(na::approx_eq(&(pnt * mat), &(pnt * complex)));
+    }
+}
+
+#[test]
+fn test_complex_mul_complex() {
+    for _ in range(0u, 10000) {
+        let angle1: Vec1<f64> = random();
+        let angle2: Vec1<f64> = random();
+
+        let c = UnitComplex::new(angle1) * UnitComplex::new(angle2);
+        let r = Rot2::new(angle1) * Rot2::new(angle2);
+
+        assert!(na::approx_eq(&c.to_rot(), &r))
+    }
+}
+
+#[test]
+fn test_complex_inv() {
+    for _ in range(0u, 10000) {
+        let c: UnitComplex<f64> = random();
+
+        assert!(na::approx_eq(&(na::inv(&c).unwrap() * c), &na::one()))
+    }
+}
+
+#[test]
+fn test_complex_rotation() {
+    for _ in range(0u, 10000) {
+        let angle: Vec1<f64> = random();
+        let c                = UnitComplex::new(angle);
+
+        assert!(na::approx_eq(&c.rotation(), &angle));
+        assert!(na::approx_eq(&na::append_rotation(&c, &angle).to_rot(),
+                              &na::append_rotation(&Rot2::new(angle), &angle)));
+    }
+}
+
+#[test]
+fn test_complex_rot_conversion() {
+    for _ in range(0u, 10000) {
+        let angle: Vec1<f64> = random();
+        let r                = Rot2::new(angle);
+
+        assert!(na::approx_eq(&UnitComplex::new_with_rot(r).to_rot(), &r))
+    }
+}
+
+#[test]
+fn test_complex_renormalize() {
+    for _ in range(0u, 100) {
+        let angle: Vec1<f32>          = random();
+        let delta                     = UnitComplex::new(angle);
+        let mut acc: UnitComplex<f32> = na::one();
+
+        for _ in range(0u, 10000) {
+            acc = acc * delta;
+        }
+
+        acc.renormalize();
+
+        assert!(na::approx_eq(&(*acc.re() * *acc.re() + *acc.im() * *acc.im()), &1.0))
+    }
+}
+
+#[test]
+fn test_complex_iso2_as_iso2() {
+    for _ in range(0u, 10000) {
+        let iso1: Iso2<f64> = random();
+        let iso2: Iso2<f64> = random();
+        let pnt: Pnt2<f64>  = random();
+
+        let ciso1 = ComplexIso2::new_with_iso(iso1);
+        let ciso2 = ComplexIso2::new_with_iso(iso2);
+
+        assert!(na::approx_eq(&ciso1.to_iso(), &iso1));
+        assert!(na::approx_eq(&(ciso1 * ciso2).to_iso(), &(iso1 * iso2)));
+        assert!(na::approx_eq(&(ciso1 * pnt), &(iso1 * pnt)));
+        assert!(na::approx_eq(&na::inv(&ciso1).unwrap().to_iso(), &na::inv(&iso1).unwrap()));
+        assert!(na::approx_eq(&na::to_homogeneous(&ciso1), &na::to_homogeneous(&iso1)));
+        assert!(na::approx_eq(&na::inv_transform(&ciso1, &na::transform(&ciso1, &pnt)), &pnt));
+    }
+}
@@ -0,0 +1,119 @@
+#![feature(macro_rules)]
+
+extern crate "nalgebra" as na;
+
+use na::{Pnt3, Vec3, Iso3, UnitDualQuat, Rotation};
+use std::rand::random;
+
+#[test]
+fn test_dual_quat_iso3_conversion() {
+    for _ in range(0u, 10000) {
+        let iso: Iso3<f64> = random();
+
+        assert!(na::approx_eq(&UnitDualQuat::new_with_iso(iso).to_iso(), &iso))
+    }
+}
+
+#[test]
+fn test_dual_quat_as_iso3() {
+    for _ in range(0u, 10000) {
+        let iso1: Iso3<f64> = random();
+        let iso2: Iso3<f64> = random();
+        let pnt: Pnt3<f64>  = random();
+        let vec: Vec3<f64>  = random();
+
+        let dq1 = UnitDualQuat::new_with_iso(iso1);
+        let dq2 = UnitDualQuat::new_with_iso(iso2);
+
+        assert!(na::approx_eq(&(dq1 * dq2).to_iso(), &(iso1 * iso2)));
+        assert!(na::approx_eq(&(dq1 * pnt), &(iso1 * pnt)));
+        assert!(na::approx_eq(&(dq1 * vec), &(iso1 * vec)));
+        assert!(na::approx_eq(&na::inv(&dq1).unwrap().to_iso(), &na::inv(&iso1).unwrap()));
+        assert!(na::approx_eq(&na::to_homogeneous(&dq1), &na::to_homogeneous(&iso1)));
+        assert!(na::approx_eq(&na::inv_transform(&dq1, &na::transform(&dq1, &pnt)), &pnt));
+    }
+}
+
+#[test]
+fn test_dual_quat_sclerp_end_points() {
+    for _ in range(0u, 10000) {
+        let dq1: UnitDualQuat<f64> = random();
+        let dq2: UnitDualQuat<f64> = random();
+        let pnt: Pnt3<f64>         = random();
+
+        assert!(na::approx_eq(&(dq1.sclerp(&dq2, 0.0) * pnt), &(dq1 * pnt)));
+        assert!(na::approx_eq(&(dq1.sclerp(&dq2, 1.0) * pnt), &(dq2 * pnt)));
+    }
+}
+
+#[test]
+fn test_dual_quat_sclerp_screw_motion() {
+    for _ in range(0u, 10000) {
+        let axisangle: Vec3<f64> = random();
+        let axis                 = na::normalize(&axisangle);
+        let shift: f64           = random();
+        let t: f64               = random();
+
+        // A screw motion along an axis passing through the origin.
+        let dq = UnitDualQuat::new(axis * shift, axisangle);
+        let it = na::one::<UnitDualQuat<f64>>().sclerp(&dq, t);
+
+        assert!(na::approx_eq(&it.translation(), &(axis * (shift * t))));
+        assert!(na::approx_eq(&it.rotation().rotation(), &(axisangle * t)));
+    }
+}
+
+#[test]
+fn test_dual_quat_sclerp_pure_translation() {
+    for _ in range(0u, 10000) {
+        let translation: Vec3<f64> = random();
+        let t: f64                 = random();
+
+        let dq = UnitDualQuat::new(translation, na::zero());
+        let it = na::one::<UnitDualQuat<f64>>().sclerp(&dq, t);
+
+        assert!(na::approx_eq(&it.translation(), &(translation * t)));
+    }
+}
+
+#[test]
+fn test_dual_quat_blend() {
+    for _ in range(0u, 10000) {
+        let dq1: UnitDualQuat<f64> = random();
+        let dq2: UnitDualQuat<f64> = random();
+        let pnt: Pnt3<f64>         = random();
+
+        let only_first = UnitDualQuat::blend(&[dq1, dq2], &[1.0, 0.0]);
+        // The blending is invariant by a flip of sign of one of the dual quaternions.
+        let flipped    = unsafe { UnitDualQuat::new_with_unit_dual_quat(-*dq2.dual_quat()) };
+        let half       = UnitDualQuat::blend(&[dq1, dq2], &[0.5, 0.5]);
+        let half_flip  = UnitDualQuat::blend(&[dq1, flipped], &[0.5, 0.5]);
+
+        assert!(na::approx_eq(&(only_first * pnt), &(dq1 * pnt)));
+        assert!(na::approx_eq(&(half * pnt), &(half_flip * pnt)));
+    }
+}
+
+#[test]
+#[should_fail]
+fn test_dual_quat_blend_zero_real_part() {
+    let dq: UnitDualQuat<f64> = random();
+
+    let _ = UnitDualQuat::blend(&[dq, dq], &[1.0, -1.0]);
+}
+
+#[test]
+fn test_dual_quat_renormalize() {
+    for _ in range(0u, 100) {
+        let delta: UnitDualQuat<f32>   = random();
+        let mut acc: UnitDualQuat<f32> = na::one();
+
+        for _ in range(0u, 1000) {
+            acc = acc * delta;
+        }
+
+        acc.renormalize();
+
+        assert!(na::approx_eq(&na::norm(&acc.dual_quat().real), &1.0))
+    }
+}
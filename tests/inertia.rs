@@ -0,0 +1,119 @@
+#![feature(macro_rules)]
+
+extern crate "nalgebra" as na;
+
+use na::{Pnt3, Vec3, Mat3, Rot3, UnitQuat, Iso3, Inertia3, Col, Diag};
+use std::rand::random;
+
+#[test]
+fn test_inertia3_shapes() {
+    let cuboid   = Inertia3::new_cuboid(12.0f64, Vec3::new(1.0, 2.0, 3.0));
+    let cylinder = Inertia3::new_cylinder(12.0f64, 1.0, 1.0);
+    let ball     = Inertia3::new_ball(5.0f64, 2.0);
+
+    assert!(na::approx_eq(&cuboid.tensor, &Diag::from_diag(&Vec3::new(52.0, 40.0, 20.0))));
+    assert!(na::approx_eq(&cylinder.tensor, &Diag::from_diag(&Vec3::new(7.0, 6.0, 7.0))));
+    assert!(na::approx_eq(&ball.tensor, &Diag::from_diag(&Vec3::new(8.0, 8.0, 8.0))));
+    assert!(na::is_zero(cuboid.center_of_mass.as_vec()));
+}
+
+#[test]
+fn test_inertia3_parallel_axis() {
+    for _ in range(0u, 10000) {
+        let p: Pnt3<f64>  = random();
+        let m: f64        = random();
+        let i             = Inertia3::new_point_mass(m, p);
+        let v             = *p.as_vec();
+        let id: Mat3<f64> = na::one();
+
+        assert!(na::approx_eq(&i.tensor_at(&p), &i.tensor));
+        assert!(na::approx_eq(&i.tensor_at(&na::orig()),
+                              &((id * na::sqnorm(&v) - na::outer(&v, &v)) * m)));
+    }
+}
+
+#[test]
+fn test_inertia3_combination() {
+    // A cuboid is the combination of its two halves.
+    let whole = Inertia3::new_cuboid(2.0f64, Vec3::new(1.0, 2.0, 3.0));
+    let half  = Inertia3::new_cuboid(1.0f64, Vec3::new(0.5, 2.0, 3.0));
+    let left  = na::transform(&Iso3::new(Vec3::new(-0.5, 0.0, 0.0), na::zero()), &half);
+    let right = na::transform(&Iso3::new(Vec3::new(0.5, 0.0, 0.0), na::zero()), &half);
+
+    assert!(na::approx_eq(&(left + right), &whole));
+
+    for _ in range(0u, 10000) {
+        let a = Inertia3::new_ball(random::<f64>() + 0.1, random());
+        let b = Inertia3::new_cylinder(random::<f64>() + 0.1, random(), random());
+        let c = Inertia3::new_point_mass(random::<f64>() + 0.1, random());
+
+        let iso1: Iso3<f64> = random();
+        let iso2: Iso3<f64> = random();
+        let a = na::transform(&iso1, &a);
+        let b = na::transform(&iso2, &b);
+
+        assert!(na::approx_eq(&((a + b) + c), &(a + (b + c))));
+        assert!(na::approx_eq(&(a + na::zero()), &a));
+        assert!(na::approx_eq(&(a + b).tensor_at(&na::orig()),
+                              &(a.tensor_at(&na::orig()) + b.tensor_at(&na::orig()))));
+    }
+}
+
+#[test]
+fn test_inertia3_transform() {
+    for _ in range(0u, 10000) {
+        let rot: Rot3<f64> = random();
+        let iso: Iso3<f64> = random();
+        let i = Inertia3::new_cuboid(random::<f64>() + 0.1, random());
+        let i = na::transform(&iso, &i);
+
+        let ri = na::transform(&rot, &i);
+        let r  = *rot.submat();
+
+        assert!(na::approx_eq(&ri.tensor, &(r * i.tensor * na::transpose(&r))));
+        assert!(na::approx_eq(&ri.center_of_mass, &(rot * i.center_of_mass)));
+        assert!(na::approx_eq(&na::inv_transform(&rot, &ri), &i));
+        assert!(na::approx_eq(&na::inv_transform(&iso, &na::transform(&iso, &i)), &i));
+
+        let q = UnitQuat::new(na::rotation(&rot));
+
+        assert!(na::approx_eq(&na::transform(&q, &i), &ri));
+    }
+}
+
+#[test]
+fn test_inertia3_principal_axes() {
+    for _ in range(0u, 10000) {
+        let rot: Rot3<f64> = random();
+        let cuboid = Inertia3::new_cuboid(12.0f64, Vec3::new(1.0, 2.0, 3.0));
+        let i      = na::transform(&rot, &cuboid);
+
+        let (moments, axes) = i.principal_axes();
+        let r               = *axes.submat();
+        let d: Mat3<f64>    = Diag::from_diag(&moments);
+
+        assert!(na::approx_eq(&moments, &Vec3::new(20.0, 40.0, 52.0)));
+        assert!(na::approx_eq(&(r * d * na::transpose(&r)), &i.tensor));
+        assert!(na::approx_eq(&na::det(&r), &1.0));
+    }
+}
+
+#[test]
+fn test_inertia3_principal_axes_degenerate() {
+    for _ in range(0u, 10000) {
+        let rot: Rot3<f64> = random();
+        let i = na::transform(&rot, &Inertia3::new_cylinder(12.0f64, 1.0, 1.0));
+
+        let (moments, axes) = i.principal_axes();
+        let r               = *axes.submat();
+        let d: Mat3<f64>    = Diag::from_diag(&moments);
+
+        assert!(na::approx_eq(&moments, &Vec3::new(6.0, 7.0, 7.0)));
+        assert!(na::approx_eq(&(r * d * na::transpose(&r)), &i.tensor));
+
+        // The axis of the cylinder is the principal axis with the smallest moment.
+        let axis = rot * Vec3::new(0.0, 1.0, 0.0);
+
+        assert!(na::approx_eq(&na::dot(&axes.col(0), &axis).abs(), &1.0));
+    }
+}
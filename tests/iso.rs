@@ -0,0 +1,171 @@
+#![feature(macro_rules)]
+
+extern crate "nalgebra" as na;
+
+use na::{Pnt2, Pnt3, Vec1, Vec2, Vec3, Rot2, Rot3, Iso2, Iso3, Rotation, Translation};
+use std::rand::random;
+
+#[test]
+fn test_iso3_lerp_slerp_end_points() {
+    for _ in range(0u, 10000) {
+        let iso1: Iso3<f64> = random();
+        let iso2: Iso3<f64> = random();
+
+        assert!(na::approx_eq(&iso1.lerp_slerp(&iso2, 0.0), &iso1));
+        assert!(na::approx_eq(&iso1.lerp_slerp(&iso2, 1.0), &iso2));
+    }
+}
+
+#[test]
+fn test_iso3_lerp_slerp() {
+    for _ in range(0u, 10000) {
+        let axisangle: Vec3<f64> = random();
+        let t1: Vec3<f64>        = random();
+        let t2: Vec3<f64>        = random();
+        let t: f64               = random();
+
+        let iso1 = Iso3::new(t1, na::zero());
+        let iso2 = Iso3::new(t2, axisangle);
+        let it   = iso1.lerp_slerp(&iso2, t);
+
+        assert!(na::approx_eq(&it.translation(), &(t1 * (1.0 - t) + t2 * t)));
+        assert!(na::approx_eq(&it.rotation(), &(axisangle * t)));
+    }
+}
+
+#[test]
+fn test_iso2_lerp_slerp() {
+    for _ in range(0u, 10000) {
+        let angle: Vec1<f64> = random();
+        let t1: Vec2<f64>    = random();
+        let t2: Vec2<f64>    = random();
+        let t: f64           = random();
+
+        let iso1 = Iso2::new(t1, na::zero());
+        let iso2 = Iso2::new(t2, angle);
+        let it   = iso1.lerp_slerp(&iso2, t);
+
+        assert!(na::approx_eq(&iso1.lerp_slerp(&iso2, 0.0), &iso1));
+        assert!(na::approx_eq(&iso1.lerp_slerp(&iso2, 1.0), &iso2));
+        assert!(na::approx_eq(&it.translation(), &(t1 * (1.0 - t) + t2 * t)));
+        assert!(na::approx_eq(&it.rotation(), &(angle * t)));
+    }
+}
+
+#[test]
+fn test_iso3_sclerp() {
+    for _ in range(0u, 10000) {
+        let iso1: Iso3<f64> = random();
+        let iso2: Iso3<f64> = random();
+
+        assert!(na::approx_eq(&iso1.sclerp(&iso2, 0.0), &iso1));
+        assert!(na::approx_eq(&iso1.sclerp(&iso2, 1.0), &iso2));
+    }
+}
+
+#[test]
+fn test_iso3_sclerp_screw_motion() {
+    for _ in range(0u, 10000) {
+        let axisangle: Vec3<f64> = random();
+        let center: Vec3<f64>    = random();
+        let shift: f64           = random();
+        let t: f64               = random();
+
+        // A screw motion around an axis that does not pass through the origin.
+        let axis    = na::normalize(&axisangle);
+        let to_c    = Iso3::new(center, na::zero());
+        let from_c  = na::inv(&to_c).unwrap();
+        let screw_1 = to_c * Iso3::new(axis * shift, axisangle) * from_c;
+        let screw_t = to_c * Iso3::new(axis * (shift * t), axisangle * t) * from_c;
+
+        assert!(na::approx_eq(&na::one::<Iso3<f64>>().sclerp(&screw_1, t), &screw_t));
+    }
+}
+
+#[test]
+fn test_iso2_sclerp() {
+    for _ in range(0u, 10000) {
+        let iso1: Iso2<f64>   = random();
+        let iso2: Iso2<f64>   = random();
+        let angle: Vec1<f64>  = random();
+        let center: Pnt2<f64> = random();
+        let t: f64            = random();
+
+        assert!(na::approx_eq(&iso1.sclerp(&iso2, 0.0), &iso1));
+        assert!(na::approx_eq(&iso1.sclerp(&iso2, 1.0), &iso2));
+
+        // The center of the rotation is a fixed point of the whole motion.
+        let c   = *center.as_vec();
+        let rot = Iso2::new(c - Rot2::new(angle) * c, angle);
+        let it  = na::one::<Iso2<f64>>().sclerp(&rot, t);
+
+        assert!(na::approx_eq(&(it * center), &center));
+        assert!(na::approx_eq(&it.rotation(), &(angle * t)));
+    }
+}
+
+#[test]
+fn test_iso2_sclerp_translation() {
+    for _ in range(0u, 10000) {
+        let translation: Vec2<f64> = random();
+        let t: f64                 = random();
+
+        let iso = Iso2::new(translation, na::zero());
+        let it  = na::one::<Iso2<f64>>().sclerp(&iso, t);
+
+        assert!(na::approx_eq(&it, &Iso2::new(translation * t, na::zero())));
+    }
+}
+
+#[test]
+fn test_iso3_weighted_average() {
+    for _ in range(0u, 10000) {
+        let iso1: Iso3<f64> = random();
+        let iso2: Iso3<f64> = random();
+        let p: Pnt3<f64>    = random();
+
+        // The average of two isometries with the same weight is their midpoint.
+        let avg = Iso3::weighted_average(&[iso1, iso2], &[1.0, 1.0]);
+
+        assert!(na::approx_eq(&avg, &iso1.lerp_slerp(&iso2, 0.5)));
+
+        // Zero weights are ignored.
+        let avg = Iso3::weighted_average(&[iso1, iso2], &[0.0, 2.0]);
+
+        assert!(na::approx_eq(&(avg * p), &(iso2 * p)));
+    }
+}
+
+#[test]
+fn test_iso3_weighted_average_symmetric() {
+    for _ in range(0u, 10000) {
+        let center: Rot3<f64> = random();
+        let delta: Vec3<f64>  = random();
+        let delta             = delta * 0.5;
+
+        // Rotations placed symmetrically around `center` average to `center`.
+        let iso1 = Iso3::new_with_rotmat(na::zero(), center * Rot3::new(delta));
+        let iso2 = Iso3::new_with_rotmat(na::zero(), center * Rot3::new(-delta));
+        let avg  = Iso3::weighted_average(&[iso1, iso2], &[0.3, 0.3]);
+
+        assert!(na::approx_eq(&avg.rotation, &center));
+    }
+}
+
+#[test]
+fn test_iso2_weighted_average() {
+    for _ in range(0u, 10000) {
+        let a1: Vec1<f64> = random();
+        let a2: Vec1<f64> = random();
+        let a3: Vec1<f64> = random();
+        let t1: Vec2<f64> = random();
+        let t2: Vec2<f64> = random();
+        let t3: Vec2<f64> = random();
+
+        let avg = Iso2::weighted_average(&[Iso2::new(t1, a1), Iso2::new(t2, a2), Iso2::new(t3, a3)],
+                                         &[1.0, 2.0, 1.0]);
+
+        assert!(na::approx_eq(&avg.translation(), &((t1 + t2 * 2.0 + t3) / 4.0)));
+        assert!(na::approx_eq(&avg.rotation(), &((a1 + a2 * 2.0 + a3) / 4.0)));
+    }
+}
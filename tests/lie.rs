@@ -0,0 +1,153 @@
+#![feature(macro_rules)]
+
+extern crate "nalgebra" as na;
+
+use na::{Vec3, Vec6, Mat3, Mat4, Mat6, Rot3, UnitQuat, Iso3};
+use std::rand::random;
+
+#[test]
+fn test_rot3_exp_log() {
+    for _ in range(0u, 10000) {
+        let phi: Vec3<f64> = random();
+        let rot: Rot3<f64> = random();
+
+        assert!(na::approx_eq(&Rot3::exp(&phi).log(), &phi));
+        assert!(na::approx_eq(&UnitQuat::exp(&phi).log(), &phi));
+        assert!(na::approx_eq(&Rot3::exp(&rot.log()), &rot));
+    }
+}
+
+#[test]
+fn test_iso3_exp_log() {
+    for _ in range(0u, 10000) {
+        let xi: Vec6<f64>  = random();
+        let iso: Iso3<f64> = random();
+
+        assert!(na::approx_eq(&Iso3::exp(&xi).log(), &xi));
+        assert!(na::approx_eq(&Iso3::exp(&iso.log()), &iso));
+    }
+}
+
+#[test]
+fn test_iso3_exp_pure_translation() {
+    for _ in range(0u, 10000) {
+        let t: Vec3<f64> = random();
+        let xi = Vec6::new(t.x, t.y, t.z, 0.0, 0.0, 0.0);
+
+        assert!(na::approx_eq(&Iso3::exp(&xi), &Iso3::new(t, na::zero())));
+    }
+}
+
+#[test]
+fn test_hat_vee() {
+    for _ in range(0u, 10000) {
+        let v: Vec3<f64>  = random();
+        let xi: Vec6<f64> = random();
+
+        let hat_v: Mat3<f64>  = na::cross_matrix(&v);
+        let hat_xi: Mat4<f64> = na::cross_matrix(&xi);
+
+        assert!(na::approx_eq(&na::vee(&hat_v), &v));
+        assert!(na::approx_eq(&na::vee(&hat_xi), &xi));
+    }
+}
+
+#[test]
+fn test_rot3_adjoint() {
+    for _ in range(0u, 10000) {
+        let rot: Rot3<f64> = random();
+        let phi: Vec3<f64> = random();
+
+        let conj = rot * Rot3::exp(&phi) * na::inv(&rot).unwrap();
+
+        assert!(na::approx_eq(&conj, &Rot3::exp(&(rot.adjoint() * phi))));
+    }
+}
+
+#[test]
+fn test_iso3_adjoint() {
+    for _ in range(0u, 10000) {
+        let iso: Iso3<f64> = random();
+        let xi: Vec6<f64>  = random();
+
+        let conj = iso * Iso3::exp(&xi) * na::inv(&iso).unwrap();
+
+        assert!(na::approx_eq(&conj, &Iso3::exp(&(iso.adjoint() * xi))));
+        assert!(na::approx_eq(&(iso.adjoint() * na::inv(&iso).unwrap().adjoint()), &na::one()));
+    }
+}
+
+#[test]
+fn test_rot3_jacobians() {
+    for _ in range(0u, 10000) {
+        let phi: Vec3<f64> = random();
+        let d: Vec3<f64>   = random();
+        let d              = d * 1.0e-6;
+
+        let jl = Rot3::left_jacobian(&phi);
+        let jr = Rot3::right_jacobian(&phi);
+
+        assert!(na::approx_eq(&(jl * Rot3::inv_left_jacobian(&phi)), &na::one()));
+        assert!(na::approx_eq(&(jr * Rot3::inv_right_jacobian(&phi)), &na::one()));
+
+        // First-order approximations.
+        let exp = Rot3::exp(&(phi + d));
+
+        assert!(na::approx_eq_eps(&exp, &(Rot3::exp(&(jl * d)) * Rot3::exp(&phi)), &1.0e-9));
+        assert!(na::approx_eq_eps(&exp, &(Rot3::exp(&phi) * Rot3::exp(&(jr * d))), &1.0e-9));
+    }
+}
+
+#[test]
+fn test_rot3_jacobians_small_angle() {
+    for _ in range(0u, 10000) {
+        let phi: Vec3<f64> = random();
+        let phi            = phi * 1.0e-5;
+        let hat: Mat3<f64> = na::cross_matrix(&phi);
+        let id: Mat3<f64>  = na::one();
+
+        assert!(na::approx_eq(&Rot3::left_jacobian(&phi), &(id + hat * 0.5)));
+        assert!(na::approx_eq(&Rot3::inv_left_jacobian(&phi), &(id - hat * 0.5)));
+        assert!(na::approx_eq(&Rot3::left_jacobian(&na::zero()), &id));
+        assert!(na::approx_eq(&Rot3::inv_left_jacobian(&na::zero()), &id));
+    }
+}
+
+#[test]
+fn test_iso3_jacobians() {
+    for _ in range(0u, 10000) {
+        let xi: Vec6<f64> = random();
+        let d: Vec6<f64>  = random();
+        let d             = d * 1.0e-6;
+
+        let jl = Iso3::left_jacobian(&xi);
+        let jr = Iso3::right_jacobian(&xi);
+
+        assert!(na::approx_eq(&(jl * Iso3::inv_left_jacobian(&xi)), &na::one::<Mat6<f64>>()));
+        assert!(na::approx_eq(&(jr * Iso3::inv_right_jacobian(&xi)), &na::one::<Mat6<f64>>()));
+
+        // First-order approximations.
+        let exp = Iso3::exp(&(xi + d));
+
+        assert!(na::approx_eq_eps(&exp, &(Iso3::exp(&(jl * d)) * Iso3::exp(&xi)), &1.0e-9));
+        assert!(na::approx_eq_eps(&exp, &(Iso3::exp(&xi) * Iso3::exp(&(jr * d))), &1.0e-9));
+    }
+}
+
+#[test]
+fn test_iso3_jacobians_small_angle() {
+    for _ in range(0u, 10000) {
+        let rho: Vec3<f64> = random();
+        let phi: Vec3<f64> = random();
+        let phi            = phi * 1.0e-5;
+        let xi             = Vec6::new(rho.x, rho.y, rho.z, phi.x, phi.y, phi.z);
+        let d: Vec6<f64>   = random();
+        let d              = d * 1.0e-6;
+
+        let jl  = Iso3::left_jacobian(&xi);
+        let exp = Iso3::exp(&(xi + d));
+
+        assert!(na::approx_eq(&(jl * Iso3::inv_left_jacobian(&xi)), &na::one::<Mat6<f64>>()));
+        assert!(na::approx_eq_eps(&exp, &(Iso3::exp(&(jl * d)) * Iso3::exp(&xi)), &1.0e-9));
+    }
+}
@@ -2,10 +2,32 @@
 
 extern crate "nalgebra" as na;
 
-use std::rand::random;
+use std::rand::{random, XorShiftRng, SeedableRng};
 use std::cmp::{min, max};
-use na::{Vec1, Vec3, Mat1, Mat2, Mat3, Mat4, Mat5, Mat6, Rot3, Persp3, PerspMat3, Ortho3, OrthoMat3,
-         DMat, DVec, Row, Col, BaseFloat};
+use na::{Vec1, Vec2, Vec3, Vec4, Pnt3, Mat1, Mat2, Mat3, Mat4, Mat5, Mat6, Mat2x3, Mat2x4, Mat3x2,
+         Mat3x4, Mat4x2, Mat4x3, Rot2, Rot3, Rot4, UnitQuat, Persp3, PerspMat3, Ortho3, OrthoMat3,
+         DMat, DVec, Row, Col, Diag, BaseFloat};
+
+macro_rules! test_dmat_conversion_impl(
+  ($t: ident, $nrows: expr, $ncols: expr) => (
+    for _ in range(0u, 10000) {
+      let m: $t<f64> = random();
+      let dm         = m.to_dmat();
+
+      assert!(dm.nrows() == $nrows && dm.ncols() == $ncols);
+
+      for i in range(0u, $nrows) {
+          for j in range(0u, $ncols) {
+              assert!(dm[(i, j)] == m[(i, j)]);
+          }
+      }
+
+      assert!($t::from_dmat(&dm) == Some(m));
+      assert!($t::<f64>::from_dmat(&DMat::new_zeros($nrows + 1, $ncols)).is_none());
+      assert!($t::<f64>::from_dmat(&DMat::new_zeros($nrows, $ncols + 1)).is_none());
+    }
+  )
+)
 
 macro_rules! test_inv_mat_impl(
   ($t: ty) => (
@@ -66,6 +88,25 @@ macro_rules! test_qr_impl(
 //     }
 // )
 
+macro_rules! test_eigen_sym_impl(
+  ($t: ty) => (
+    for _ in range(0u, 10000) {
+      let randmat : $t = random();
+      // Make it symetric so that we can recompose the matrix to test at the end.
+      let randmat = na::transpose(&randmat) * randmat;
+
+      let (eigenvectors, eigenvalues) = na::eigen_sym(&randmat, &1.0e-12, 50);
+
+      let diag: $t = Diag::from_diag(&eigenvalues);
+
+      let recomp = eigenvectors * diag * na::transpose(&eigenvectors);
+
+      assert!(na::approx_eq(&(na::transpose(&eigenvectors) * eigenvectors), &na::one()));
+      assert!(na::approx_eq(&randmat,  &recomp));
+    }
+  );
+)
+
 #[test]
 fn test_transpose_mat1() {
     test_transpose_mat_impl!(Mat1<f64>);
@@ -155,6 +196,306 @@ fn test_inv_rotation3() {
     }
 }
 
+#[test]
+fn test_rotation3_log_round_trip() {
+    for _ in range(0u, 10000) {
+        let dir: Vec3<f64> = random::<Vec3<f64>>() - 0.5;
+        let ang            = na::normalize(&dir) * (na::abs(&random::<f64>()) % BaseFloat::pi());
+        let rot            = Rot3::new(ang);
+
+        assert!(na::approx_eq(&na::rotation(&rot), &ang));
+    }
+}
+
+#[test]
+fn test_rotation3_log_identity() {
+    let rot: Rot3<f64> = na::one();
+
+    assert!(na::rotation(&rot) == na::zero());
+}
+
+#[test]
+fn test_rotation3_log_small_angles() {
+    let mut angle = 1.0e-2f64;
+
+    while angle > 1.0e-12 {
+        for _ in range(0u, 1000) {
+            let dir: Vec3<f64> = random::<Vec3<f64>>() - 0.5;
+            let ang            = na::normalize(&dir) * angle;
+            let rot            = Rot3::new(ang);
+
+            assert!(na::approx_eq_eps(&na::rotation(&rot), &ang, &1.0e-10));
+        }
+
+        angle = angle / 10.0;
+    }
+}
+
+#[test]
+fn test_rotation3_log_near_pi() {
+    let pi: f64   = BaseFloat::pi();
+    let mut delta = 1.0e-1f64;
+
+    while delta > 1.0e-12 {
+        for _ in range(0u, 1000) {
+            let dir: Vec3<f64> = random::<Vec3<f64>>() - 0.5;
+            let ang            = na::normalize(&dir) * (pi - delta);
+            let rot            = Rot3::new(ang);
+            let log            = na::rotation(&rot);
+
+            assert!(na::approx_eq(&log, &ang));
+        }
+
+        delta = delta / 10.0;
+    }
+}
+
+#[test]
+fn test_rotation3_log_pi() {
+    let pi: f64 = BaseFloat::pi();
+
+    for _ in range(0u, 10000) {
+        let dir: Vec3<f64> = random::<Vec3<f64>>() - 0.5;
+        let ang            = na::normalize(&dir) * pi;
+        let rot            = Rot3::new(ang);
+        let log            = na::rotation(&rot);
+
+        // Both `ang` and `-ang` are valid results for a rotation of `pi`.
+        assert!(na::approx_eq(&na::norm(&log), &pi));
+        assert!(na::approx_eq(&log, &ang) || na::approx_eq(&log, &-ang));
+        assert!(na::approx_eq(&Rot3::new(log), &rot));
+    }
+}
+
+#[test]
+fn test_rotation3_log_pi_canonical_axes() {
+    let pi: f64 = BaseFloat::pi();
+
+    na::canonical_basis(|e: Vec3<f64>| {
+        let rot = Rot3::new(e * pi);
+        let log = na::rotation(&rot);
+
+        assert!(na::approx_eq(&log, &(e * pi)) || na::approx_eq(&log, &(e * -pi)));
+
+        true
+    })
+}
+
+#[test]
+fn test_rotation3_log_is_inverse_of_exp() {
+    for _ in range(0u, 10000) {
+        let ang: Vec3<f64> = (random::<Vec3<f64>>() - 0.5) * 10.0;
+        let rot            = Rot3::new(ang);
+
+        assert!(na::approx_eq(&Rot3::new(na::rotation(&rot)), &rot));
+    }
+}
+
+#[test]
+fn test_rotation_between_rot2() {
+    for _ in range(0u, 10000) {
+        let a: Vec2<f64> = random::<Vec2<f64>>() - 0.5;
+        let b: Vec2<f64> = random::<Vec2<f64>>() - 0.5;
+        let rot          = Rot2::rotation_between(&a, &b).unwrap();
+
+        assert!(na::approx_eq(&(rot * na::normalize(&a)), &na::normalize(&b)));
+    }
+
+    assert!(Rot2::rotation_between(&na::zero(), &Vec2::new(1.0f64, 0.0)).is_none());
+}
+
+#[test]
+fn test_rotation_between_rot3() {
+    for _ in range(0u, 10000) {
+        let a: Vec3<f64> = random::<Vec3<f64>>() - 0.5;
+        let b: Vec3<f64> = random::<Vec3<f64>>() - 0.5;
+        let rot          = Rot3::rotation_between(&a, &b).unwrap();
+
+        assert!(na::approx_eq(&(rot * na::normalize(&a)), &na::normalize(&b)));
+        // The rotation axis is orthogonal to both vectors.
+        assert!(na::approx_eq(&na::dot(&na::rotation(&rot), &a), &0.0));
+        assert!(na::approx_eq(&na::dot(&na::rotation(&rot), &b), &0.0));
+    }
+
+    assert!(Rot3::rotation_between(&na::zero(), &Vec3::new(1.0f64, 0.0, 0.0)).is_none());
+}
+
+#[test]
+fn test_rotation_between_rot3_opposite() {
+    for _ in range(0u, 10000) {
+        let a: Vec3<f64> = random::<Vec3<f64>>() - 0.5;
+        let b            = a * -2.0;
+        let rot          = Rot3::rotation_between(&a, &b).unwrap();
+
+        assert!(na::approx_eq(&(rot * na::normalize(&a)), &na::normalize(&b)));
+        assert!(na::approx_eq(&(na::transpose(&rot) * rot), &na::one()));
+    }
+}
+
+#[test]
+fn test_rot_new_with_basis() {
+    for _ in range(0u, 10000) {
+        let ang: Vec3<f64> = random();
+        let rot            = Rot3::new(ang);
+
+        let basis = Rot3::new_with_basis(rot.col(0), rot.col(1), rot.col(2));
+        assert!(na::approx_eq(&basis.unwrap(), &rot));
+
+        // Indirect basis.
+        assert!(Rot3::new_with_basis(rot.col(1), rot.col(0), rot.col(2)).is_none());
+        // Non-orthonormal basis.
+        assert!(Rot3::new_with_basis(rot.col(0) * 2.0, rot.col(1), rot.col(2)).is_none());
+
+        let ang: Vec1<f64> = random();
+        let rot            = Rot2::new(ang);
+
+        assert!(na::approx_eq(&Rot2::new_with_basis(rot.col(0), rot.col(1)).unwrap(), &rot));
+        assert!(Rot2::new_with_basis(rot.col(1), rot.col(0)).is_none());
+    }
+}
+
+#[test]
+fn test_rot_renormalize() {
+    for _ in range(0u, 10000) {
+        let ang: Vec3<f64>   = random();
+        let rot              = Rot3::new(ang);
+        let noise: Mat3<f64> = (random::<Mat3<f64>>() - 0.5) * 1.0e-4;
+        let mut drifted      = unsafe { Rot3::new_with_mat(*rot.submat() + noise) };
+
+        drifted.renormalize();
+
+        assert!(na::approx_eq(&(na::transpose(&drifted) * drifted), &na::one()));
+        assert!(na::approx_eq(&na::det(drifted.submat()), &1.0));
+        assert!(na::approx_eq_eps(&drifted, &rot, &1.0e-3));
+    }
+}
+
+#[test]
+fn test_rot2_renormalize() {
+    for _ in range(0u, 100) {
+        let ang: Vec1<f32> = random();
+        let delta          = Rot2::new(ang);
+        let mut acc: Rot2<f32> = na::one();
+
+        for _ in range(0u, 1000) {
+            acc = acc * delta;
+        }
+
+        acc.renormalize();
+
+        assert!(na::approx_eq_eps(&(na::transpose(&acc) * acc), &na::one(), &1.0e-6));
+        assert!(na::approx_eq_eps(&na::det(acc.submat()), &1.0, &1.0e-6));
+    }
+}
+
+#[test]
+fn test_mat4_trs() {
+    for _ in range(0u, 10000) {
+        let t: Vec3<f64> = random();
+        let r: Rot3<f64> = random();
+        let s: Vec3<f64> = random();
+        let p: Pnt3<f64> = random();
+
+        let m        = Mat4::new_with_trs(t, r, s);
+        let expected = r * Pnt3::new(p.x * s.x, p.y * s.y, p.z * s.z) + t;
+
+        assert!(na::approx_eq(&na::from_homogeneous(&(m * na::to_homogeneous(&p))), &expected));
+        assert!(na::approx_eq(&Mat4::new_with_trs_quat(t, UnitQuat::new_with_rot(r), s), &m));
+    }
+}
+
+#[test]
+fn test_mat4_trs_decompose() {
+    for _ in range(0u, 10000) {
+        let t: Vec3<f64> = random();
+        let r: Rot3<f64> = random();
+        let s: Vec3<f64> = random();
+        let s            = s + Vec3::new(0.5, 0.5, 0.5);
+
+        let (dt, dr, ds) = Mat4::new_with_trs(t, r, s).decompose().unwrap();
+
+        assert!(na::approx_eq(&dt, &t));
+        assert!(na::approx_eq(&dr, &r));
+        assert!(na::approx_eq(&ds, &s));
+    }
+}
+
+#[test]
+fn test_mat4_trs_decompose_tiny_scale() {
+    for _ in range(0u, 1000) {
+        let t: Vec3<f64> = random();
+        let r: Rot3<f64> = random();
+        let s            = Vec3::new(1.0e-7, 2.0e-7, 3.0e-7);
+
+        let (dt, dr, ds) = Mat4::new_with_trs(t, r, s).decompose().unwrap();
+
+        assert!(na::approx_eq(&dt, &t));
+        assert!(na::approx_eq(&dr, &r));
+        assert!(na::approx_eq(&(ds * 1.0e7), &(s * 1.0e7)));
+    }
+}
+
+#[test]
+fn test_mat4_trs_decompose_negative_scale() {
+    for _ in range(0u, 10000) {
+        let t: Vec3<f64> = random();
+        let r: Rot3<f64> = random();
+        let s: Vec3<f64> = random();
+        let s            = s + Vec3::new(0.5, 0.5, 0.5);
+        let flipped      = Vec3::new(s.x, -s.y, s.z);
+
+        let m            = Mat4::new_with_trs(t, r, flipped);
+        let (dt, dr, ds) = m.decompose().unwrap();
+
+        assert!(ds.x < 0.0);
+        assert!(na::approx_eq(&na::det(dr.submat()), &1.0));
+        assert!(na::approx_eq(&dt, &t));
+        assert!(na::approx_eq(&Mat4::new_with_trs(dt, dr, ds), &m));
+    }
+}
+
+#[test]
+fn test_mat4_trs_decompose_failures() {
+    let shear = Mat4::new(1.0f64, 1.0, 0.0, 0.0,
+                          0.0,    1.0, 0.0, 0.0,
+                          0.0,    0.0, 1.0, 0.0,
+                          0.0,    0.0, 0.0, 1.0);
+    let proj  = Mat4::new(1.0f64, 0.0, 0.0, 0.0,
+                          0.0,    1.0, 0.0, 0.0,
+                          0.0,    0.0, 1.0, 0.0,
+                          0.0,    0.0, 1.0, 0.0);
+    let flat  = Mat4::new(1.0f64, 0.0, 0.0, 0.0,
+                          0.0,    1.0, 0.0, 0.0,
+                          0.0,    0.0, 0.0, 0.0,
+                          0.0,    0.0, 0.0, 1.0);
+
+    assert!(shear.decompose().is_none());
+    assert!(proj.decompose().is_none());
+    assert!(flat.decompose().is_none());
+}
+
+#[test]
+fn test_mat4_normal_matrix() {
+    for _ in range(0u, 10000) {
+        let t: Vec3<f64> = random();
+        let r: Rot3<f64> = random();
+        let s: Vec3<f64> = random();
+        let s            = s + Vec3::new(0.5, 0.5, 0.5);
+        let a: Vec3<f64> = random();
+        let b: Vec3<f64> = random();
+
+        let m      = Mat4::new_with_trs(t, r, s);
+        let linear = na::from_homogeneous::<Mat4<f64>, Mat3<f64>>(&m);
+        let normal = m.normal_matrix().unwrap();
+
+        // normals stay orthogonal to the transformed tangent vectors
+        let n = na::cross(&a, &b);
+
+        assert!(na::approx_eq(&na::dot(&(normal * n), &(linear * a)), &0.0));
+        assert!(na::approx_eq(&na::dot(&(normal * n), &(linear * b)), &0.0));
+    }
+}
+
 #[test]
 fn test_mean_dmat() {
     let mat = DMat::from_row_vec(
@@ -326,6 +667,36 @@ fn test_qr_mat6() {
 //     test_eigen_qr_impl!(Mat6<f64>);
 // }
 
+#[test]
+fn test_eigen_sym_mat1() {
+    test_eigen_sym_impl!(Mat1<f64>);
+}
+
+#[test]
+fn test_eigen_sym_mat2() {
+    test_eigen_sym_impl!(Mat2<f64>);
+}
+
+#[test]
+fn test_eigen_sym_mat3() {
+    test_eigen_sym_impl!(Mat3<f64>);
+}
+
+#[test]
+fn test_eigen_sym_mat4() {
+    test_eigen_sym_impl!(Mat4<f64>);
+}
+
+#[test]
+fn test_eigen_sym_mat5() {
+    test_eigen_sym_impl!(Mat5<f64>);
+}
+
+#[test]
+fn test_eigen_sym_mat6() {
+    test_eigen_sym_impl!(Mat6<f64>);
+}
+
 #[test]
 fn test_from_fn() {
     let actual: DMat<uint> = DMat::from_fn(3, 4, |i, j| 10 * i + j);
@@ -428,3 +799,165 @@ fn test_ortho() {
     assert!(na::approx_eq(&pm.znear(),  &24.0));
     assert!(na::approx_eq(&pm.zfar(),   &61.0));
 }
+
+#[test]
+fn test_dmat_random_orthogonal() {
+    let mut rng: XorShiftRng = SeedableRng::from_seed([1, 2, 3, 4]);
+
+    for dim in range(1u, 10) {
+        let q: DMat<f64>  = DMat::new_random_orthogonal(dim, &mut rng);
+        let id: DMat<f64> = na::new_identity(dim);
+
+        assert!(na::approx_eq(&(na::transpose(&q) * q), &id));
+        assert!(na::approx_eq(&(q * na::transpose(&q)), &id));
+    }
+}
+
+#[test]
+fn test_dmat_random_spd() {
+    let mut rng: XorShiftRng = SeedableRng::from_seed([1, 2, 3, 4]);
+
+    for dim in range(1u, 10) {
+        let m: DMat<f64> = DMat::new_random_spd(dim, 0.5, 2.0, &mut rng);
+
+        assert!(na::approx_eq(&na::transpose(&m), &m));
+
+        // The Rayleigh quotient lies between the smallest and the largest eigenvalue.
+        for _ in range(0u, 100) {
+            let x: DVec<f64> = DVec::new_random_unit(dim, &mut rng);
+            let r            = na::dot(&x, &(m * x));
+
+            assert!(r >= 0.5 - 1.0e-10 && r <= 2.0 + 1.0e-10);
+        }
+    }
+}
+
+#[test]
+fn test_rect_mat_layout() {
+    let m = Mat2x3::new(1.0f64, 2.0, 3.0,
+                        4.0,    5.0, 6.0);
+
+    assert!(m[(0, 2)] == 3.0 && m[(1, 0)] == 4.0);
+    assert!(m.row(1) == Vec3::new(4.0, 5.0, 6.0));
+    assert!(m.col(2) == Vec2::new(3.0, 6.0));
+    assert!(na::shape(&m) == (2, 3));
+    assert!(m.transpose() == Mat3x2::new(1.0, 4.0,
+                                         2.0, 5.0,
+                                         3.0, 6.0));
+    assert!(m.to_dmat() == DMat::from_row_vec(2, 3, &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]));
+}
+
+#[test]
+fn test_rect_mat_mul() {
+    for _ in range(0u, 10000) {
+        let a: Mat3x4<f64> = random();
+        let b: Mat4x2<f64> = random();
+        let c: Mat2x3<f64> = random();
+        let v: Vec4<f64>   = random();
+        let w: Vec3<f64>   = random();
+
+        let ab: Mat3x2<f64> = a * b;
+        let abc: Mat3<f64>  = ab * c;
+        let bc: Mat4x3<f64> = b * c;
+        let ca: Mat2x4<f64> = c * a;
+        let av: Vec3<f64>   = a * v;
+        let wa: Vec4<f64>   = w * a;
+
+        assert!(na::approx_eq(&ab.to_dmat(), &(a.to_dmat() * b.to_dmat())));
+        assert!(na::approx_eq(&abc, &(a * bc)));
+        assert!(na::approx_eq(&ca.to_dmat(), &(c.to_dmat() * a.to_dmat())));
+        assert!(na::approx_eq(&ab.transpose(), &(b.transpose() * a.transpose())));
+        assert!(na::approx_eq(&na::dot(&w, &av), &na::dot(&wa, &v)));
+    }
+}
+
+#[test]
+fn test_dmat_conversion_mat1() {
+    test_dmat_conversion_impl!(Mat1, 1, 1);
+}
+
+#[test]
+fn test_dmat_conversion_mat2() {
+    test_dmat_conversion_impl!(Mat2, 2, 2);
+}
+
+#[test]
+fn test_dmat_conversion_mat3() {
+    test_dmat_conversion_impl!(Mat3, 3, 3);
+}
+
+#[test]
+fn test_dmat_conversion_mat4() {
+    test_dmat_conversion_impl!(Mat4, 4, 4);
+}
+
+#[test]
+fn test_dmat_conversion_mat5() {
+    test_dmat_conversion_impl!(Mat5, 5, 5);
+}
+
+#[test]
+fn test_dmat_conversion_mat6() {
+    test_dmat_conversion_impl!(Mat6, 6, 6);
+}
+
+#[test]
+fn test_dmat_conversion_mat3x4() {
+    test_dmat_conversion_impl!(Mat3x4, 3, 4);
+}
+
+#[test]
+fn test_dmat_conversion_rot3() {
+    for _ in range(0u, 10000) {
+        let r: Rot3<f64> = random();
+
+        assert!(Mat3::from_dmat(&r.to_dmat()) == Some(*r.submat()));
+    }
+}
+
+#[test]
+fn test_rot_from_dmat() {
+    for _ in range(0u, 10000) {
+        let r2: Rot2<f64> = random();
+        let r3: Rot3<f64> = random();
+
+        assert!(Rot2::from_dmat(&r2.to_dmat()) == Some(r2));
+        assert!(Rot3::from_dmat(&r3.to_dmat()) == Some(r3));
+    }
+
+    // wrong shape
+    assert!(Rot2::<f64>::from_dmat(&na::new_identity(3)).is_none());
+    assert!(Rot3::<f64>::from_dmat(&DMat::new_zeros(3, 4)).is_none());
+
+    // not orthonormal
+    assert!(Rot3::<f64>::from_dmat(&(na::new_identity::<DMat<f64>>(3) * 2.0f64)).is_none());
+    assert!(Rot2::from_dmat(&DMat::from_row_vec(2, 2, &[1.0f64, 1.0, 0.0, 1.0])).is_none());
+
+    // reflections
+    let mut refl3: DMat<f64> = na::new_identity(3);
+    let mut refl4: DMat<f64> = na::new_identity(4);
+    refl3[(2, 2)] = -1.0;
+    refl4[(0, 0)] = -1.0;
+
+    assert!(Rot3::from_dmat(&refl3).is_none());
+    assert!(Rot4::from_dmat(&refl4).is_none());
+    assert!(Rot4::from_dmat(&na::new_identity(4)) == Some(na::one::<Rot4<f64>>()));
+}
+
+#[test]
+fn test_det_mat4() {
+    let m = Mat4::new(2.0f64, 0.0, 0.0, 0.0,
+                      0.0,    3.0, 1.0, 0.0,
+                      0.0,    0.0, 4.0, 0.0,
+                      1.0,    0.0, 0.0, 5.0);
+
+    assert!(na::det(&m) == 120.0);
+
+    for _ in range(0u, 10000) {
+        let a: Mat4<f64> = random();
+        let b: Mat4<f64> = random();
+
+        assert!(na::approx_eq(&na::det(&(a * b)), &(na::det(&a) * na::det(&b))));
+        assert!(na::approx_eq(&na::det(&na::transpose(&a)), &na::det(&a)));
+    }
+}
@@ -0,0 +1,136 @@
+extern crate "nalgebra" as na;
+
+use std::num::Float;
+use std::rand::random;
+use na::{Pnt2, Pnt3, Vec2};
+
+fn sign(x: f64) -> int {
+    if x > 0.0 { 1 } else if x < 0.0 { -1 } else { 0 }
+}
+
+#[test]
+fn test_orient2d_near_collinear() {
+    // The points `(0.5 + i * ulp, 0.5 + j * ulp)` are nearly collinear with `b` and `c`. Naive
+    // floating-point evaluation gives wrong signs for many of them.
+    let ulp = 2.0f64.powi(-53);
+    let b   = Pnt2::new(12.0f64, 12.0);
+    let c   = Pnt2::new(24.0f64, 24.0);
+
+    for i in range(0i, 64) {
+        for j in range(0i, 64) {
+            let p = Pnt2::new(0.5 + i as f64 * ulp, 0.5 + j as f64 * ulp);
+
+            assert!(sign(na::orient2d(&p, &b, &c)) == sign((j - i) as f64));
+            assert!(sign(na::orient2d(&b, &p, &c)) == -sign((j - i) as f64));
+        }
+    }
+}
+
+#[test]
+fn test_orient2d_random() {
+    for _ in range(0u, 10000) {
+        let a: Pnt2<f64> = random();
+        let b: Pnt2<f64> = random();
+        let c: Pnt2<f64> = random();
+
+        let naive = (a.x - c.x) * (b.y - c.y) - (a.y - c.y) * (b.x - c.x);
+        let det   = na::orient2d(&a, &b, &c);
+
+        assert!(sign(det) == -sign(na::orient2d(&b, &a, &c)));
+        assert!(sign(det) == sign(na::orient2d(&b, &c, &a)));
+
+        if naive.abs() > 1.0e-10 {
+            assert!(det == naive);
+        }
+    }
+
+    let a = Pnt2::new(1.0f64, 1.0);
+    assert!(na::orient2d(&a, &Pnt2::new(2.0, 3.0), &Pnt2::new(3.0, 5.0)) == 0.0);
+    assert!(na::orient2d(&a, &a, &Pnt2::new(3.0, 5.0)) == 0.0);
+    assert!(na::orient2d(&na::orig(), &Pnt2::new(1.0, 0.0), &Pnt2::new(0.0, 1.0)) > 0.0);
+}
+
+#[test]
+fn test_orient3d_near_coplanar() {
+    // The point `p` is nearly on the plane `x = y` passing through `a`, `b` and `c`.
+    let ulp = 2.0f64.powi(-53);
+    let a   = Pnt3::new(12.0f64, 12.0, 0.0);
+    let b   = Pnt3::new(24.0f64, 24.0, 0.0);
+    let c   = Pnt3::new(12.0f64, 12.0, 1.0);
+
+    for i in range(0i, 64) {
+        for j in range(0i, 64) {
+            let p = Pnt3::new(0.5 + i as f64 * ulp, 0.5 + j as f64 * ulp, 0.5);
+
+            assert!(sign(na::orient3d(&a, &b, &c, &p)) == sign((j - i) as f64));
+            assert!(sign(na::orient3d(&b, &a, &c, &p)) == -sign((j - i) as f64));
+            assert!(sign(na::orient3d(&p, &a, &b, &c)) == -sign((j - i) as f64));
+        }
+    }
+
+    let o = na::orig::<Pnt3<f64>>();
+    let x = Pnt3::new(1.0f64, 0.0, 0.0);
+    let y = Pnt3::new(0.0f64, 1.0, 0.0);
+
+    assert!(na::orient3d(&o, &x, &y, &Pnt3::new(0.0, 0.0, -1.0)) > 0.0);
+    assert!(na::orient3d(&o, &x, &y, &Pnt3::new(0.0, 0.0, 1.0)) < 0.0);
+    assert!(na::orient3d(&o, &x, &y, &Pnt3::new(3.0, -7.0, 0.0)) == 0.0);
+}
+
+#[test]
+fn test_incircle_near_cocircular() {
+    // `(3, 4)` lies exactly on the circle of radius 5 passing through `a`, `b` and `c`.
+    let ulp = 2.0f64.powi(-51);
+    let a   = Pnt2::new(5.0f64, 0.0);
+    let b   = Pnt2::new(0.0f64, 5.0);
+    let c   = Pnt2::new(-5.0f64, 0.0);
+
+    assert!(na::incircle(&a, &b, &c, &Pnt2::new(3.0, 4.0)) == 0.0);
+    assert!(na::incircle(&a, &b, &c, &Pnt2::new(4.0, -3.0)) == 0.0);
+    assert!(na::incircle(&a, &b, &c, &na::orig()) > 0.0);
+    assert!(na::incircle(&a, &c, &b, &na::orig()) < 0.0);
+
+    for i in range(-32i, 32) {
+        let d = Pnt2::new(3.0 - i as f64 * ulp, 4.0);
+
+        assert!(sign(na::incircle(&a, &b, &c, &d)) == sign(i as f64));
+        assert!(sign(na::incircle(&b, &a, &c, &d)) == -sign(i as f64));
+    }
+
+    // Same configuration, far from the origin.
+    let ulp = 2.0f64.powi(-42);
+    let t   = Vec2::new(1024.0f64, 1024.0);
+    let a   = a + t;
+    let b   = b + t;
+    let c   = c + t;
+
+    for i in range(-32i, 32) {
+        let d = Pnt2::new(1027.0 - i as f64 * ulp, 1028.0);
+
+        assert!(sign(na::incircle(&a, &b, &c, &d)) == sign(i as f64));
+        assert!(sign(na::incircle(&c, &a, &b, &d)) == sign(i as f64));
+    }
+}
+
+#[test]
+fn test_insphere_near_cospherical() {
+    // `(1, 2, 2)` lies exactly on the sphere of radius 3 passing through `a`, `b`, `c` and `d`.
+    let ulp = 2.0f64.powi(-52);
+    let a   = Pnt3::new(3.0f64, 0.0, 0.0);
+    let b   = Pnt3::new(0.0f64, 3.0, 0.0);
+    let c   = Pnt3::new(0.0f64, 0.0, 3.0);
+    let d   = Pnt3::new(-3.0f64, 0.0, 0.0);
+
+    assert!(na::orient3d(&a, &b, &c, &d) > 0.0);
+    assert!(na::insphere(&a, &b, &c, &d, &Pnt3::new(1.0, 2.0, 2.0)) == 0.0);
+    assert!(na::insphere(&a, &b, &c, &d, &Pnt3::new(0.0, -3.0, 0.0)) == 0.0);
+    assert!(na::insphere(&a, &b, &c, &d, &na::orig()) > 0.0);
+    assert!(na::insphere(&a, &b, &c, &d, &Pnt3::new(3.0, 3.0, 3.0)) < 0.0);
+
+    for i in range(-32i, 32) {
+        let e = Pnt3::new(1.0 - i as f64 * ulp, 2.0, 2.0);
+
+        assert!(sign(na::insphere(&a, &b, &c, &d, &e)) == sign(i as f64));
+        assert!(sign(na::insphere(&b, &a, &c, &d, &e)) == -sign(i as f64));
+    }
+}
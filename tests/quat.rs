@@ -2,8 +2,8 @@
 
 extern crate "nalgebra" as na;
 
-use na::{Pnt3, Vec3, Rot3, UnitQuat, Rotation};
-use std::rand::random;
+use na::{Pnt3, Vec1, Vec3, Mat2, Mat3, Rot2, Rot3, UnitQuat, Iso3, QuatIso3, Rotation, Col, BaseFloat};
+use std::rand::{random, Rng, XorShiftRng, SeedableRng};
 
 #[test]
 fn test_quat_as_mat() {
@@ -59,6 +59,178 @@ fn test_quat_to_axis_angle() {
     }
 }
 
+#[test]
+fn test_quat_to_axis_angle_negative_scalar() {
+    for _ in range(0u, 10000) {
+        let axis_angle: Vec3<f64> = random();
+
+        // `-q` represents the same rotation as `q`.
+        let q  = UnitQuat::new(axis_angle);
+        let mq = unsafe { UnitQuat::new_with_unit_quat(-*q.quat()) };
+
+        assert!(na::approx_eq(&mq.rotation(), &axis_angle))
+    }
+}
+
+#[test]
+fn test_quat_to_axis_angle_small_angles() {
+    let mut angle = 1.0e-2f64;
+
+    while angle > 1.0e-12 {
+        for _ in range(0u, 1000) {
+            let dir: Vec3<f64> = random::<Vec3<f64>>() - 0.5;
+            let axis_angle     = na::normalize(&dir) * angle;
+            let q              = UnitQuat::new(axis_angle);
+
+            assert!(na::approx_eq_eps(&q.rotation(), &axis_angle, &1.0e-10))
+        }
+
+        angle = angle / 10.0;
+    }
+}
+
+#[test]
+fn test_quat_to_axis_angle_near_pi() {
+    let pi: f64   = BaseFloat::pi();
+    let mut delta = 1.0e-1f64;
+
+    while delta > 1.0e-12 {
+        for _ in range(0u, 1000) {
+            let dir: Vec3<f64> = random::<Vec3<f64>>() - 0.5;
+            let axis_angle     = na::normalize(&dir) * (pi - delta);
+            let q              = UnitQuat::new(axis_angle);
+
+            assert!(na::approx_eq(&q.rotation(), &axis_angle))
+        }
+
+        delta = delta / 10.0;
+    }
+}
+
+#[test]
+fn test_quat_to_axis_angle_pi() {
+    let pi: f64 = BaseFloat::pi();
+
+    for _ in range(0u, 10000) {
+        let dir: Vec3<f64> = random::<Vec3<f64>>() - 0.5;
+        let axis_angle     = na::normalize(&dir) * pi;
+        let q              = UnitQuat::new(axis_angle);
+        let log            = q.rotation();
+
+        // Both `axis_angle` and `-axis_angle` are valid results for a rotation of `pi`.
+        assert!(na::approx_eq(&na::norm(&log), &pi));
+        assert!(na::approx_eq(&log, &axis_angle) || na::approx_eq(&log, &-axis_angle));
+    }
+}
+
+#[test]
+fn test_quat_to_axis_angle_identity() {
+    let q: UnitQuat<f64> = na::one();
+
+    assert!(q.rotation() == na::zero())
+}
+
+#[test]
+fn test_quat_to_axis_angle_large_angles() {
+    for _ in range(0u, 10000) {
+        let axis_angle: Vec3<f64> = (random::<Vec3<f64>>() - 0.5) * 10.0;
+        let q                     = UnitQuat::new(axis_angle);
+
+        // The recovered rotation may differ from `axis_angle` by a multiple of `2 * pi`.
+        assert!(na::approx_eq(&UnitQuat::new(q.rotation()).to_rot(), &q.to_rot()));
+        assert!(na::norm(&q.rotation()) <= BaseFloat::pi() + 1.0e-6)
+    }
+}
+
+#[test]
+fn test_quat_and_rot3_log_agree() {
+    for _ in range(0u, 10000) {
+        let axis_angle: Vec3<f64> = (random::<Vec3<f64>>() - 0.5) * 6.0;
+        let q                     = UnitQuat::new(axis_angle);
+        let r                     = Rot3::new(axis_angle);
+
+        assert!(na::approx_eq(&UnitQuat::new(r.rotation()).to_rot(), &r));
+        assert!(na::approx_eq(&Rot3::new(q.rotation()), &r));
+    }
+}
+
+#[test]
+fn test_quat_rotation_between() {
+    for _ in range(0u, 10000) {
+        let a: Vec3<f64> = random::<Vec3<f64>>() - 0.5;
+        let b: Vec3<f64> = random::<Vec3<f64>>() - 0.5;
+        let q            = UnitQuat::rotation_between(&a, &b).unwrap();
+
+        assert!(na::approx_eq(&(q * na::normalize(&a)), &na::normalize(&b)));
+        assert!(na::approx_eq(&q.to_rot(), &Rot3::rotation_between(&a, &b).unwrap()));
+    }
+}
+
+#[test]
+fn test_quat_rotation_between_opposite() {
+    for _ in range(0u, 10000) {
+        let a: Vec3<f64> = random::<Vec3<f64>>() - 0.5;
+        let b            = -a;
+        let q            = UnitQuat::rotation_between(&a, &b).unwrap();
+
+        assert!(na::approx_eq(&(q * na::normalize(&a)), &na::normalize(&b)));
+        assert!(na::approx_eq(&na::norm(q.quat()), &1.0));
+    }
+}
+
+#[test]
+fn test_quat_rotation_between_same() {
+    for _ in range(0u, 10000) {
+        let a: Vec3<f64> = random::<Vec3<f64>>() - 0.5;
+        let q            = UnitQuat::rotation_between(&a, &(a * 3.0)).unwrap();
+
+        assert!(na::approx_eq(&q, &na::one()));
+    }
+
+    assert!(UnitQuat::rotation_between(&Vec3::new(1.0f64, 0.0, 0.0), &na::zero()).is_none());
+}
+
+#[test]
+fn test_quat_new_with_rot() {
+    for _ in range(0u, 10000) {
+        let axis_angle: Vec3<f64> = (random::<Vec3<f64>>() - 0.5) * 6.0;
+        let r                     = Rot3::new(axis_angle);
+        let q                     = UnitQuat::new_with_rot(r);
+
+        assert!(na::approx_eq(&q.to_rot(), &r));
+        assert!(na::approx_eq(&q.to_rot(), &UnitQuat::new(axis_angle).to_rot()));
+    }
+}
+
+#[test]
+fn test_quat_new_with_basis() {
+    for _ in range(0u, 10000) {
+        let axis_angle: Vec3<f64> = random();
+        let r                     = Rot3::new(axis_angle);
+        let q                     = UnitQuat::new_with_basis(r.col(0), r.col(1), r.col(2)).unwrap();
+
+        assert!(na::approx_eq(&q.to_rot(), &r));
+        assert!(UnitQuat::new_with_basis(r.col(0), r.col(2), r.col(1)).is_none());
+    }
+}
+
+#[test]
+fn test_quat_renormalize() {
+    for _ in range(0u, 100) {
+        let axis_angle: Vec3<f32>  = random();
+        let delta                  = UnitQuat::new(axis_angle);
+        let mut acc: UnitQuat<f32> = na::one();
+
+        for _ in range(0u, 1000) {
+            acc = acc * delta;
+        }
+
+        acc.renormalize();
+
+        assert!(na::approx_eq(&na::norm(acc.quat()), &1.0));
+    }
+}
+
 #[test]
 fn test_quat_euler_angles() {
     for _ in range(0u, 10000) {
@@ -70,3 +242,217 @@ fn test_quat_euler_angles() {
         assert!(na::approx_eq(&q.to_rot(), &m))
     }
 }
+
+#[test]
+fn test_quat_iso3_as_iso3() {
+    for _ in range(0u, 10000) {
+        let iso1: Iso3<f64> = random();
+        let iso2: Iso3<f64> = random();
+        let pnt: Pnt3<f64>  = random();
+        let vec: Vec3<f64>  = random();
+
+        let qiso1 = QuatIso3::new_with_iso(iso1);
+        let qiso2 = QuatIso3::new_with_iso(iso2);
+
+        assert!(na::approx_eq(&qiso1.to_iso(), &iso1));
+        assert!(na::approx_eq(&(qiso1 * qiso2).to_iso(), &(iso1 * iso2)));
+        assert!(na::approx_eq(&(qiso1 * pnt), &(iso1 * pnt)));
+        assert!(na::approx_eq(&(qiso1 * vec), &(iso1 * vec)));
+        assert!(na::approx_eq(&na::inv(&qiso1).unwrap().to_iso(), &na::inv(&iso1).unwrap()));
+        assert!(na::approx_eq(&na::to_homogeneous(&qiso1), &na::to_homogeneous(&iso1)));
+        assert!(na::approx_eq(&na::rotation(&qiso1), &na::rotation(&iso1)));
+        assert!(na::approx_eq(&na::inv_transform(&qiso1, &na::transform(&qiso1, &pnt)), &pnt));
+    }
+}
+
+#[test]
+fn test_quat_iso3_vec_ignores_translation() {
+    for _ in range(0u, 10000) {
+        let iso: QuatIso3<f64> = random();
+        let vec: Vec3<f64>     = random();
+
+        assert!(na::approx_eq(&(iso * vec), &(iso.rotation * vec)));
+    }
+}
+
+#[test]
+fn test_quat_integrate() {
+    for _ in range(0u, 10000) {
+        let q: UnitQuat<f64>  = random();
+        let angvel: Vec3<f64> = random();
+        let dt: f64           = random();
+
+        let q1 = q.integrate(&angvel, dt);
+
+        assert!(na::approx_eq(&q1, &(UnitQuat::new(angvel * dt) * q)));
+        assert!(na::approx_eq(&q1, &q.integrate(&angvel, dt * 0.5).integrate(&angvel, dt * 0.5)));
+        assert!(na::approx_eq(&q1.to_rot(), &q.to_rot().integrate(&angvel, dt)));
+    }
+}
+
+#[test]
+fn test_quat_integrate_does_not_drift() {
+    let angvel = Vec3::new(1.0f64, 2.0, 3.0);
+    let mut q: UnitQuat<f64> = na::one();
+    let mut r: Rot3<f64>     = na::one();
+
+    for _ in range(0u, 10000) {
+        q = q.integrate(&angvel, 0.01);
+        r = r.integrate(&angvel, 0.01);
+    }
+
+    assert!(na::approx_eq(&na::norm(q.quat()), &1.0));
+    assert!(na::approx_eq(&(na::transpose(&r) * r), &na::one()));
+    assert!(na::approx_eq(&q.to_rot(), &r));
+}
+
+#[test]
+fn test_quat_derivative() {
+    for _ in range(0u, 10000) {
+        let q: UnitQuat<f64>  = random();
+        let angvel: Vec3<f64> = random();
+        let h                 = 1.0e-6;
+
+        let finite_diff = (*q.integrate(&angvel, h).quat() - *q.quat()) / h;
+
+        assert!(na::approx_eq_eps(&q.derivative(&angvel), &finite_diff, &1.0e-5));
+    }
+}
+
+#[test]
+fn test_quat_iso3_integrate() {
+    for _ in range(0u, 10000) {
+        let iso: Iso3<f64>    = random();
+        let linvel: Vec3<f64> = random();
+        let angvel: Vec3<f64> = random();
+        let dt: f64           = random();
+
+        let iso1 = iso.integrate(&linvel, &angvel, dt);
+
+        assert!(na::approx_eq(&iso1.translation, &(iso.translation + linvel * dt)));
+        assert!(na::approx_eq(&iso1.rotation, &(Rot3::new(angvel * dt) * iso.rotation)));
+        assert!(na::approx_eq(&QuatIso3::new_with_iso(iso).integrate(&linvel, &angvel, dt).to_iso(),
+                              &iso1));
+    }
+}
+
+#[test]
+fn test_rotation_distances() {
+    for _ in range(0u, 10000) {
+        let q: UnitQuat<f64> = random();
+        let axis: Vec3<f64>  = random();
+        let angle: f64       = random();
+        let angle            = angle * 3.0;
+
+        let delta = UnitQuat::new(na::normalize(&axis) * angle);
+        let other = q * delta;
+        let chord = 2.0 * 2.0f64.sqrt() * (angle / 2.0).sin();
+
+        assert!(na::approx_eq(&q.angle_to(&other), &angle));
+        assert!(na::approx_eq(&other.angle_to(&q), &angle));
+        assert!(na::approx_eq(&q.to_rot().angle_to(&other.to_rot()), &angle));
+        assert!(na::approx_eq(&q.chordal_distance(&other), &chord));
+        assert!(na::approx_eq(&q.to_rot().chordal_distance(&other.to_rot()), &chord));
+    }
+}
+
+#[test]
+fn test_rot2_distances() {
+    for _ in range(0u, 10000) {
+        let r: Rot2<f64> = random();
+        let angle: f64   = random();
+        let angle        = angle * 6.0 - 3.0;
+
+        let other = r * Rot2::new(Vec1::new(angle));
+        let chord = 2.0 * 2.0f64.sqrt() * (angle / 2.0).sin().abs();
+
+        assert!(na::approx_eq(&r.angle_to(&other), &angle.abs()));
+        assert!(na::approx_eq(&r.chordal_distance(&other), &chord));
+    }
+}
+
+#[test]
+fn test_quat_average() {
+    for _ in range(0u, 10000) {
+        let center: UnitQuat<f64> = random();
+        let delta: Vec3<f64>      = random();
+
+        // Rotations placed symmetrically around `center` average to `center`, whatever the sign
+        // of their quaternion.
+        let q1  = center * UnitQuat::new(delta);
+        let q2  = center * UnitQuat::new(-delta);
+        let q2  = UnitQuat::new_with_quat(-*q2.quat());
+        let avg = UnitQuat::average(&[q1, q2]);
+
+        assert!(na::approx_eq(&avg.angle_to(&center), &0.0));
+
+        // Zero weights are ignored.
+        let avg = UnitQuat::weighted_average(&[q1, q2, center], &[0.0, 0.0, 2.0]);
+
+        assert!(na::approx_eq(&avg.angle_to(&center), &0.0));
+    }
+}
+
+#[test]
+fn test_quat_weighted_average_minimizes_chordal_distances() {
+    for _ in range(0u, 1000) {
+        let q1: UnitQuat<f64> = random();
+        let q2: UnitQuat<f64> = random();
+        let q3: UnitQuat<f64> = random();
+        let eps: Vec3<f64>    = random();
+        let eps               = (eps - Vec3::new(0.5, 0.5, 0.5)) * 1.0e-2;
+
+        let rots    = [q1, q2, q3];
+        let weights = [1.0, 2.0, 3.0];
+        let avg     = UnitQuat::weighted_average(&rots, &weights);
+
+        let cost = |q: &UnitQuat<f64>| {
+            let mut res = 0.0;
+
+            for (r, w) in rots.iter().zip(weights.iter()) {
+                let d = q.chordal_distance(r);
+
+                res = res + *w * d * d;
+            }
+
+            res
+        };
+
+        assert!(cost(&avg) <= cost(&(avg * UnitQuat::new(eps))) + 1.0e-12);
+    }
+}
+
+#[test]
+fn test_random_rotations_are_uniform() {
+    // The mean of Haar-distributed rotation matrices is zero.
+    let mut rng: XorShiftRng = SeedableRng::from_seed([1, 2, 3, 4]);
+    let mut sum2: Mat2<f64>  = na::zero();
+    let mut sum3: Mat3<f64>  = na::zero();
+
+    for _ in range(0u, 100000) {
+        let r2: Rot2<f64>    = rng.gen();
+        let r3: Rot3<f64>    = rng.gen();
+        let q: UnitQuat<f64> = rng.gen();
+
+        assert!(na::approx_eq(&na::norm(q.quat()), &1.0));
+
+        sum2 = sum2 + *r2.submat();
+        sum3 = sum3 + *r3.submat() + *q.to_rot().submat();
+    }
+
+    assert!(na::approx_eq_eps(&(sum2 / 100000.0), &na::zero(), &0.02));
+    assert!(na::approx_eq_eps(&(sum3 / 200000.0), &na::zero(), &0.02));
+}
+
+#[test]
+fn test_random_rotations_are_reproducible() {
+    let mut rng1: XorShiftRng = SeedableRng::from_seed([4, 3, 2, 1]);
+    let mut rng2: XorShiftRng = SeedableRng::from_seed([4, 3, 2, 1]);
+
+    for _ in range(0u, 100) {
+        let q1: UnitQuat<f64> = rng1.gen();
+        let q2: UnitQuat<f64> = rng2.gen();
+
+        assert!(q1 == q2);
+    }
+}
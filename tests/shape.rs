@@ -0,0 +1,294 @@
+extern crate "nalgebra" as na;
+
+use std::num::Float;
+use std::rand::random;
+use na::{Pnt2, Pnt3, Vec1, Vec2, Vec3, Iso3, Aabb, Ray, Line, Segment, Plane, Triangle,
+         Tetrahedron};
+
+#[test]
+fn test_ray_plane() {
+    let plane = Plane::new(Pnt3::new(0.0f64, 0.0, 1.0), Vec3::new(0.0, 0.0, 2.0));
+    let ray   = Ray::new(Pnt3::new(1.0f64, 2.0, -4.0), Vec3::new(0.0, 0.0, 1.0));
+    let back  = Ray::new(Pnt3::new(1.0f64, 2.0, -4.0), Vec3::new(0.0, 0.0, -1.0));
+    let par   = Ray::new(Pnt3::new(1.0f64, 2.0, -4.0), Vec3::new(1.0, 0.0, 0.0));
+
+    assert!(plane.normal == Vec3::z());
+    assert!(ray.toi_with_plane(&plane) == Some(5.0));
+    assert!(back.toi_with_plane(&plane).is_none());
+    assert!(par.toi_with_plane(&plane).is_none());
+}
+
+#[test]
+fn test_ray_ball() {
+    let center = Pnt3::new(1.0f64, 1.0, 1.0);
+    let ray    = Ray::new(Pnt3::new(-4.0f64, 1.0, 1.0), Vec3::new(2.0, 0.0, 0.0));
+    let inside = Ray::new(Pnt3::new(1.5f64, 1.0, 1.0), Vec3::new(2.0, 0.0, 0.0));
+    let away   = Ray::new(Pnt3::new(-4.0f64, 1.0, 1.0), Vec3::new(-2.0, 0.0, 0.0));
+    let miss   = Ray::new(Pnt3::new(-4.0f64, 3.0, 1.0), Vec3::new(2.0, 0.0, 0.0));
+
+    assert!(ray.toi_with_ball(&center, 1.0) == Some(2.0));
+    assert!(inside.toi_with_ball(&center, 1.0) == Some(0.0));
+    assert!(away.toi_with_ball(&center, 1.0).is_none());
+    assert!(miss.toi_with_ball(&center, 1.0).is_none());
+}
+
+#[test]
+fn test_ray_triangle() {
+    for _ in range(0u, 10000) {
+        let a: Pnt3<f64> = random();
+        let b: Pnt3<f64> = random();
+        let c: Pnt3<f64> = random();
+        let u: f64       = 0.1 + random::<f64>() * 0.35;
+        let v: f64       = 0.1 + random::<f64>() * 0.35;
+        let dir          = random::<Vec3<f64>>() - 0.5f64;
+        let target       = a + (b - a) * u + (c - a) * v;
+        let ray          = Ray::new(target + dir * (-3.0f64), dir);
+
+        match ray.toi_with_triangle(&a, &b, &c) {
+            Some((t, tu, tv)) => {
+                assert!(na::approx_eq_eps(&t, &3.0, &1.0e-5));
+                assert!(na::approx_eq_eps(&tu, &u, &1.0e-5));
+                assert!(na::approx_eq_eps(&tv, &v, &1.0e-5));
+            },
+            None => {
+                // The ray is almost parallel to the triangle, or the triangle is degenerate.
+                let n     = na::cross(&(b - a), &(c - a));
+                let scale = na::norm(&dir) * na::norm(&(b - a)) * na::norm(&(c - a));
+                assert!(na::dot(&n, &dir).abs() <= 2.0e-6 * scale)
+            }
+        }
+
+        let behind = Ray::new(target + dir * 3.0f64, dir);
+        assert!(behind.toi_with_triangle(&a, &b, &c).is_none());
+    }
+}
+
+#[test]
+fn test_ray_triangle_scale() {
+    let a = Pnt3::new(0.0f64, 0.0, 0.0);
+    let b = Pnt3::new(1.0f64, 0.0, 0.0);
+    let c = Pnt3::new(0.0f64, 1.0, 0.0);
+
+    for i in range(0i, 8) {
+        let s   = 10.0f64.powi(-i);
+        let sa  = a * s;
+        let sb  = b * s;
+        let sc  = c * s;
+        let hit = Pnt3::new(0.25 * s, 0.25 * s, 0.0);
+
+        // Small triangle, unit direction.
+        let ray = Ray::new(hit + Vec3::new(0.0, 0.0, 1.0), Vec3::new(0.0, 0.0, -1.0));
+        let (t, u, v) = ray.toi_with_triangle(&sa, &sb, &sc).unwrap();
+
+        assert!(na::approx_eq(&t, &1.0));
+        assert!(na::approx_eq(&u, &0.25) && na::approx_eq(&v, &0.25));
+
+        // Large triangle, short direction.
+        let ray = Ray::new(Pnt3::new(0.25, 0.25, 1.0), Vec3::new(0.0, 0.0, -s));
+        let (t, _, _) = ray.toi_with_triangle(&a, &b, &c).unwrap();
+
+        assert!(na::approx_eq_eps(&(t * s), &1.0, &1.0e-10));
+
+        // Parallel ray.
+        let ray = Ray::new(hit + Vec3::new(0.0, 0.0, s), Vec3::new(s, s, 0.0));
+        assert!(ray.toi_with_triangle(&sa, &sb, &sc).is_none());
+    }
+}
+
+#[test]
+fn test_ray_aabb() {
+    let aabb3 = Aabb::new(Pnt3::new(0.0f64, 0.0, 0.0), Pnt3::new(1.0, 1.0, 1.0));
+    let ray3  = Ray::new(Pnt3::new(-2.0f64, 0.5, 0.5), Vec3::new(1.0, 0.0, 0.0));
+    let diag3 = Ray::new(Pnt3::new(-1.0f64, -1.0, -1.0), Vec3::new(1.0, 1.0, 1.0));
+    let miss3 = Ray::new(Pnt3::new(-2.0f64, 1.5, 0.5), Vec3::new(1.0, 0.0, 0.0));
+    let in3   = Ray::new(Pnt3::new(0.5f64, 0.5, 0.5), Vec3::new(1.0, -2.0, 0.0));
+
+    assert!(ray3.toi_with_aabb(&aabb3) == Some(2.0));
+    assert!(diag3.toi_with_aabb(&aabb3) == Some(1.0));
+    assert!(miss3.toi_with_aabb(&aabb3).is_none());
+    assert!(in3.toi_with_aabb(&aabb3) == Some(0.0));
+
+    let aabb2 = Aabb::new(Pnt2::new(0.0f64, 0.0), Pnt2::new(2.0, 1.0));
+    let ray2  = Ray::new(Pnt2::new(1.0f64, 3.0), Vec2::new(0.0, -1.0));
+    let miss2 = Ray::new(Pnt2::new(1.0f64, 3.0), Vec2::new(1.0, 0.0));
+
+    assert!(ray2.toi_with_aabb(&aabb2) == Some(2.0));
+    assert!(miss2.toi_with_aabb(&aabb2).is_none());
+}
+
+#[test]
+fn test_ray_transform() {
+    for _ in range(0u, 10000) {
+        let m: Iso3<f64> = random();
+        let ray          = Ray::new(random::<Pnt3<f64>>(), random::<Vec3<f64>>());
+        let t: f64       = random();
+        let transformed  = ray.transform_by(&m);
+
+        assert!(na::approx_eq(&transformed.pnt_at(t), &na::transform(&m, &ray.pnt_at(t))));
+        assert!(na::approx_eq(&transformed.inv_transform_by(&m).orig, &ray.orig));
+        assert!(na::approx_eq(&transformed.inv_transform_by(&m).dir, &ray.dir));
+    }
+}
+
+#[test]
+fn test_segment_closest_pnt() {
+    let s = Segment::new(Pnt2::new(0.0f64, 0.0), Pnt2::new(2.0, 0.0));
+
+    assert!(s.length() == 2.0);
+    assert!(s.closest_pnt(&Pnt2::new(1.0, 1.0)) == Pnt2::new(1.0, 0.0));
+    assert!(s.closest_pnt(&Pnt2::new(-1.0, 1.0)) == Pnt2::new(0.0, 0.0));
+    assert!(s.closest_pnt(&Pnt2::new(5.0, -1.0)) == Pnt2::new(2.0, 0.0));
+    assert!(s.dist_to_pnt(&Pnt2::new(3.0, 0.0)) == 1.0);
+}
+
+#[test]
+fn test_line_plane_projection() {
+    for _ in range(0u, 10000) {
+        let p: Pnt3<f64> = random();
+        let o: Pnt3<f64> = random();
+        let d: Vec3<f64> = random::<Vec3<f64>>() + 0.1f64;
+
+        let line = Line::new(o, d);
+        let pl   = line.project_pnt(&p);
+
+        assert!(na::approx_eq(&na::dot(&(p - pl), &d), &0.0));
+        assert!(na::approx_eq(&na::norm(&na::cross(&(pl - o), &d)), &0.0));
+        assert!(na::approx_eq(&line.dist_to_pnt(&p), &na::dist(&p, &pl)));
+
+        let plane = Plane::new(o, d);
+        let pp    = plane.project_pnt(&p);
+
+        assert!(na::approx_eq(&plane.signed_dist_to_pnt(&pp), &0.0));
+        assert!(na::approx_eq(&na::norm(&na::cross(&(p - pp), &d)), &0.0));
+        assert!(na::approx_eq(&plane.dist_to_pnt(&p), &na::dist(&p, &pp)));
+    }
+}
+
+#[test]
+fn test_triangle_2d() {
+    let t = Triangle::new(Pnt2::new(0.0f64, 0.0), Pnt2::new(4.0, 0.0), Pnt2::new(0.0, 2.0));
+
+    assert!(t.area() == 4.0);
+    assert!(t.normal() == Vec1::new(1.0));
+    assert!(Triangle::new(t.a, t.c, t.b).scaled_normal() == Vec1::new(-8.0));
+    assert!(t.circumcenter() == Some(Pnt2::new(2.0, 1.0)));
+    assert!(t.barycentric_coordinates(&Pnt2::new(1.0, 1.0)) == Some((0.25, 0.25, 0.5)));
+    assert!(Triangle::new(t.a, t.b, t.b).barycentric_coordinates(&t.a).is_none());
+}
+
+#[test]
+fn test_triangle_3d() {
+    for _ in range(0u, 10000) {
+        let t = Triangle::new(random::<Pnt3<f64>>(), random::<Pnt3<f64>>(), random::<Pnt3<f64>>());
+
+        if t.area() < 1.0e-3 {
+            // Skip nearly degenerate triangles.
+            continue
+        }
+
+        let n = t.normal();
+
+        assert!(na::approx_eq(&na::dot(&n, &(t.b - t.a)), &0.0));
+        assert!(na::approx_eq(&na::dot(&n, &(t.c - t.a)), &0.0));
+        assert!(na::approx_eq(&(t.area() * 2.0), &na::norm(&t.scaled_normal())));
+
+        let center = t.circumcenter().unwrap();
+        let r      = na::dist(&center, &t.a);
+
+        assert!(na::approx_eq_eps(&na::dist(&center, &t.b), &r, &1.0e-5));
+        assert!(na::approx_eq_eps(&na::dist(&center, &t.c), &r, &1.0e-5));
+
+        let (u, v, w) = (random::<f64>(), random::<f64>(), random::<f64>());
+        let sum       = u + v + w;
+        let p         = na::affine_combination(&[ t.a, t.b, t.c ], &[ u, v, w ]);
+
+        let (bu, bv, bw) = t.barycentric_coordinates(&p).unwrap();
+
+        assert!(na::approx_eq_eps(&bu, &(u / sum), &1.0e-5));
+        assert!(na::approx_eq_eps(&bv, &(v / sum), &1.0e-5));
+        assert!(na::approx_eq_eps(&bw, &(w / sum), &1.0e-5));
+
+        assert!(na::approx_eq(&t.centroid(), &na::affine_combination(&[ t.a, t.b, t.c ],
+                                                                     &[ 1.0, 1.0, 1.0 ])));
+    }
+}
+
+#[test]
+fn test_tetrahedron() {
+    let t = Tetrahedron::new(Pnt3::new(0.0f64, 0.0, 0.0), Pnt3::new(1.0, 0.0, 0.0),
+                             Pnt3::new(0.0, 1.0, 0.0), Pnt3::new(0.0, 0.0, 3.0));
+
+    assert!(na::approx_eq(&t.signed_volume(), &0.5));
+    assert!(na::approx_eq(&Tetrahedron::new(t.a, t.c, t.b, t.d).signed_volume(), &-0.5));
+    assert!(na::approx_eq(&Tetrahedron::new(t.a, t.c, t.b, t.d).volume(), &0.5));
+    assert!(Tetrahedron::new(t.a, t.b, t.c, t.a).barycentric_coordinates(&t.a).is_none());
+
+    for _ in range(0u, 10000) {
+        let w: Vec<f64>  = range(0u, 4).map(|_| random::<f64>()).collect();
+        let sum          = w[0] + w[1] + w[2] + w[3];
+        let p            = na::affine_combination(&[ t.a, t.b, t.c, t.d ], w.as_slice());
+        let (u, v, x, y) = t.barycentric_coordinates(&p).unwrap();
+
+        assert!(na::approx_eq(&u, &(w[0] / sum)));
+        assert!(na::approx_eq(&v, &(w[1] / sum)));
+        assert!(na::approx_eq(&x, &(w[2] / sum)));
+        assert!(na::approx_eq(&y, &(w[3] / sum)));
+    }
+
+    assert!(na::approx_eq(&t.centroid(), &Pnt3::new(0.25, 0.25, 0.75)));
+}
+
+#[test]
+fn test_small_simplices() {
+    // A right triangle with 3cm legs and a regular tetrahedron with 1cm edges, in meters.
+    let t = Triangle::new(Pnt3::new(1.0f64, 2.0, 3.0), Pnt3::new(1.03, 2.0, 3.0),
+                          Pnt3::new(1.0, 2.03, 3.0));
+
+    let (u, v, w) = t.barycentric_coordinates(&t.centroid()).unwrap();
+    let center    = t.circumcenter().unwrap();
+
+    assert!(na::approx_eq(&u, &(1.0 / 3.0)));
+    assert!(na::approx_eq(&v, &(1.0 / 3.0)));
+    assert!(na::approx_eq(&w, &(1.0 / 3.0)));
+    assert!(na::approx_eq_eps(&center, &Pnt3::new(1.015, 2.015, 3.0), &1.0e-10));
+    assert!(Triangle::new(t.a, t.b, t.a + (t.b - t.a) * 2.0f64).circumcenter().is_none());
+
+    let s = 0.01f64 / (8.0f64).sqrt();
+    let t = Tetrahedron::new(Pnt3::new(s, s, s), Pnt3::new(s, -s, -s), Pnt3::new(-s, s, -s),
+                             Pnt3::new(-s, -s, s));
+
+    let (u, v, w, x) = t.barycentric_coordinates(&t.centroid()).unwrap();
+
+    assert!(na::approx_eq(&u, &0.25));
+    assert!(na::approx_eq(&v, &0.25));
+    assert!(na::approx_eq(&w, &0.25));
+    assert!(na::approx_eq(&x, &0.25));
+    assert!(Tetrahedron::new(t.a, t.b, t.c, t.a + (t.b - t.a) * 0.5f64 + (t.c - t.a) * 0.5f64)
+            .barycentric_coordinates(&t.a).is_none());
+}
+
+#[test]
+fn test_thin_triangle() {
+    // An isosceles triangle with an apex angle of 0.05 degree.
+    let angle: f64 = 0.05f64.to_radians();
+    let t = Triangle::new(Pnt2::new(0.0f64, 0.0), Pnt2::new(1.0, 0.0),
+                          Pnt2::new(angle.cos(), angle.sin()));
+
+    let (u, v, w) = t.barycentric_coordinates(&t.centroid()).unwrap();
+    let center    = t.circumcenter().unwrap();
+
+    assert!(na::approx_eq(&u, &(1.0 / 3.0)));
+    assert!(na::approx_eq(&v, &(1.0 / 3.0)));
+    assert!(na::approx_eq(&w, &(1.0 / 3.0)));
+    assert!(na::approx_eq_eps(&na::dist(&center, &t.a), &na::dist(&center, &t.b), &1.0e-8));
+    assert!(na::approx_eq_eps(&na::dist(&center, &t.a), &na::dist(&center, &t.c), &1.0e-8));
+}
+
+#[test]
+fn test_affine_combination() {
+    let pts = [ Pnt2::new(0.0f64, 0.0), Pnt2::new(2.0, 0.0), Pnt2::new(2.0, 4.0) ];
+
+    assert!(na::affine_combination(&pts, &[ 1.0, 1.0, 2.0 ]) == Pnt2::new(1.5, 2.0));
+    assert!(na::affine_combination(&pts, &[ 0.0, 3.0, 0.0 ]) == pts[1]);
+    assert!(na::affine_combination(&pts, &[ -1.0, 2.0, 0.0 ]) == Pnt2::new(4.0, 0.0));
+}
@@ -0,0 +1,113 @@
+#![feature(macro_rules)]
+
+extern crate "nalgebra" as na;
+
+use na::{Pnt2, Pnt3, Vec2, Vec3, Iso2, Iso3, Sim2, Sim3, Mat3, Mat4};
+use std::rand::random;
+
+#[test]
+fn test_sim2() {
+    for _ in range(0u, 10000) {
+        let s1: Sim2<f64> = random();
+        let s2: Sim2<f64> = random();
+        let p: Pnt2<f64>  = random();
+        let v: Vec2<f64>  = random();
+
+        assert!(na::approx_eq(&((s1 * s2) * p), &(s1 * (s2 * p))));
+        assert!(na::approx_eq(&na::to_homogeneous(&(s1 * s2)),
+                              &(na::to_homogeneous(&s1) * na::to_homogeneous(&s2))));
+        assert!(na::approx_eq(&(na::inv(&s1).unwrap() * s1), &na::one()));
+        assert!(na::approx_eq(&na::inv_transform(&s1, &na::transform(&s1, &p)), &p));
+        assert!(na::approx_eq(&(s1 * p), &(s1.rotation * (p * s1.scale) + s1.translation)));
+        assert!(na::approx_eq(&(s1 * v), &(s1.rotation * (v * s1.scale))));
+    }
+}
+
+#[test]
+fn test_sim2_iso2() {
+    for _ in range(0u, 10000) {
+        let iso: Iso2<f64> = random();
+        let p: Pnt2<f64>   = random();
+
+        assert!(na::approx_eq(&(Sim2::new_with_iso(iso) * p), &(iso * p)));
+        assert!(na::approx_eq(&Sim2::new_with_iso(iso).to_iso(), &iso));
+    }
+}
+
+#[test]
+fn test_sim2_homogeneous_round_trip() {
+    for _ in range(0u, 10000) {
+        let s: Sim2<f64> = random();
+
+        assert!(na::approx_eq(&Sim2::new_with_homogeneous(&na::to_homogeneous(&s)).unwrap(), &s));
+    }
+}
+
+#[test]
+fn test_sim2_homogeneous_rejects_non_similarities() {
+    let shear      = Mat3::new(1.0f64, 1.0, 0.0,
+                               0.0,    1.0, 0.0,
+                               0.0,    0.0, 1.0);
+    let reflection = Mat3::new(-1.0f64, 0.0, 0.0,
+                                0.0,    1.0, 0.0,
+                                0.0,    0.0, 1.0);
+    let projective = Mat3::new(1.0f64, 0.0, 0.0,
+                               0.0,    1.0, 0.0,
+                               1.0,    0.0, 1.0);
+
+    assert!(Sim2::new_with_homogeneous(&shear).is_none());
+    assert!(Sim2::new_with_homogeneous(&reflection).is_none());
+    assert!(Sim2::new_with_homogeneous(&projective).is_none());
+}
+
+#[test]
+fn test_sim3_homogeneous_round_trip() {
+    for _ in range(0u, 10000) {
+        let s: Sim3<f64> = random();
+
+        assert!(na::approx_eq(&Sim3::new_with_homogeneous(&na::to_homogeneous(&s)).unwrap(), &s));
+    }
+}
+
+#[test]
+fn test_sim3_homogeneous_rejects_non_similarities() {
+    let scale = Mat4::new(1.0f64, 0.0, 0.0, 0.0,
+                          0.0,    2.0, 0.0, 0.0,
+                          0.0,    0.0, 1.0, 0.0,
+                          0.0,    0.0, 0.0, 1.0);
+
+    assert!(Sim3::new_with_homogeneous(&scale).is_none());
+}
+
+#[test]
+fn test_sim3_iso3() {
+    for _ in range(0u, 10000) {
+        let iso: Iso3<f64> = random();
+        let p: Pnt3<f64>   = random();
+        let v: Vec3<f64>   = random();
+
+        assert!(na::approx_eq(&(Sim3::new_with_iso(iso) * p), &(iso * p)));
+        assert!(na::approx_eq(&(Sim3::new_with_iso(iso) * v), &(iso * v)));
+        assert!(na::approx_eq(&Sim3::new_with_iso(iso).to_iso(), &iso));
+    }
+}
+
+#[test]
+fn test_sim3_mul() {
+    for _ in range(0u, 10000) {
+        let s1: Sim3<f64> = random();
+        let s2: Sim3<f64> = random();
+        let p: Pnt3<f64>  = random();
+
+        assert!(na::approx_eq(&((s1 * s2) * p), &(s1 * (s2 * p))));
+        assert!(na::approx_eq(&(na::inv(&s1).unwrap() * s1), &na::one()));
+        assert!(na::approx_eq(&na::inv_transform(&s1, &na::transform(&s1, &p)), &p));
+    }
+}
+
+#[test]
+fn test_sim_zero_scale_is_not_invertible() {
+    let s = Sim3::new(na::zero(), na::zero(), 0.0f64);
+
+    assert!(na::inv(&s).is_none());
+}
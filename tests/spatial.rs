@@ -0,0 +1,115 @@
+#![feature(macro_rules)]
+
+extern crate "nalgebra" as na;
+
+use na::{Pnt3, Vec3, Vec6, Iso3, Twist, Wrench};
+use std::rand::random;
+
+#[test]
+fn test_spatial_vec6() {
+    for _ in range(0u, 10000) {
+        let v: Vec6<f64> = random();
+
+        assert!(Twist::new_with_vec6(&v).to_vec6() == v);
+        assert!(Wrench::new_with_vec6(&v).to_vec6() == v);
+        assert!(Twist::new_with_vec6(&v).linear == Vec3::new(v.x, v.y, v.z));
+        assert!(Wrench::new_with_vec6(&v).torque == Vec3::new(v.w, v.a, v.b));
+    }
+}
+
+#[test]
+fn test_twist_transform() {
+    for _ in range(0u, 10000) {
+        let iso1: Iso3<f64> = random();
+        let iso2: Iso3<f64> = random();
+        let v: Twist<f64>   = random();
+
+        let tv = na::transform(&iso1, &v);
+
+        assert!(na::approx_eq(&na::inv_transform(&iso1, &tv), &v));
+        assert!(na::approx_eq(&tv.to_vec6(), &(iso1.adjoint() * v.to_vec6())));
+        assert!(na::approx_eq(&na::transform(&(iso1 * iso2), &v),
+                              &na::transform(&iso1, &na::transform(&iso2, &v))));
+    }
+}
+
+#[test]
+fn test_wrench_transform() {
+    for _ in range(0u, 10000) {
+        let iso1: Iso3<f64> = random();
+        let iso2: Iso3<f64> = random();
+        let f: Wrench<f64>  = random();
+
+        let tf: Wrench<f64> = na::transform(&iso1, &f);
+
+        assert!(na::approx_eq(&na::inv_transform(&iso1, &tf), &f));
+        assert!(na::approx_eq(&na::transform(&(iso1 * iso2), &f),
+                              &na::transform(&iso1, &na::transform(&iso2, &f))));
+    }
+}
+
+#[test]
+fn test_power_is_frame_invariant() {
+    for _ in range(0u, 10000) {
+        let iso: Iso3<f64> = random();
+        let v: Twist<f64>  = random();
+        let f: Wrench<f64> = random();
+
+        let tv: Twist<f64>  = na::transform(&iso, &v);
+        let tf: Wrench<f64> = na::transform(&iso, &f);
+
+        assert!(na::approx_eq(&tv.power(&tf), &v.power(&f)));
+        assert!(na::approx_eq(&f.power(&v), &v.power(&f)));
+        assert!(na::approx_eq(&v.power(&f), &na::dot(&v.to_vec6(), &f.to_vec6())));
+    }
+}
+
+#[test]
+fn test_spatial_cross() {
+    for _ in range(0u, 10000) {
+        let v: Twist<f64>  = random();
+        let u: Twist<f64>  = random();
+        let f: Wrench<f64> = random();
+
+        assert!(na::approx_eq(&na::cross(&v, &v), &na::zero()));
+        assert!(na::approx_eq(&na::cross(&v, &u), &-na::cross(&u, &v)));
+
+        // The force cross product is the dual of the motion cross product.
+        assert!(na::approx_eq(&na::cross(&v, &u).power(&f), &-u.power(&v.cross_wrench(&f))));
+    }
+}
+
+#[test]
+fn test_velocity_and_force_at_point() {
+    for _ in range(0u, 10000) {
+        let v: Twist<f64>    = random();
+        let p: Pnt3<f64>     = random();
+        let force: Vec3<f64> = random();
+
+        // The linear velocity at `p` is the linear part of the twist expressed at `p`.
+        let at_p = Iso3::new(*p.as_vec(), na::zero());
+        let vp: Twist<f64> = na::inv_transform(&at_p, &v);
+
+        assert!(na::approx_eq(&vp.linear, &v.velocity_at(&p)));
+
+        // The power of a force applied at `p` only depends on the velocity of `p`.
+        let f = Wrench::new_with_force_at(force, &p);
+
+        assert!(na::approx_eq(&v.power(&f), &na::dot(&force, &v.velocity_at(&p))));
+    }
+}
+
+#[test]
+fn test_spatial_arith() {
+    for _ in range(0u, 10000) {
+        let v: Twist<f64>  = random();
+        let u: Twist<f64>  = random();
+        let f: Wrench<f64> = random();
+        let g: Wrench<f64> = random();
+
+        assert!(na::approx_eq(&(v + u).to_vec6(), &(v.to_vec6() + u.to_vec6())));
+        assert!(na::approx_eq(&(f - g).to_vec6(), &(f.to_vec6() - g.to_vec6())));
+        assert!(na::approx_eq(&(v * 2.0).to_vec6(), &(v.to_vec6() * 2.0)));
+        assert!(na::is_zero(&(f + -f)));
+    }
+}
@@ -2,8 +2,41 @@
 
 extern crate "nalgebra" as na;
 
-use std::rand::random;
-use na::{Vec0, Vec1, Vec2, Vec3, Vec4, Vec5, Vec6, Mat3, Iterable, IterableMut};
+use std::rand::{random, XorShiftRng, SeedableRng};
+use na::{Vec0, Vec1, Vec2, Vec3, Vec4, Vec5, Vec6, Pnt2, Pnt3, Pnt4, DVec, DVec3, DVec4, Mat3,
+         Iterable, IterableMut};
+
+macro_rules! test_random_unit_impl(
+    ($t: ident) => (
+        {
+            let mut rng: XorShiftRng = SeedableRng::from_seed([1, 2, 3, 4]);
+
+            for _ in range(0u, 10000) {
+                let u: $t<f64> = $t::new_random_unit(&mut rng);
+                let b: $t<f64> = $t::new_random_in_ball(&mut rng);
+
+                assert!(na::approx_eq(&na::norm(&u), &1.0));
+                assert!(na::norm(&b) <= 1.0 + 1.0e-10);
+            }
+        }
+    )
+)
+
+macro_rules! test_dvec_conversion_impl(
+    ($t: ident, $to_dvec: ident) => (
+        for _ in range(0u, 10000) {
+            let v: $t<f64> = random();
+            let dv         = v.to_dvec();
+            let sv         = v.$to_dvec();
+
+            assert!(dv.len() == na::dim::<$t<f64>>());
+            assert!(dv.as_slice() == v.as_array().as_slice());
+            assert!(sv.as_slice() == v.as_array().as_slice());
+            assert!($t::from_dvec(&dv) == Some(v));
+            assert!($t::from_dvec(&sv) == Some(v));
+        }
+    )
+)
 
 macro_rules! test_iterator_impl(
     ($t: ty, $n: ty) => (
@@ -99,6 +132,100 @@ macro_rules! test_subspace_basis_impl(
     );
 )
 
+macro_rules! test_basis_iter_impl(
+    ($t: ty, $dim: expr) => (
+        for _ in range(0u, 1000) {
+            let v : $t = random();
+            let v1     = na::normalize(&v);
+
+            let canonical: Vec<$t> = na::canonical_basis_iter().collect();
+            let subspace: Vec<$t>  = na::orthonormal_subspace_basis_iter(&v1).collect();
+            let mut from_closure   = Vec::new();
+
+            na::canonical_basis(|e: $t| { from_closure.push(e); true });
+
+            assert!(canonical.len() == $dim);
+            assert!(canonical == from_closure);
+
+            from_closure.clear();
+            na::orthonormal_subspace_basis(&v1, |e| { from_closure.push(e); true });
+
+            assert!(subspace.len() == if $dim == 0 { 0 } else { $dim - 1 });
+            assert!(subspace == from_closure);
+
+            for (i, e) in canonical.iter().enumerate() {
+                assert!(Some(*e) == na::canonical_basis_element(i));
+            }
+        }
+    );
+)
+
+macro_rules! test_sphere_samples_with_count_impl(
+    ($t: ident) => (
+        {
+            for count in [1u, 10, 100, 500].iter() {
+                let samples: Vec<$t<f64>> = na::sphere_samples_with_count(*count).collect();
+
+                assert!(samples.len() == *count);
+
+                for s in samples.iter() {
+                    assert!(na::approx_eq(&na::norm(s), &1.0));
+                }
+
+                // The sample sets are cached and deterministic.
+                let again: Vec<$t<f64>> = na::sphere_samples_with_count(*count).collect();
+
+                assert!(samples == again);
+            }
+        }
+    )
+)
+
+macro_rules! test_comp_ops_impl(
+    ($t: ident) => (
+        {
+            for _ in range(0u, 10000) {
+                let v: $t<f64> = random::<$t<f64>>() * 10.0f64 - 5.0f64;
+                let w: $t<f64> = random::<$t<f64>>() * 10.0f64 - 5.0f64;
+
+                let floor  = na::floor(&v);
+                let ceil   = na::ceil(&v);
+                let round  = na::round(&v);
+                let signum = na::signum(&v);
+                let abs    = na::abs(&v);
+                let clamp  = v.clamp(&$t::new_repeat(-1.0), &$t::new_repeat(1.0));
+
+                for (i, e) in v.iter().enumerate() {
+                    assert!(floor[i]  == e.floor());
+                    assert!(ceil[i]   == e.ceil());
+                    assert!(round[i]  == e.round());
+                    assert!(signum[i] == e.signum());
+                    assert!(abs[i]    == e.abs());
+                    assert!(clamp[i]  == na::clamp(*e, -1.0, 1.0));
+                }
+
+                assert!(na::lerp(&v, &w, &0.0) == v);
+                assert!(na::approx_eq(&na::lerp(&v, &w, &1.0), &w));
+                let mid = na::zip_map(&v, &w, |a, b| (a + b) * 0.5);
+
+                assert!(na::approx_eq(&na::lerp(&v, &w, &0.5), &mid));
+
+                assert!(na::map(&v, |e| e * 2.0) == v * 2.0f64);
+                assert!(na::zip_map(&v, &w, |a, b| a - b) == na::zip_map(&w, &v, |a, b| b - a));
+
+                assert!(na::sum(&v)     == v.iter().fold(0.0, |a, b| a + *b));
+                assert!(na::product(&v) == v.iter().fold(1.0, |a, b| a * *b));
+
+                let (imin, min) = na::min_component(&v);
+                let (imax, max) = na::max_component(&v);
+
+                assert!(v[imin] == min && v[imax] == max);
+                assert!(v.iter().all(|e| *e >= min && *e <= max));
+            }
+        }
+    )
+)
+
 #[test]
 fn test_cross_vec3() {
     for _ in range(0u, 10000) {
@@ -318,3 +445,355 @@ fn test_outer_vec3() {
             8.0, 10.0, 12.0,
             12.0, 15.0, 18.0));
 }
+
+#[test]
+fn test_random_unit_vec2() {
+    test_random_unit_impl!(Vec2);
+}
+
+#[test]
+fn test_random_unit_vec3() {
+    test_random_unit_impl!(Vec3);
+}
+
+#[test]
+fn test_random_unit_vec4() {
+    test_random_unit_impl!(Vec4);
+}
+
+#[test]
+fn test_random_unit_vec5() {
+    test_random_unit_impl!(Vec5);
+}
+
+#[test]
+fn test_random_unit_vec6() {
+    test_random_unit_impl!(Vec6);
+}
+
+#[test]
+fn test_random_unit_dvec() {
+    let mut rng: XorShiftRng = SeedableRng::from_seed([1, 2, 3, 4]);
+
+    for dim in range(1u, 10) {
+        let u: DVec<f64> = DVec::new_random_unit(dim, &mut rng);
+        let b: DVec<f64> = DVec::new_random_in_ball(dim, &mut rng);
+
+        assert!(u.len() == dim && b.len() == dim);
+        assert!(na::approx_eq(&na::norm(&u), &1.0));
+        assert!(na::norm(&b) <= 1.0 + 1.0e-10);
+    }
+}
+
+#[test]
+fn test_random_unit_is_reproducible() {
+    let mut rng1: XorShiftRng = SeedableRng::from_seed([4, 3, 2, 1]);
+    let mut rng2: XorShiftRng = SeedableRng::from_seed([4, 3, 2, 1]);
+
+    for _ in range(0u, 100) {
+        let u1: Vec3<f64> = Vec3::new_random_unit(&mut rng1);
+        let u2: Vec3<f64> = Vec3::new_random_unit(&mut rng2);
+
+        assert!(u1 == u2);
+    }
+}
+
+#[test]
+fn test_random_in_ball_radius_distribution() {
+    // The fraction of samples inside of the ball of radius 1/2 should be 1/8 in 3D.
+    let mut rng: XorShiftRng = SeedableRng::from_seed([1, 2, 3, 4]);
+    let mut inside = 0u;
+
+    for _ in range(0u, 100000) {
+        let b: Vec3<f64> = Vec3::new_random_in_ball(&mut rng);
+
+        if na::norm(&b) < 0.5 {
+            inside = inside + 1;
+        }
+    }
+
+    assert!((inside as f64 / 100000.0 - 0.125).abs() < 0.01);
+}
+
+#[test]
+fn test_sphere_samples_with_count_vec2() {
+    test_sphere_samples_with_count_impl!(Vec2);
+}
+
+#[test]
+fn test_sphere_samples_with_count_vec3() {
+    test_sphere_samples_with_count_impl!(Vec3);
+}
+
+#[test]
+fn test_sphere_samples_with_count_vec4() {
+    test_sphere_samples_with_count_impl!(Vec4);
+}
+
+#[test]
+fn test_sphere_samples_with_count_vec5() {
+    test_sphere_samples_with_count_impl!(Vec5);
+}
+
+#[test]
+fn test_sphere_samples_with_count_vec6() {
+    test_sphere_samples_with_count_impl!(Vec6);
+}
+
+#[test]
+fn test_sphere_samples_with_count_are_spread() {
+    // The samples are spread evenly enough for their mean to be close to the origin.
+    let samples: Vec<Vec3<f64>> = na::sphere_samples_with_count(1000).collect();
+    let mean = samples.iter().fold(na::zero::<Vec3<f64>>(), |a, s| a + *s) / 1000.0f64;
+
+    assert!(na::norm(&mean) < 1.0e-2);
+
+    let samples: Vec<Vec6<f64>> = na::sphere_samples_with_count(1000).collect();
+    let mean = samples.iter().fold(na::zero::<Vec6<f64>>(), |a, s| a + *s) / 1000.0f64;
+
+    assert!(na::norm(&mean) < 0.05);
+}
+
+#[test]
+fn test_sample_sphere_vec5_vec6() {
+    let mut samples5: Vec<Vec5<f64>> = Vec::new();
+    let mut samples6: Vec<Vec6<f64>> = Vec::new();
+
+    na::sample_sphere(|s: Vec5<f64>| samples5.push(s));
+    na::sample_sphere(|s: Vec6<f64>| samples6.push(s));
+
+    assert!(samples5.len() == 256);
+    assert!(samples6.len() == 512);
+}
+
+#[test]
+fn test_sample_sphere_vec4() {
+    let mut samples: Vec<Vec4<f64>> = Vec::new();
+
+    na::sample_sphere(|s: Vec4<f64>| samples.push(s));
+
+    assert!(samples.len() == 120);
+
+    for (i, s) in samples.iter().enumerate() {
+        assert!(na::approx_eq(&na::norm(s), &1.0));
+
+        // Two distinct vertices of the 600-cell are at least 36 degrees apart.
+        for t in samples.slice_from(i + 1).iter() {
+            assert!(na::dot(s, t) < 0.81);
+        }
+    }
+}
+
+#[test]
+fn test_dvec_conversion_vec1() {
+    test_dvec_conversion_impl!(Vec1, to_dvec1);
+}
+
+#[test]
+fn test_dvec_conversion_vec2() {
+    test_dvec_conversion_impl!(Vec2, to_dvec2);
+}
+
+#[test]
+fn test_dvec_conversion_vec3() {
+    test_dvec_conversion_impl!(Vec3, to_dvec3);
+}
+
+#[test]
+fn test_dvec_conversion_vec4() {
+    test_dvec_conversion_impl!(Vec4, to_dvec4);
+}
+
+#[test]
+fn test_dvec_conversion_vec5() {
+    test_dvec_conversion_impl!(Vec5, to_dvec5);
+}
+
+#[test]
+fn test_dvec_conversion_vec6() {
+    test_dvec_conversion_impl!(Vec6, to_dvec6);
+}
+
+#[test]
+fn test_dvec_conversion_pnt3() {
+    test_dvec_conversion_impl!(Pnt3, to_dvec3);
+}
+
+#[test]
+fn test_dvec_conversion_dim_mismatch() {
+    let v: Vec0<f64> = Vec0::new();
+
+    assert!(v.to_dvec().len() == 0);
+    assert!(Vec0::from_dvec(&v.to_dvec()) == Some(v));
+    assert!(Vec0::<f64>::from_dvec(&DVec::new_zeros(1)).is_none());
+    assert!(Vec3::<f64>::from_dvec(&DVec::new_zeros(4)).is_none());
+    assert!(Vec3::<f64>::from_dvec(&DVec4::new_zeros(2)).is_none());
+    assert!(Vec4::<f64>::from_dvec(&DVec3::new_zeros(3)).is_none());
+}
+
+#[test]
+fn test_basis_iter_vec0() {
+    test_basis_iter_impl!(Vec0<f64>, 0u);
+}
+
+#[test]
+fn test_basis_iter_vec1() {
+    test_basis_iter_impl!(Vec1<f64>, 1u);
+}
+
+#[test]
+fn test_basis_iter_vec2() {
+    test_basis_iter_impl!(Vec2<f64>, 2u);
+}
+
+#[test]
+fn test_basis_iter_vec3() {
+    test_basis_iter_impl!(Vec3<f64>, 3u);
+}
+
+#[test]
+fn test_basis_iter_vec4() {
+    test_basis_iter_impl!(Vec4<f64>, 4u);
+}
+
+#[test]
+fn test_basis_iter_vec5() {
+    test_basis_iter_impl!(Vec5<f64>, 5u);
+}
+
+#[test]
+fn test_basis_iter_vec6() {
+    test_basis_iter_impl!(Vec6<f64>, 6u);
+}
+
+#[test]
+fn test_basis_iter_early_exit() {
+    let mut n = 0u;
+
+    na::canonical_basis(|_: Vec6<f64>| { n = n + 1; n != 2 });
+
+    assert!(n == 2);
+    assert!(na::canonical_basis_iter::<Vec6<f64>>().take(2).count() == 2);
+}
+
+#[test]
+fn test_basis_iter_dvec() {
+    for dim in range(1u, 10) {
+        let v: DVec<f64> = DVec::new_random(dim);
+        let v1           = na::normalize(&v);
+
+        let canonical: Vec<DVec<f64>> = DVec::canonical_basis_iter(dim).collect();
+        let subspace: Vec<DVec<f64>>  = v1.orthonormal_subspace_basis_iter().collect();
+
+        assert!(canonical == DVec::canonical_basis_with_dim(dim));
+        assert!(subspace.len() == dim - 1);
+
+        for e in subspace.iter() {
+            assert!(na::approx_eq(&na::dot(e, &v1), &0.0));
+            assert!(na::approx_eq(&na::norm(e), &1.0));
+        }
+    }
+}
+
+#[test]
+fn test_sphere_samples() {
+    let mut samples3 = Vec::new();
+
+    na::sample_sphere(|s: Vec3<f64>| samples3.push(s));
+
+    assert!(na::sphere_samples::<Vec1<f64>>().count() == 1);
+    assert!(na::sphere_samples::<Vec3<f64>>().collect::<Vec<Vec3<f64>>>() == samples3);
+    assert!(na::sphere_samples::<Vec4<f64>>().count() == 120);
+}
+
+#[test]
+fn test_comp_ops_vec1() {
+    test_comp_ops_impl!(Vec1);
+}
+
+#[test]
+fn test_comp_ops_vec2() {
+    test_comp_ops_impl!(Vec2);
+}
+
+#[test]
+fn test_comp_ops_vec3() {
+    test_comp_ops_impl!(Vec3);
+}
+
+#[test]
+fn test_comp_ops_vec4() {
+    test_comp_ops_impl!(Vec4);
+}
+
+#[test]
+fn test_comp_ops_vec5() {
+    test_comp_ops_impl!(Vec5);
+}
+
+#[test]
+fn test_comp_ops_vec6() {
+    test_comp_ops_impl!(Vec6);
+}
+
+#[test]
+fn test_comp_ops_pnt3() {
+    test_comp_ops_impl!(Pnt3);
+}
+
+#[test]
+fn test_comp_ops_dvec() {
+    let v = DVec::from_slice(4, [ -1.5f64, 2.5, 0.25, 3.0 ].as_slice());
+    let w = DVec::from_slice(4, [ 1.5f64, 0.5, -0.25, 1.0 ].as_slice());
+
+    assert!(na::floor(&v)  == DVec::from_slice(4, [ -2.0f64, 2.0, 0.0, 3.0 ].as_slice()));
+    assert!(na::ceil(&v)   == DVec::from_slice(4, [ -1.0f64, 3.0, 1.0, 3.0 ].as_slice()));
+    assert!(na::round(&v)  == DVec::from_slice(4, [ -2.0f64, 3.0, 0.0, 3.0 ].as_slice()));
+    assert!(na::signum(&v) == DVec::from_slice(4, [ -1.0f64, 1.0, 1.0, 1.0 ].as_slice()));
+    assert!(na::abs(&v)    == DVec::from_slice(4, [ 1.5f64, 2.5, 0.25, 3.0 ].as_slice()));
+    assert!(na::lerp(&v, &w, &0.5) == DVec::from_slice(4, [ 0.0f64, 1.5, 0.0, 2.0 ].as_slice()));
+    assert!(na::zip_map(&v, &w, |a, b| a * b) == v * w);
+    assert!(na::map(&v, |e| -e) == -v);
+    assert!(na::sum(&v) == 4.25);
+    assert!(na::product(&v) == -2.8125);
+    assert!(na::min_component(&v) == (0, -1.5));
+    assert!(na::max_component(&v) == (3, 3.0));
+}
+
+#[test]
+fn test_swizzle_vec() {
+    let v2 = Vec2::new(1.0f64, 2.0);
+    let v3 = Vec3::new(1.0f64, 2.0, 3.0);
+    let v4 = Vec4::new(1.0f64, 2.0, 3.0, 4.0);
+
+    assert!(v2.yx()   == Vec2::new(2.0, 1.0));
+    assert!(v3.xy()   == Vec2::new(1.0, 2.0));
+    assert!(v3.xz()   == Vec2::new(1.0, 3.0));
+    assert!(v3.zyx()  == Vec3::new(3.0, 2.0, 1.0));
+    assert!(v3.xyz()  == v3);
+    assert!(v4.wx()   == Vec2::new(4.0, 1.0));
+    assert!(v4.xyz()  == v3);
+    assert!(v4.wzyx() == Vec4::new(4.0, 3.0, 2.0, 1.0));
+}
+
+#[test]
+fn test_swizzle_pnt() {
+    let p3 = Pnt3::new(1.0f64, 2.0, 3.0);
+    let p4 = Pnt4::new(1.0f64, 2.0, 3.0, 4.0);
+
+    assert!(p3.xy()  == Pnt2::new(1.0, 2.0));
+    assert!(p3.zx()  == Pnt2::new(3.0, 1.0));
+    assert!(p4.xyz() == p3);
+    assert!(p4.ywz() == Pnt3::new(2.0, 4.0, 3.0));
+}
+
+#[test]
+fn test_extend() {
+    let v3 = Vec3::new(1.0f64, 2.0, 3.0);
+    let p3 = Pnt3::new(1.0f64, 2.0, 3.0);
+
+    assert!(Vec3::from_xy(v3.xy(), 3.0)   == v3);
+    assert!(Vec4::from_xyz(v3, 4.0)       == Vec4::new(1.0, 2.0, 3.0, 4.0));
+    assert!(Pnt3::from_pnt2(p3.xy(), 3.0) == p3);
+    assert!(Pnt4::from_pnt3(p3, 4.0)      == Pnt4::new(1.0, 2.0, 3.0, 4.0));
+}